@@ -1,14 +1,23 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use crucible_core::baseline::{Baseline, BASELINE_FILE};
+use crucible_core::calls::CallGraph;
 use crucible_core::claude::{
     ContextGenerator, IntegrationConfig, IntegrationMode, SyncManager, ValidationHooks,
     ValidationLevel,
 };
+use crucible_core::graph::{explain_dependency, ModuleGraph, Reached};
+use crucible_core::metrics::project_metrics;
+use crucible_core::validator::ValidationIssue;
+use crucible_core::versions::outdated_dependencies;
 use crucible_core::{Generator, Parser as CrucibleParser, Validator};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
+/// Paths listed by `graph path --all` and `graph why` before the rest are omitted
+const PATH_LIMIT: usize = 100;
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -50,6 +59,10 @@ enum Commands {
         /// Enable strict validation
         #[arg(long)]
         strict: bool,
+
+        /// Record current issues in .crucible/baseline.json instead of failing on them
+        #[arg(long)]
+        write_baseline: bool,
     },
 
     /// Generate code from architecture
@@ -67,13 +80,37 @@ enum Commands {
         output: PathBuf,
     },
 
-    /// Show dependency graph
+    /// Show dependency graph, or query it
     Graph {
-        /// Output format (text, dot, svg)
-        #[arg(long, default_value = "text")]
+        #[command(subcommand)]
+        query: Option<GraphQuery>,
+
+        /// Path to .crucible directory
+        #[arg(long, default_value = ".crucible", global = true)]
+        path: PathBuf,
+
+        /// Output format (text, dot, mermaid, json; queries support text and json)
+        #[arg(long, default_value = "text", global = true)]
+        format: String,
+    },
+
+    /// Show coupling, instability and abstractness metrics per module
+    Metrics {
+        /// Path to .crucible directory
+        #[arg(long, default_value = ".crucible")]
+        path: PathBuf,
+
+        /// Output format (table, json)
+        #[arg(long, default_value = "table")]
         format: String,
     },
 
+    /// Inspect module dependencies
+    Deps {
+        #[command(subcommand)]
+        command: DepsCommands,
+    },
+
     /// Claude Code integration commands
     Claude {
         #[command(subcommand)]
@@ -91,6 +128,41 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum GraphQuery {
+    /// Show the shortest dependency path from one module to another
+    Path {
+        from: String,
+        to: String,
+
+        /// List every path instead of the shortest
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// List the modules that depend on a module, directly or transitively
+    Dependents { module: String },
+
+    /// List the modules a module depends on, directly or transitively
+    Dependencies { module: String },
+
+    /// Explain why one module depends on another
+    Why { from: String, to: String },
+
+    /// Show the method-level call graph, or the part reachable from a method or export
+    Calls { from: Option<String> },
+}
+
+#[derive(Subcommand)]
+enum DepsCommands {
+    /// List dependency constraints that lag behind current module versions
+    Outdated {
+        /// Path to .crucible directory
+        #[arg(long, default_value = ".crucible")]
+        path: PathBuf,
+    },
+}
+
 #[derive(Subcommand)]
 enum ClaudeCommands {
     /// Initialize Claude Code integration
@@ -159,16 +231,33 @@ fn main() -> Result<()> {
             }
             init_project(name.as_deref(), here, force, &language, &pattern)?;
         }
-        Commands::Validate { path, strict } => {
-            validate_project(&path, strict)?;
+        Commands::Validate {
+            path,
+            strict,
+            write_baseline,
+        } => {
+            validate_project(&path, strict, write_baseline)?;
         }
         Commands::Generate { path, lang, output } => {
             generate_code(&path, &lang, &output)?;
         }
-        Commands::Graph { format } => {
-            println!("Graph generation not yet implemented");
-            println!("Format: {format}");
+        Commands::Graph {
+            query,
+            path,
+            format,
+        } => match query {
+            None => show_graph(&path, &format)?,
+            Some(GraphQuery::Calls { from }) => show_call_graph(&path, &format, from.as_deref())?,
+            Some(query) => query_graph(&path, &format, query)?,
+        },
+        Commands::Metrics { path, format } => {
+            show_metrics(&path, &format)?;
         }
+        Commands::Deps { command } => match command {
+            DepsCommands::Outdated { path } => {
+                deps_outdated(&path)?;
+            }
+        },
         Commands::Claude { command } => match command {
             ClaudeCommands::Init {
                 mode,
@@ -464,7 +553,8 @@ fn create_example_modules(project_path: &Path) -> Result<()> {
         "email": {"type": "string", "required": true},
         "name": {"type": "string", "required": true},
         "createdAt": {"type": "Date", "required": true},
-        "isActive": {"type": "boolean", "required": true}
+        "isActive": {"type": "boolean", "required": true},
+        "role": {"type": "UserRole", "required": true}
       }
     },
     "UserRole": {
@@ -599,6 +689,7 @@ fn create_example_modules(project_path: &Path) -> Result<()> {
     "UserController": {
       "type": "class",
       "description": "RESTful API endpoints for user management",
+      "entry_point": true,
       "methods": {
         "createUser": {
           "inputs": [
@@ -606,6 +697,7 @@ fn create_example_modules(project_path: &Path) -> Result<()> {
           ],
           "returns": {"type": "HttpResponse"},
           "throws": [],
+          "handles": ["InvalidEmailError", "UserAlreadyExistsError"],
           "calls": ["user-service.UserService.createUser"],
           "effects": ["sends HTTP response"]
         },
@@ -618,12 +710,22 @@ fn create_example_modules(project_path: &Path) -> Result<()> {
           "calls": ["user-service.UserService.getUserById"],
           "effects": ["sends HTTP response"]
         },
+        "listUsers": {
+          "inputs": [
+            {"name": "request", "type": "HttpRequest", "optional": false}
+          ],
+          "returns": {"type": "HttpResponse"},
+          "throws": [],
+          "calls": ["user-service.UserService.getAllUsers"],
+          "effects": ["sends HTTP response"]
+        },
         "updateUser": {
           "inputs": [
             {"name": "request", "type": "HttpRequest", "optional": false}
           ],
           "returns": {"type": "HttpResponse"},
           "throws": [],
+          "handles": ["UserNotFoundError"],
           "calls": ["user-service.UserService.updateUser"],
           "effects": ["sends HTTP response"]
         },
@@ -633,6 +735,7 @@ fn create_example_modules(project_path: &Path) -> Result<()> {
           ],
           "returns": {"type": "HttpResponse"},
           "throws": [],
+          "handles": ["UserNotFoundError"],
           "calls": ["user-service.UserService.deleteUser"],
           "effects": ["sends HTTP response"]
         }
@@ -940,7 +1043,7 @@ fn create_claude_commands(project_path: &Path) -> Result<()> {
     Ok(())
 }
 
-fn validate_project(path: &Path, strict: bool) -> Result<()> {
+fn validate_project(path: &Path, strict: bool, write_baseline: bool) -> Result<()> {
     println!("{}  architecture...", "Validating".cyan().bold());
 
     let parser = CrucibleParser::new(path);
@@ -949,15 +1052,32 @@ fn validate_project(path: &Path, strict: bool) -> Result<()> {
     println!("  {} modules found", project.modules.len());
 
     let validator = Validator::new(project);
-    let result = validator.validate();
+    let mut result = validator.validate();
+
+    let baseline_path = path.join(BASELINE_FILE);
+    if write_baseline {
+        let baseline = Baseline::from_result(&result);
+        baseline.save(&baseline_path)?;
+        println!(
+            "{} {} known issue(s) to {}",
+            "Recorded".green().bold(),
+            baseline.entries.len(),
+            baseline_path.display()
+        );
+        return Ok(());
+    }
+
+    let baseline_report = if baseline_path.exists() {
+        Some(Baseline::load(&baseline_path)?.apply(&mut result))
+    } else {
+        None
+    };
 
     // Display results
     for error in &result.errors {
         println!("{} {}: {}", "✗".red(), error.rule.bold(), error.message);
 
-        if let Some(location) = &error.location {
-            println!("    {} {}", "at".dimmed(), location.cyan());
-        }
+        print_issue_location(error);
 
         // Show found vs expected if available
         if let (Some(found), Some(expected)) = (&error.found, &error.expected) {
@@ -988,9 +1108,7 @@ fn validate_project(path: &Path, strict: bool) -> Result<()> {
                 warning.message
             );
 
-            if let Some(location) = &warning.location {
-                println!("    {} {}", "at".dimmed(), location.cyan());
-            }
+            print_issue_location(warning);
 
             // Show suggestion for warnings too
             if let Some(suggestion) = &warning.suggestion {
@@ -1005,6 +1123,67 @@ fn validate_project(path: &Path, strict: bool) -> Result<()> {
         }
     }
 
+    if strict {
+        for note in &result.info {
+            println!("{} {}: {}", "ℹ".blue(), note.rule.bold(), note.message);
+            print_issue_location(note);
+            println!();
+        }
+    }
+
+    if let Some(report) = &baseline_report {
+        if !report.baselined.is_empty() {
+            println!(
+                "  {} known issue(s) ignored from {}",
+                report.baselined.len().to_string().dimmed(),
+                BASELINE_FILE
+            );
+        }
+
+        if !report.fixed.is_empty() {
+            println!(
+                "{} {} baseline issue(s) fixed:",
+                "✓".green(),
+                report.fixed.len()
+            );
+            for entry in &report.fixed {
+                match &entry.location {
+                    Some(location) => println!(
+                        "    {} {} {}",
+                        "-".dimmed(),
+                        entry.rule.bold(),
+                        location.cyan()
+                    ),
+                    None => println!("    {} {}", "-".dimmed(), entry.rule.bold()),
+                }
+            }
+            println!(
+                "    {} run {} to shrink the baseline",
+                "Tip:".cyan().bold(),
+                "crucible validate --write-baseline".bold()
+            );
+        }
+    }
+
+    if !result.suppressed.is_empty() {
+        println!(
+            "  {} issue(s) suppressed",
+            result.suppressed.len().to_string().dimmed()
+        );
+
+        if strict {
+            for suppressed in &result.suppressed {
+                println!(
+                    "    {} {} {} ({})",
+                    "-".dimmed(),
+                    suppressed.issue.rule.bold(),
+                    suppressed.scope.cyan(),
+                    suppressed.reason.dimmed()
+                );
+            }
+        }
+    }
+
     println!();
     if result.valid {
         println!("{}", "Architecture is valid!".green().bold());
@@ -1016,6 +1195,21 @@ fn validate_project(path: &Path, strict: bool) -> Result<()> {
     Ok(())
 }
 
+/// Print where an issue is, as a clickable `path:line:col` when the position is known
+fn print_issue_location(issue: &ValidationIssue) {
+    match (&issue.source, &issue.location) {
+        (Some(source), Some(location)) => println!(
+            "    {} {} {}",
+            "at".dimmed(),
+            source.to_string().cyan(),
+            format!("({location})").dimmed()
+        ),
+        (Some(source), None) => println!("    {} {}", "at".dimmed(), source.to_string().cyan()),
+        (None, Some(location)) => println!("    {} {}", "at".dimmed(), location.cyan()),
+        (None, None) => {}
+    }
+}
+
 fn generate_code(path: &Path, lang: &str, output: &Path) -> Result<()> {
     let parser = CrucibleParser::new(path);
     let project = parser.parse_project()?;
@@ -1034,6 +1228,375 @@ fn generate_code(path: &Path, lang: &str, output: &Path) -> Result<()> {
     Ok(())
 }
 
+fn show_graph(path: &Path, format: &str) -> Result<()> {
+    let parser = CrucibleParser::new(path);
+    let project = parser.parse_project()?;
+    let graph = ModuleGraph::from_project(&project);
+
+    match format {
+        "text" => {
+            println!("{}", "Module Dependency Graph:".bold());
+            println!();
+            let violation = "[layer violation]";
+            print!(
+                "{}",
+                graph
+                    .to_text()
+                    .replace(violation, &violation.red().to_string())
+            );
+        }
+        "dot" => print!("{}", graph.to_dot()),
+        "mermaid" => print!("{}", graph.to_mermaid()),
+        "json" => println!("{}", graph.to_json()?),
+        _ => {
+            eprintln!("{} Unknown graph format '{format}'", "Error:".red().bold());
+            eprintln!("  Supported formats: text, dot, mermaid, json");
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+fn query_graph(path: &Path, format: &str, query: GraphQuery) -> Result<()> {
+    if !matches!(format, "text" | "json") {
+        eprintln!(
+            "{} Graph queries support the text and json formats, not '{format}'",
+            "Error:".red().bold()
+        );
+        std::process::exit(1);
+    }
+    let json = format == "json";
+
+    let parser = CrucibleParser::new(path);
+    let project = parser.parse_project()?;
+    let graph = ModuleGraph::from_project(&project);
+
+    let modules: Vec<&String> = match &query {
+        GraphQuery::Path { from, to, .. } | GraphQuery::Why { from, to } => vec![from, to],
+        GraphQuery::Dependents { module } | GraphQuery::Dependencies { module } => vec![module],
+        GraphQuery::Calls { .. } => unreachable!("call graphs are shown by show_call_graph"),
+    };
+    for module in modules {
+        if !graph.contains(module) {
+            eprintln!("{} Module '{module}' not found", "Error:".red().bold());
+            std::process::exit(1);
+        }
+    }
+
+    match query {
+        GraphQuery::Path { from, to, all } => {
+            let (paths, truncated) = if all {
+                graph.all_paths(&from, &to, PATH_LIMIT)
+            } else {
+                (graph.shortest_path(&from, &to).into_iter().collect(), false)
+            };
+            if json {
+                let output = serde_json::json!({"paths": paths, "truncated": truncated});
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            } else if paths.is_empty() {
+                println!("No dependency path from '{from}' to '{to}'");
+            } else {
+                for path in paths {
+                    println!("{}", path.join(" → "));
+                }
+                if truncated {
+                    print_paths_omitted();
+                }
+            }
+        }
+        GraphQuery::Dependents { module } => {
+            let dependents = graph.dependents(&module);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&dependents)?);
+            } else if dependents.is_empty() {
+                println!("No modules depend on '{module}'");
+            } else {
+                println!("{}", format!("Modules depending on '{module}':").bold());
+                print_reached(&dependents);
+            }
+        }
+        GraphQuery::Dependencies { module } => {
+            let dependencies = graph.dependencies(&module);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&dependencies)?);
+            } else if dependencies.is_empty() {
+                println!("'{module}' has no dependencies");
+            } else {
+                println!("{}", format!("Modules '{module}' depends on:").bold());
+                print_reached(&dependencies);
+            }
+        }
+        GraphQuery::Why { from, to } => {
+            let explanation = explain_dependency(&project, &from, &to, PATH_LIMIT);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&explanation)?);
+                return Ok(());
+            }
+            if explanation.paths.is_empty() {
+                println!("'{from}' doesn't depend on '{to}'");
+                return Ok(());
+            }
+
+            println!(
+                "{}",
+                format!(
+                    "'{from}' depends on '{to}' through {}{} path(s):",
+                    if explanation.truncated {
+                        "more than "
+                    } else {
+                        ""
+                    },
+                    explanation.paths.len()
+                )
+                .bold()
+            );
+            println!();
+            for path in &explanation.paths {
+                println!("  {}", path.join(" → "));
+            }
+            if explanation.truncated {
+                print!("  ");
+                print_paths_omitted();
+            }
+            println!();
+            println!("{}", "Reasons:".bold());
+            for edge in &explanation.edges {
+                println!("  {} → {}", edge.from.cyan(), edge.to.cyan());
+                for reason in &edge.reasons {
+                    println!("    - {reason}");
+                }
+            }
+        }
+        GraphQuery::Calls { .. } => unreachable!("call graphs are shown by show_call_graph"),
+    }
+
+    Ok(())
+}
+
+fn show_call_graph(path: &Path, format: &str, from: Option<&str>) -> Result<()> {
+    let parser = CrucibleParser::new(path);
+    let project = parser.parse_project()?;
+    let mut graph = CallGraph::new(&project);
+
+    if let Some(from) = from {
+        let reachable = graph.reachable([from]);
+        if reachable.methods.is_empty() {
+            eprintln!(
+                "{} '{from}' isn't a method or an export with methods",
+                "Error:".red().bold()
+            );
+            std::process::exit(1);
+        }
+        graph = graph.subgraph(&reachable.methods);
+    }
+
+    match format {
+        "text" => {
+            println!("{}", "Method Call Graph:".bold());
+            println!();
+            print!("{}", graph.to_text());
+        }
+        "dot" => print!("{}", graph.to_dot()),
+        "mermaid" => print!("{}", graph.to_mermaid()),
+        "json" => println!("{}", graph.to_json()?),
+        _ => {
+            eprintln!("{} Unknown graph format '{format}'", "Error:".red().bold());
+            eprintln!("  Supported formats: text, dot, mermaid, json");
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_paths_omitted() {
+    println!(
+        "{}",
+        format!("... more paths omitted (showing the first {PATH_LIMIT})").dimmed()
+    );
+}
+
+fn print_reached(reached: &[Reached]) {
+    for module in reached {
+        let distance = if module.distance == 1 {
+            "direct".to_string()
+        } else {
+            format!("{} steps", module.distance)
+        };
+        println!("  {} {}", module.module, format!("({distance})").dimmed());
+    }
+}
+
+fn show_metrics(path: &Path, format: &str) -> Result<()> {
+    let parser = CrucibleParser::new(path);
+    let project = parser.parse_project()?;
+    let metrics = project_metrics(&project);
+
+    match format {
+        "json" => {
+            println!("{}", serde_json::to_string_pretty(&metrics)?);
+            return Ok(());
+        }
+        "table" => {}
+        _ => {
+            eprintln!(
+                "{} Unknown metrics format '{format}'",
+                "Error:".red().bold()
+            );
+            eprintln!("  Supported formats: table, json");
+            std::process::exit(1);
+        }
+    }
+
+    if metrics.is_empty() {
+        println!("No modules to measure");
+        return Ok(());
+    }
+
+    let thresholds = project
+        .rules
+        .as_ref()
+        .and_then(|rules| rules.metrics.clone())
+        .unwrap_or_default();
+    let exceeds = |value: f64, limit: Option<f64>| limit.is_some_and(|limit| value > limit);
+    let width = metrics
+        .iter()
+        .map(|m| m.module.len())
+        .max()
+        .unwrap_or(0)
+        .max("Module".len());
+
+    println!(
+        "{}",
+        format!(
+            "{:<width$}  {:>4}  {:>4}  {:>5}  {:>5}  {:>5}",
+            "Module", "Ca", "Ce", "I", "A", "D"
+        )
+        .bold()
+    );
+    for m in &metrics {
+        let cells = [
+            (
+                format!("{:>4}", m.afferent_coupling),
+                exceeds(
+                    m.afferent_coupling as f64,
+                    thresholds.max_afferent_coupling.map(|limit| limit as f64),
+                ),
+            ),
+            (
+                format!("{:>4}", m.efferent_coupling),
+                exceeds(
+                    m.efferent_coupling as f64,
+                    thresholds.max_efferent_coupling.map(|limit| limit as f64),
+                ),
+            ),
+            (
+                format!("{:>5.2}", m.instability),
+                exceeds(m.instability, thresholds.max_instability),
+            ),
+            (format!("{:>5.2}", m.abstractness), false),
+            (
+                format!("{:>5.2}", m.distance),
+                m.is_coupled() && exceeds(m.distance, thresholds.max_distance),
+            ),
+        ];
+        let cells: Vec<String> = cells
+            .into_iter()
+            .map(|(cell, over)| {
+                if over {
+                    cell.yellow().bold().to_string()
+                } else {
+                    cell
+                }
+            })
+            .collect();
+        println!("{:<width$}  {}", m.module, cells.join("  "));
+    }
+
+    println!();
+    println!(
+        "{}",
+        "Ca: afferent coupling, Ce: efferent coupling, I: instability, A: abstractness, \
+         D: distance from the main sequence"
+            .dimmed()
+    );
+
+    Ok(())
+}
+
+fn deps_outdated(path: &Path) -> Result<()> {
+    let parser = CrucibleParser::new(path);
+    let project = parser.parse_project()?;
+
+    let outdated = outdated_dependencies(&project);
+    if outdated.is_empty() {
+        println!(
+            "{}",
+            "All dependency constraints are up to date".green().bold()
+        );
+        return Ok(());
+    }
+
+    let rows: Vec<[String; 4]> = outdated
+        .iter()
+        .map(|dep| {
+            [
+                dep.module.clone(),
+                dep.dependency.clone(),
+                dep.constraint.clone(),
+                dep.current.to_string(),
+            ]
+        })
+        .collect();
+    let headers = ["Module", "Dependency", "Constraint", "Current"];
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].len())
+                .chain([headers[col].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let pad = |text: &str, col: usize| format!("{text:<width$}", width = widths[col]);
+
+    println!(
+        "{}  {}  {}  {}",
+        pad(headers[0], 0).bold(),
+        pad(headers[1], 1).bold(),
+        pad(headers[2], 2).bold(),
+        headers[3].bold()
+    );
+    for (row, dep) in rows.iter().zip(&outdated) {
+        let prefix = format!(
+            "{}  {}  {}",
+            pad(&row[0], 0),
+            pad(&row[1], 1),
+            pad(&row[2], 2)
+        );
+        if dep.satisfied {
+            println!("{prefix}  {}", row[3].yellow());
+        } else {
+            println!(
+                "{prefix}  {}  {}",
+                pad(&row[3], 3).red(),
+                format!("(not satisfied, use {})", dep.suggested_constraint()).dimmed()
+            );
+        }
+    }
+
+    println!();
+    println!(
+        "  {} outdated constraint(s), {} no longer satisfied",
+        outdated.len(),
+        outdated.iter().filter(|dep| !dep.satisfied).count()
+    );
+
+    Ok(())
+}
+
 fn claude_init(mode_str: &str, _global: &bool, validation_str: &str) -> Result<()> {
     println!(
         "{}  Claude Code integration...",
@@ -259,7 +259,7 @@ impl Reporter {
             severity_str,
             violation.rule_id,
             violation.description,
-            violation.location,
+            violation.display_location(),
             violation.issue
         );
 
@@ -374,7 +374,10 @@ impl Reporter {
     fn format_markdown_violation(&self, violation: &Violation) -> String {
         let mut output = format!(
             "#### `{}`\n\n- **Location:** `{}`\n- **Description:** {}\n- **Issue:** {}\n",
-            violation.rule_id, violation.location, violation.description, violation.issue
+            violation.rule_id,
+            violation.display_location(),
+            violation.description,
+            violation.issue
         );
 
         if self.config.include_suggestions {
@@ -788,7 +791,7 @@ impl Reporter {
             html_escape(&violation.rule_id),
             severity_tag_class,
             severity_text,
-            html_escape(&violation.location),
+            html_escape(&violation.display_location()),
             html_escape(&violation.description),
             html_escape(&violation.issue)
         );
@@ -857,11 +860,21 @@ struct JsonViolation {
     severity: String,
     description: String,
     location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<JsonSource>,
     issue: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     suggestion: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+struct JsonSource {
+    file: String,
+    pointer: String,
+    line: usize,
+    column: usize,
+}
+
 impl JsonReport {
     fn from_report(report: &ValidationReport, config: &ReportConfig) -> Self {
         Self {
@@ -883,6 +896,12 @@ impl JsonReport {
                     severity: format!("{:?}", v.severity).to_lowercase(),
                     description: v.description.clone(),
                     location: v.location.clone(),
+                    source: v.source.as_ref().map(|source| JsonSource {
+                        file: source.file.display().to_string(),
+                        pointer: source.pointer.clone(),
+                        line: source.line,
+                        column: source.column,
+                    }),
                     issue: v.issue.clone(),
                     suggestion: if config.include_suggestions {
                         v.suggestion.clone()
@@ -962,6 +981,16 @@ struct SarifLocation {
 struct SarifPhysicalLocation {
     #[serde(rename = "artifactLocation")]
     artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRegion {
+    #[serde(rename = "startLine")]
+    start_line: usize,
+    #[serde(rename = "startColumn")]
+    start_column: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -998,9 +1027,21 @@ impl SarifReport {
                     text: v.issue.clone(),
                 },
                 locations: vec![SarifLocation {
-                    physical_location: SarifPhysicalLocation {
-                        artifact_location: SarifArtifactLocation {
-                            uri: v.location.clone(),
+                    physical_location: match &v.source {
+                        Some(source) => SarifPhysicalLocation {
+                            artifact_location: SarifArtifactLocation {
+                                uri: source.file.display().to_string(),
+                            },
+                            region: Some(SarifRegion {
+                                start_line: source.line,
+                                start_column: source.column,
+                            }),
+                        },
+                        None => SarifPhysicalLocation {
+                            artifact_location: SarifArtifactLocation {
+                                uri: v.location.clone(),
+                            },
+                            region: None,
                         },
                     },
                 }],
@@ -1061,6 +1102,7 @@ mod tests {
                     issue: "Method with logging effect accesses data with @phi annotations"
                         .to_string(),
                     suggestion: Some("Use redacted logging or remove PHI from logs".to_string()),
+                    source: None,
                 },
                 Violation {
                     rule_id: "phi-requires-auth".to_string(),
@@ -1070,6 +1112,7 @@ mod tests {
                     issue: "Accessing data with @phi annotations requires @requires-auth"
                         .to_string(),
                     suggestion: Some("Add @requires-auth to the method annotations".to_string()),
+                    source: None,
                 },
             ],
             rules_checked: 8,
@@ -1140,7 +1183,7 @@ mod tests {
 
         assert_eq!(parsed["version"], "2.1.0");
         assert!(parsed["$schema"].as_str().unwrap().contains("sarif"));
-        assert!(parsed["runs"].as_array().unwrap().len() > 0);
+        assert!(!parsed["runs"].as_array().unwrap().is_empty());
 
         let run = &parsed["runs"][0];
         assert!(run["tool"]["driver"]["name"]
@@ -1150,6 +1193,37 @@ mod tests {
         assert_eq!(run["results"].as_array().unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_source_positions_in_output() {
+        let mut report = create_test_report();
+        report.violations[0].source = Some(crucible_core::source_map::SourceLocation {
+            file: std::path::PathBuf::from(".crucible/modules/patient.json"),
+            pointer: "/exports/PatientService/methods/logPatient".to_string(),
+            line: 12,
+            column: 9,
+        });
+
+        let text =
+            Reporter::new(ReportConfig::new(OutputFormat::Text).with_color(false)).format(&report);
+        assert!(text
+            .contains(".crucible/modules/patient.json:12:9 (patient.PatientService.logPatient)"));
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&Reporter::sarif().format(&report)).unwrap();
+        let location = &sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            ".crucible/modules/patient.json"
+        );
+        assert_eq!(location["region"]["startLine"], 12);
+        assert_eq!(location["region"]["startColumn"], 9);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&Reporter::json().format(&report)).unwrap();
+        assert_eq!(json["violations"][0]["source"]["line"], 12);
+        assert!(json["violations"][1].get("source").is_none());
+    }
+
     #[test]
     fn test_markdown_format() {
         let report = create_test_report();
@@ -1265,6 +1339,7 @@ mod tests {
                 location: "module.Class.method".to_string(),
                 issue: "Issue with <dangerous> & 'quotes' and \"doubles\"".to_string(),
                 suggestion: None,
+                source: None,
             }],
             rules_checked: 1,
             modules_validated: 1,
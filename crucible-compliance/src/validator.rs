@@ -2,6 +2,7 @@
 
 use crate::error::ComplianceResult;
 use crate::framework::Framework;
+use crucible_core::source_map::SourceLocation;
 use crucible_core::types::{
     ComplianceRule, Export, Method, Module, Project, Property, Severity, ValidationCheckType,
 };
@@ -22,6 +23,18 @@ pub struct Violation {
     pub issue: String,
     /// Suggestion for fixing the violation
     pub suggestion: Option<String>,
+    /// File position of `location` in the architecture definitions
+    pub source: Option<SourceLocation>,
+}
+
+impl Violation {
+    /// Location as a clickable `path:line:col`, followed by the dotted location
+    pub fn display_location(&self) -> String {
+        match &self.source {
+            Some(source) => format!("{source} ({})", self.location),
+            None => self.location.clone(),
+        }
+    }
 }
 
 /// Report containing all validation results
@@ -116,6 +129,10 @@ impl<'a> ComplianceValidator<'a> {
             self.validate_module(module, &mut report)?;
         }
 
+        for violation in &mut report.violations {
+            violation.source = project.source_map.resolve(project, &violation.location);
+        }
+
         Ok(report)
     }
 
@@ -277,6 +294,7 @@ impl<'a> ComplianceValidator<'a> {
                             "Add {} to the property annotations",
                             check.required_annotations.join(", ")
                         )),
+                        source: None,
                     });
                 }
             }
@@ -324,6 +342,7 @@ impl<'a> ComplianceValidator<'a> {
                             check.forbidden_data.join(", ")
                         ),
                         suggestion: rule.examples.as_ref().and_then(|ex| ex.compliant.clone()),
+                        source: None,
                     });
                 } else if check.required_annotations.is_empty() {
                     // If no mitigation possible, it's always a violation
@@ -341,6 +360,7 @@ impl<'a> ComplianceValidator<'a> {
                             "Remove sensitive data from the operation or use a different approach"
                                 .to_string(),
                         ),
+                        source: None,
                     });
                 }
             }
@@ -386,6 +406,7 @@ impl<'a> ComplianceValidator<'a> {
                         "Add {} to the method's effects",
                         check.required_effects.join(", ")
                     )),
+                    source: None,
                 });
             }
         }
@@ -431,6 +452,7 @@ impl<'a> ComplianceValidator<'a> {
                         "Add {} to the method annotations",
                         check.required_annotations.join(", ")
                     )),
+                    source: None,
                 });
             }
         }
@@ -459,6 +481,7 @@ mod tests {
             modules: vec!["test".to_string()],
             strict_validation: true,
             metadata: None,
+            external_types: Vec::new(),
         }
     }
 
@@ -562,6 +585,10 @@ mod tests {
                 effects: vec!["logging".to_string()],
                 is_async: false,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -575,6 +602,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -585,12 +618,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let report = validator.validate(&project).unwrap();
@@ -629,6 +667,10 @@ mod tests {
                 effects: vec!["logging".to_string()], // Logging effect
                 is_async: false,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -642,6 +684,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -652,12 +700,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let report = validator.validate(&project).unwrap();
@@ -685,6 +738,10 @@ mod tests {
                 effects: vec![], // Missing audit.log
                 is_async: false,
                 annotations: vec!["@phi-access".to_string()], // PHI access annotation
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -698,6 +755,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -708,12 +771,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let report = validator.validate(&project).unwrap();
@@ -733,6 +801,7 @@ mod tests {
             manifest: create_test_manifest(),
             modules: vec![],
             rules: None,
+            source_map: Default::default(),
         };
 
         let report = validator.validate(&project).unwrap();
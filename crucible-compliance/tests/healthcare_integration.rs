@@ -26,6 +26,7 @@ fn create_healthcare_manifest() -> Manifest {
         ],
         strict_validation: true,
         metadata: None,
+        external_types: Vec::new(),
     }
 }
 
@@ -91,6 +92,10 @@ fn test_hipaa_compliant_patient_service() {
                 "@https-only".to_string(),    // Transport encryption
                 "@session-timeout".to_string(), // Auto logoff
             ],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -104,6 +109,12 @@ fn test_hipaa_compliant_patient_service() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -114,12 +125,17 @@ fn test_hipaa_compliant_patient_service() {
         description: Some("Patient management service".to_string()),
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -166,6 +182,10 @@ fn test_hipaa_violation_phi_in_logs() {
             effects: vec!["logging".to_string()], // Logging effect with PHI = violation
             is_async: false,
             annotations: vec![],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -179,6 +199,12 @@ fn test_hipaa_violation_phi_in_logs() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -189,12 +215,17 @@ fn test_hipaa_violation_phi_in_logs() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -234,6 +265,10 @@ fn test_hipaa_violation_missing_audit_log() {
             effects: vec![], // Missing audit.log effect
             is_async: true,
             annotations: vec!["@phi-access".to_string()], // PHI access without audit
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -247,6 +282,12 @@ fn test_hipaa_violation_missing_audit_log() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -257,12 +298,17 @@ fn test_hipaa_violation_missing_audit_log() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -324,6 +370,10 @@ fn test_pci_compliant_payment_service() {
                 "@cardholder-data-access".to_string(),
                 "@requires-auth".to_string(),
             ],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -337,6 +387,12 @@ fn test_pci_compliant_payment_service() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -347,12 +403,17 @@ fn test_pci_compliant_payment_service() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -397,6 +458,10 @@ fn test_pci_violation_pan_in_logs() {
             effects: vec!["logging".to_string()], // Logging PAN = violation
             is_async: false,
             annotations: vec![],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -410,6 +475,12 @@ fn test_pci_violation_pan_in_logs() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -420,12 +491,17 @@ fn test_pci_violation_pan_in_logs() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -487,6 +563,10 @@ fn test_soc2_compliant_user_service() {
                 "@sensitive-data-access".to_string(),
                 "@requires-auth".to_string(),
             ],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -500,6 +580,12 @@ fn test_soc2_compliant_user_service() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -510,12 +596,17 @@ fn test_soc2_compliant_user_service() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -560,6 +651,10 @@ fn test_soc2_violation_pii_in_logs() {
             effects: vec!["logging".to_string()], // Logging PII = violation
             is_async: false,
             annotations: vec![],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -573,6 +668,12 @@ fn test_soc2_violation_pii_in_logs() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -583,12 +684,17 @@ fn test_soc2_violation_pii_in_logs() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -655,6 +761,10 @@ fn test_multi_framework_healthcare_project() {
                 "@https-only".to_string(),      // HIPAA transport encryption
                 "@session-timeout".to_string(), // HIPAA auto logoff
             ],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -668,6 +778,12 @@ fn test_multi_framework_healthcare_project() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -678,12 +794,17 @@ fn test_multi_framework_healthcare_project() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     // Validate against both frameworks
@@ -743,6 +864,10 @@ fn test_reporter_formats() {
             effects: vec!["logging".to_string()],
             is_async: false,
             annotations: vec![],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -756,6 +881,12 @@ fn test_reporter_formats() {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -766,12 +897,17 @@ fn test_reporter_formats() {
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     };
 
     let project = Project {
         manifest: create_healthcare_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
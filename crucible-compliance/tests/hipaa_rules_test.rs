@@ -26,6 +26,7 @@ fn create_test_manifest() -> Manifest {
         modules: vec!["test".to_string()],
         strict_validation: true,
         metadata: None,
+        external_types: Vec::new(),
     }
 }
 
@@ -46,6 +47,12 @@ fn create_module(
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -56,6 +63,10 @@ fn create_module(
         description: None,
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     }
 }
 
@@ -82,6 +93,10 @@ fn method(effects: Vec<&str>, annotations: Vec<&str>) -> Method {
         effects: effects.iter().map(|s| s.to_string()).collect(),
         is_async: false,
         annotations: annotations.iter().map(|s| s.to_string()).collect(),
+        suppress: Vec::new(),
+        handles: Vec::new(),
+        emits: Vec::new(),
+        subscribes: Vec::new(),
     }
 }
 
@@ -107,6 +122,7 @@ fn test_rule_no_phi_in_logs() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -140,6 +156,7 @@ fn test_rule_no_phi_in_logs_console_log() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -170,6 +187,7 @@ fn test_rule_no_phi_in_error_messages() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -200,6 +218,7 @@ fn test_rule_no_phi_in_urls() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -233,6 +252,7 @@ fn test_rule_no_phi_in_local_storage() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -263,6 +283,7 @@ fn test_rule_no_phi_in_cookies() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -296,6 +317,7 @@ fn test_rule_no_phi_in_cache() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -330,6 +352,7 @@ fn test_rule_phi_access_requires_audit() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -360,6 +383,7 @@ fn test_rule_phi_access_requires_audit_compliant() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -390,6 +414,7 @@ fn test_rule_phi_modification_requires_audit() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -420,6 +445,7 @@ fn test_rule_phi_disclosure_requires_audit() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -454,6 +480,7 @@ fn test_rule_phi_requires_authentication() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -487,6 +514,7 @@ fn test_rule_phi_requires_authorization() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -514,6 +542,7 @@ fn test_rule_phi_storage_encryption() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -541,6 +570,7 @@ fn test_rule_phi_storage_encryption_compliant() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -574,6 +604,7 @@ fn test_rule_phi_transmission_encryption() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -607,6 +638,7 @@ fn test_rule_phi_email_encryption() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -646,6 +678,7 @@ fn test_rule_session_timeout() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -681,6 +714,7 @@ fn test_rule_unique_user_identification() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -715,6 +749,7 @@ fn test_rule_failed_login_monitoring() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -750,6 +785,7 @@ fn test_rule_account_lockout() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -780,6 +816,7 @@ fn test_rule_password_complexity() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -814,6 +851,7 @@ fn test_rule_phi_integrity_verification() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -849,6 +887,7 @@ fn test_rule_phi_deletion_authorization() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -891,6 +930,7 @@ fn test_rule_emergency_access_procedure() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -921,6 +961,7 @@ fn test_rule_emergency_access_audit() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -955,6 +996,7 @@ fn test_rule_security_incident_logging() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -985,6 +1027,7 @@ fn test_rule_breach_notification() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1024,6 +1067,7 @@ fn test_rule_access_termination() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1066,6 +1110,7 @@ fn test_rule_mobile_device_security() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1104,6 +1149,7 @@ fn test_rule_remote_access_security() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1138,6 +1184,7 @@ fn test_rule_business_associate_data() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1176,6 +1223,7 @@ fn test_rule_subcontractor_security() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1218,6 +1266,7 @@ fn test_rule_phi_export_control() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1257,6 +1306,7 @@ fn test_rule_input_validation() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1292,6 +1342,7 @@ fn test_rule_sql_injection_prevention() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1327,6 +1378,7 @@ fn test_rule_xss_prevention() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1366,6 +1418,7 @@ fn test_rule_encryption_key_management() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1408,6 +1461,7 @@ fn test_rule_contingency_backup() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1446,6 +1500,7 @@ fn test_rule_contingency_recovery() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1496,6 +1551,10 @@ fn test_fully_compliant_phi_access() {
                 "@session-timeout".to_string(),
                 "@user-identified".to_string(),
             ],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -1504,6 +1563,7 @@ fn test_fully_compliant_phi_access() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1581,6 +1641,10 @@ fn test_compliant_authentication_service() {
                 "@account-lockout".to_string(),
                 "@password-policy".to_string(),
             ],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -1589,6 +1653,7 @@ fn test_compliant_authentication_service() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1637,6 +1702,10 @@ fn test_compliant_emergency_access() {
             effects: vec!["audit.log".to_string()],
             is_async: true,
             annotations: vec!["@phi-emergency".to_string(), "@break-glass".to_string()],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -1645,6 +1714,7 @@ fn test_compliant_emergency_access() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -1686,6 +1756,10 @@ fn test_compliant_security_incident_handling() {
             effects: vec!["incident.log".to_string()],
             is_async: true,
             annotations: vec!["@security-incident".to_string()],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
     methods.insert(
@@ -1701,6 +1775,10 @@ fn test_compliant_security_incident_handling() {
             effects: vec!["breach.notify".to_string()],
             is_async: true,
             annotations: vec!["@breach-confirmed".to_string()],
+            suppress: Vec::new(),
+            handles: Vec::new(),
+            emits: Vec::new(),
+            subscribes: Vec::new(),
         },
     );
 
@@ -1709,6 +1787,7 @@ fn test_compliant_security_incident_handling() {
         manifest: create_test_manifest(),
         modules: vec![module],
         rules: None,
+        source_map: Default::default(),
     };
 
     let report = validator.validate(&project).unwrap();
@@ -0,0 +1,287 @@
+//! Validation baseline for gradual adoption
+//!
+//! A baseline records the issues a project had at one point in time, so that
+//! only issues introduced afterwards fail validation. Entries are keyed by rule
+//! id and a fingerprint of the issue location and message; line numbers are
+//! deliberately left out so unrelated edits don't invalidate the baseline.
+
+use crate::error::{CrucibleError, Result};
+use crate::validator::{ValidationIssue, ValidationResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// File name of the baseline inside the `.crucible` directory
+pub const BASELINE_FILE: &str = "baseline.json";
+
+/// Known issues accepted at the time the baseline was written
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Baseline {
+    pub entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BaselineEntry {
+    pub rule: String,
+    pub fingerprint: String,
+    /// Location of the issue, kept for readability of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Message of the issue, kept for readability of the file
+    pub message: String,
+}
+
+impl BaselineEntry {
+    fn from_issue(issue: &ValidationIssue) -> Self {
+        Self {
+            rule: issue.rule.clone(),
+            fingerprint: fingerprint(issue),
+            location: issue.location.clone(),
+            message: issue.message.clone(),
+        }
+    }
+
+    fn key(&self) -> (&str, &str) {
+        (&self.rule, &self.fingerprint)
+    }
+}
+
+/// Outcome of comparing a validation result against a baseline
+#[derive(Debug, Clone, Default)]
+pub struct BaselineReport {
+    /// Issues that were already recorded in the baseline
+    pub baselined: Vec<ValidationIssue>,
+    /// Baseline entries that no longer occur and can be removed
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Record the errors and warnings of a validation result
+    pub fn from_result(result: &ValidationResult) -> Self {
+        let mut entries: Vec<_> = result
+            .errors
+            .iter()
+            .chain(&result.warnings)
+            .map(BaselineEntry::from_issue)
+            .collect();
+        entries.sort_by(|a, b| a.key().cmp(&b.key()));
+        Self { entries }
+    }
+
+    /// Load a baseline file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| CrucibleError::FileRead {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| CrucibleError::ParseError {
+            file: BASELINE_FILE.to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Write the baseline as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|e| CrucibleError::ParseError {
+                file: BASELINE_FILE.to_string(),
+                message: e.to_string(),
+            })?;
+
+        fs::write(path, content + "\n").map_err(|e| CrucibleError::FileWrite {
+            path: path.display().to_string(),
+            source: e,
+        })
+    }
+
+    /// Remove baselined errors and warnings from `result`
+    ///
+    /// The result stays invalid only if errors remain that the baseline
+    /// doesn't know about. Each entry accepts a single occurrence, so a second
+    /// identical issue is still reported as new.
+    pub fn apply(&self, result: &mut ValidationResult) -> BaselineReport {
+        let mut remaining: HashMap<(String, String), Vec<&BaselineEntry>> = HashMap::new();
+        for entry in &self.entries {
+            remaining
+                .entry((entry.rule.clone(), entry.fingerprint.clone()))
+                .or_default()
+                .push(entry);
+        }
+
+        let mut report = BaselineReport::default();
+
+        for issues in [&mut result.errors, &mut result.warnings] {
+            let mut kept = Vec::new();
+
+            for issue in issues.drain(..) {
+                let key = (issue.rule.clone(), fingerprint(&issue));
+                let known = remaining.get_mut(&key).and_then(|entries| entries.pop());
+
+                match known {
+                    Some(_) => report.baselined.push(issue),
+                    None => kept.push(issue),
+                }
+            }
+
+            *issues = kept;
+        }
+
+        result.valid = result.errors.is_empty();
+
+        report.fixed = remaining.into_values().flatten().cloned().collect();
+        report.fixed.sort_by(|a, b| a.key().cmp(&b.key()));
+        report
+    }
+}
+
+/// Stable fingerprint of an issue's location and message
+///
+/// Uses 64-bit FNV-1a rather than `DefaultHasher`, whose output may change
+/// between Rust releases.
+fn fingerprint(issue: &ValidationIssue) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let location = issue.location.as_deref().unwrap_or("");
+    for byte in location
+        .bytes()
+        .chain(std::iter::once(0))
+        .chain(issue.message.bytes())
+    {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+    use crate::types::Severity;
+    use tempfile::tempdir;
+
+    fn issue(rule: &str, location: &str, message: &str) -> ValidationIssue {
+        ValidationIssue::new(
+            rule.to_string(),
+            Severity::Error,
+            message.to_string(),
+            Some(location.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let a = issue("all-types-exist", "auth.User", "Type 'Foo' not found");
+        let b = issue("all-types-exist", "auth.User", "Type 'Foo' not found");
+        let c = issue("all-types-exist", "auth.User", "Type 'Bar' not found");
+        assert_eq!(fingerprint(&a), fingerprint(&b));
+        assert_ne!(fingerprint(&a), fingerprint(&c));
+        assert_eq!(fingerprint(&a).len(), 16);
+    }
+
+    #[test]
+    fn test_duplicate_issues_need_one_entry_each() {
+        let mut result = ValidationResult {
+            valid: false,
+            errors: vec![issue("r", "m", "msg")],
+            warnings: Vec::new(),
+            info: Vec::new(),
+            suppressed: Vec::new(),
+            validated_modules: Vec::new(),
+        };
+        let baseline = Baseline::from_result(&result);
+
+        result.errors.push(issue("r", "m", "msg"));
+        let report = baseline.apply(&mut result);
+
+        assert_eq!(report.baselined.len(), 1);
+        assert_eq!(result.errors.len(), 1);
+        assert!(!result.valid);
+    }
+
+    /// Module `a` declares a dependency on `b`; `calls` adds broken calls
+    fn validate(calls: &str) -> ValidationResult {
+        let module_a = format!(
+            r#"{{
+            "module": "a",
+            "version": "1.0.0",
+            "exports": {{
+                "Service": {{
+                    "type": "class",
+                    "methods": {{
+                        "run": {{"inputs": [], "returns": {{"type": "void"}}, "calls": {calls}}}
+                    }}
+                }}
+            }},
+            "dependencies": {{"b": "^1.0.0"}}
+        }}"#
+        );
+        let module_b = r#"{
+            "module": "b",
+            "version": "1.0.0",
+            "exports": {
+                "Store": {
+                    "type": "class",
+                    "methods": {"save": {"inputs": [], "returns": {"type": "void"}}}
+                }
+            },
+            "dependencies": {}
+        }"#;
+
+        TestProject::new("typescript")
+            .module(module_a)
+            .module(module_b)
+            .validate()
+    }
+
+    #[test]
+    fn test_baselined_issues_do_not_fail() {
+        let dir = tempdir().unwrap();
+        let result = validate(r#"["a.Service.missing"]"#);
+        assert!(!result.valid);
+
+        let path = dir.path().join("baseline.json");
+        Baseline::from_result(&result).save(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        let mut result = validate(r#"["a.Service.missing"]"#);
+        let report = baseline.apply(&mut result);
+
+        assert!(result.valid);
+        assert!(result.errors.is_empty());
+        assert!(result.warnings.is_empty());
+        assert_eq!(report.baselined.len(), 2);
+        assert!(report.fixed.is_empty());
+    }
+
+    #[test]
+    fn test_new_issues_still_fail() {
+        let baseline = Baseline::from_result(&validate(r#"["a.Service.missing"]"#));
+
+        let mut result = validate(r#"["a.Service.missing", "a.Service.alsoMissing"]"#);
+        baseline.apply(&mut result);
+
+        assert!(!result.valid);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("alsoMissing"));
+    }
+
+    #[test]
+    fn test_fixed_entries_are_reported() {
+        let baseline = Baseline::from_result(&validate(r#"["a.Service.missing"]"#));
+
+        let mut result = validate(r#"["b.Store.save"]"#);
+        let report = baseline.apply(&mut result);
+
+        assert!(result.valid);
+        let rules: Vec<_> = report
+            .fixed
+            .iter()
+            .map(|entry| entry.rule.as_str())
+            .collect();
+        assert_eq!(rules.len(), 2);
+        assert!(rules.contains(&"all-calls-must-exist"));
+        assert!(rules.contains(&"declared-dependencies-must-be-used"));
+    }
+}
@@ -9,8 +9,9 @@ use std::time::SystemTime;
 
 /// Cache for parsed module definitions to avoid repeated parsing
 pub struct ArchitectureCache {
-    /// Cached modules with their last modified time
-    modules: HashMap<PathBuf, (Module, SystemTime)>,
+    /// Cached modules with the source they were parsed from and its last
+    /// modified time
+    modules: HashMap<PathBuf, (Module, String, SystemTime)>,
 
     /// Cached project manifest with last modified time
     project: Option<(Project, SystemTime)>,
@@ -46,13 +47,13 @@ impl ArchitectureCache {
         }
     }
 
-    /// Get a cached module if it's still valid
-    pub fn get_module(&self, path: &Path) -> Result<Option<Module>> {
+    /// Get a cached module and its source if they're still valid
+    pub fn get_module(&self, path: &Path) -> Result<Option<(Module, String)>> {
         if !self.enabled {
             return Ok(None);
         }
 
-        if let Some((module, cached_time)) = self.modules.get(path) {
+        if let Some((module, source, cached_time)) = self.modules.get(path) {
             // Check if file has been modified since caching
             let metadata = fs::metadata(path).map_err(|e| CrucibleError::FileRead {
                 path: path.display().to_string(),
@@ -66,15 +67,15 @@ impl ArchitectureCache {
 
             if modified <= *cached_time {
                 // Cache is still valid
-                return Ok(Some(module.clone()));
+                return Ok(Some((module.clone(), source.clone())));
             }
         }
 
         Ok(None)
     }
 
-    /// Cache a module definition
-    pub fn cache_module(&mut self, path: PathBuf, module: Module) -> Result<()> {
+    /// Cache a module definition with the source it was parsed from
+    pub fn cache_module(&mut self, path: PathBuf, module: Module, source: String) -> Result<()> {
         if !self.enabled {
             return Ok(());
         }
@@ -89,7 +90,7 @@ impl ArchitectureCache {
             source: e,
         })?;
 
-        self.modules.insert(path, (module, modified));
+        self.modules.insert(path, (module, source, modified));
         Ok(())
     }
 
@@ -192,6 +193,10 @@ mod tests {
             description: Some("Test".to_string()),
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -199,7 +204,9 @@ mod tests {
         fs::write(&path, "{}").unwrap();
 
         // Caching should do nothing when disabled
-        cache.cache_module(path.clone(), module.clone()).unwrap();
+        cache
+            .cache_module(path.clone(), module.clone(), "{}".to_string())
+            .unwrap();
         assert_eq!(cache.modules.len(), 0);
 
         // Getting should return None when disabled
@@ -218,6 +225,10 @@ mod tests {
             description: Some("Test".to_string()),
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -225,12 +236,15 @@ mod tests {
         fs::write(&path, "{}").unwrap();
 
         // Cache the module
-        cache.cache_module(path.clone(), module.clone()).unwrap();
+        cache
+            .cache_module(path.clone(), module.clone(), "{}".to_string())
+            .unwrap();
         assert_eq!(cache.modules.len(), 1);
 
         // Should return cached module
-        let cached = cache.get_module(&path).unwrap().unwrap();
+        let (cached, source) = cache.get_module(&path).unwrap().unwrap();
         assert_eq!(cached.module, "test");
+        assert_eq!(source, "{}");
     }
 
     #[test]
@@ -244,6 +258,10 @@ mod tests {
             description: Some("Test".to_string()),
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let temp_dir = TempDir::new().unwrap();
@@ -251,7 +269,9 @@ mod tests {
         fs::write(&path, "{}").unwrap();
 
         // Cache the module
-        cache.cache_module(path.clone(), module).unwrap();
+        cache
+            .cache_module(path.clone(), module, "{}".to_string())
+            .unwrap();
 
         // Modify the file (this simulates a change)
         std::thread::sleep(std::time::Duration::from_millis(10));
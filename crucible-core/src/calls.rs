@@ -0,0 +1,662 @@
+//! Resolution of `calls` entries and the method-level call graph they form
+//!
+//! [`CallGraph`] has one node per method, keyed by `module.Export.method`, and
+//! one edge per resolved call. A call to a trait method also dispatches to the
+//! matching method of every implementation; those edges are kept apart from
+//! the calls, because they only matter for reachability.
+
+use crate::graph::{dot_quote, mermaid_id};
+use crate::rules::resolve_implemented;
+use crate::types::{Export, Module, Project};
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::DiGraph;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Resolve a call made from `export` to a `module.Export.method` key
+///
+/// Returns `None` for calls whose target isn't a method, such as standalone
+/// `module.function` exports.
+pub fn resolve_call(
+    module: &Module,
+    export_name: &str,
+    export: &Export,
+    call: &str,
+) -> Option<String> {
+    let parts: Vec<&str> = call.split('.').collect();
+    match parts.as_slice() {
+        [target_module, target_export, target_method] => {
+            Some(format!("{target_module}.{target_export}.{target_method}"))
+        }
+        // Self-call: "module.method" on the calling export
+        [target_module, target_method]
+            if *target_module == module.module
+                && export
+                    .methods
+                    .as_ref()
+                    .is_some_and(|methods| methods.contains_key(*target_method)) =>
+        {
+            Some(format!("{target_module}.{export_name}.{target_method}"))
+        }
+        _ => None,
+    }
+}
+
+/// Methods and the calls between them
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CallGraph {
+    /// Every method, mapped to the methods it calls
+    callees: BTreeMap<String, Vec<String>>,
+    /// Trait methods, mapped to the implementation methods they dispatch to
+    dispatches: BTreeMap<String, Vec<String>>,
+    /// Calls that don't resolve to a known method, such as standalone
+    /// functions, by the method making them
+    unresolved: BTreeMap<String, Vec<String>>,
+}
+
+/// Methods and unresolved calls reached from a set of roots
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Reachable {
+    /// Reached methods, by `module.Export.method`
+    pub methods: BTreeSet<String>,
+    /// Unresolved calls made by reached methods, such as `module.function`
+    pub unresolved: BTreeSet<String>,
+}
+
+impl Reachable {
+    /// Exports reached, either through one of their methods or as a called function
+    pub fn exports(&self) -> BTreeSet<String> {
+        self.methods
+            .iter()
+            .filter_map(|method| method.rsplit_once('.').map(|(export, _)| export))
+            .chain(
+                self.unresolved
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|call| call.split('.').count() == 2),
+            )
+            .map(String::from)
+            .collect()
+    }
+
+    /// Whether a method, export or called function was reached
+    pub fn contains(&self, target: &str) -> bool {
+        self.methods.contains(target)
+            || self.unresolved.contains(target)
+            || self
+                .methods
+                .iter()
+                .any(|method| method.rsplit_once('.').is_some_and(|(e, _)| e == target))
+    }
+}
+
+impl CallGraph {
+    /// Build the call graph of every method in the project
+    pub fn new(project: &Project) -> Self {
+        let mut graph = Self::default();
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                for method_name in export.methods.iter().flat_map(|methods| methods.keys()) {
+                    graph.callees.insert(
+                        format!("{}.{}.{}", module.module, export_name, method_name),
+                        Vec::new(),
+                    );
+                }
+            }
+        }
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                let Some(methods) = &export.methods else {
+                    continue;
+                };
+                for (method_name, method) in methods {
+                    let key = format!("{}.{}.{}", module.module, export_name, method_name);
+                    let mut targets = Vec::new();
+                    let mut unresolved = Vec::new();
+                    for call in &method.calls {
+                        match resolve_call(module, export_name, export, call) {
+                            Some(target) if graph.callees.contains_key(&target) => {
+                                targets.push(target)
+                            }
+                            _ => unresolved.push(call.clone()),
+                        }
+                    }
+                    targets.sort();
+                    targets.dedup();
+                    graph.callees.insert(key.clone(), targets);
+                    if !unresolved.is_empty() {
+                        graph.unresolved.insert(key, unresolved);
+                    }
+                }
+
+                for implemented in &export.implements {
+                    let Some((trait_module, trait_name, _)) =
+                        resolve_implemented(project, module, implemented)
+                    else {
+                        continue;
+                    };
+                    for method_name in methods.keys() {
+                        let trait_method =
+                            format!("{}.{}.{}", trait_module.module, trait_name, method_name);
+                        if graph.callees.contains_key(&trait_method) {
+                            graph
+                                .dispatches
+                                .entry(trait_method)
+                                .or_default()
+                                .push(format!("{}.{}.{}", module.module, export_name, method_name));
+                        }
+                    }
+                }
+            }
+        }
+
+        for implementations in graph.dispatches.values_mut() {
+            implementations.sort();
+            implementations.dedup();
+        }
+
+        graph
+    }
+
+    /// Every method, ordered by `module.Export.method`
+    pub fn methods(&self) -> impl Iterator<Item = &str> {
+        self.callees.keys().map(String::as_str)
+    }
+
+    /// Whether a method is in the graph
+    pub fn contains(&self, method: &str) -> bool {
+        self.callees.contains_key(method)
+    }
+
+    /// Methods a method calls directly, sorted and without duplicates
+    pub fn callees(&self, method: &str) -> &[String] {
+        self.callees.get(method).map_or(&[], Vec::as_slice)
+    }
+
+    /// Methods that call a method directly
+    pub fn callers(&self, method: &str) -> Vec<&str> {
+        self.callees
+            .iter()
+            .filter(|(_, targets)| targets.iter().any(|target| target == method))
+            .map(|(caller, _)| caller.as_str())
+            .collect()
+    }
+
+    /// Implementation methods a trait method dispatches to
+    pub fn implementations(&self, method: &str) -> &[String] {
+        self.dispatches.get(method).map_or(&[], Vec::as_slice)
+    }
+
+    /// Calls of a method that don't resolve to a known method
+    pub fn unresolved_calls(&self, method: &str) -> &[String] {
+        self.unresolved.get(method).map_or(&[], Vec::as_slice)
+    }
+
+    /// Everything reachable from the roots through calls and trait dispatch
+    ///
+    /// A root is either a method or a `module.Export`, which stands for all of
+    /// its methods. The roots themselves are part of the result.
+    pub fn reachable<'r>(&self, roots: impl IntoIterator<Item = &'r str>) -> Reachable {
+        let mut pending: Vec<&str> = Vec::new();
+        for root in roots {
+            if self.contains(root) {
+                pending.push(root);
+            } else {
+                pending.extend(self.export_methods(root));
+            }
+        }
+
+        let mut reachable = Reachable::default();
+        while let Some(method) = pending.pop() {
+            if !reachable.methods.insert(method.to_string()) {
+                continue;
+            }
+            pending.extend(self.callees(method).iter().map(String::as_str));
+            pending.extend(self.implementations(method).iter().map(String::as_str));
+            reachable
+                .unresolved
+                .extend(self.unresolved_calls(method).iter().cloned());
+        }
+        reachable
+    }
+
+    /// Whether `to`, a method or export, is reachable from `from`
+    pub fn reaches(&self, from: &str, to: &str) -> bool {
+        self.reachable([from]).contains(to)
+    }
+
+    /// Groups of mutually recursive methods
+    ///
+    /// Each group is a strongly connected component of the calls, with more
+    /// than one method or a method calling itself. Methods within a group are
+    /// sorted, and groups are ordered by their first method.
+    pub fn recursive_groups(&self) -> Vec<Vec<String>> {
+        let mut graph: DiGraph<&str, ()> = DiGraph::new();
+        let indices: HashMap<&str, _> = self
+            .methods()
+            .map(|method| (method, graph.add_node(method)))
+            .collect();
+        for (caller, targets) in &self.callees {
+            for target in targets {
+                graph.add_edge(indices[caller.as_str()], indices[target.as_str()], ());
+            }
+        }
+
+        let mut groups: Vec<Vec<String>> = tarjan_scc(&graph)
+            .into_iter()
+            .filter(|group| group.len() > 1 || graph.contains_edge(group[0], group[0]))
+            .map(|group| {
+                let mut methods: Vec<String> = group
+                    .iter()
+                    .map(|&index| graph[index].to_string())
+                    .collect();
+                methods.sort();
+                methods
+            })
+            .collect();
+        groups.sort();
+        groups
+    }
+
+    /// Recursive groups whose methods belong to more than one module
+    pub fn cross_module_recursion(&self) -> Vec<Vec<String>> {
+        self.recursive_groups()
+            .into_iter()
+            .filter(|group| {
+                let modules: HashSet<&str> = group.iter().map(|method| module_of(method)).collect();
+                modules.len() > 1
+            })
+            .collect()
+    }
+
+    /// The part of the graph made of the given methods
+    pub fn subgraph(&self, methods: &BTreeSet<String>) -> Self {
+        let keep = |entries: &BTreeMap<String, Vec<String>>, filter_targets: bool| {
+            entries
+                .iter()
+                .filter(|(method, _)| methods.contains(*method))
+                .map(|(method, targets)| {
+                    let targets = targets
+                        .iter()
+                        .filter(|target| !filter_targets || methods.contains(*target))
+                        .cloned()
+                        .collect();
+                    (method.clone(), targets)
+                })
+                .collect()
+        };
+
+        Self {
+            callees: keep(&self.callees, true),
+            dispatches: keep(&self.dispatches, true),
+            unresolved: keep(&self.unresolved, false),
+        }
+    }
+
+    /// Methods of a `module.Export`
+    fn export_methods<'g>(&'g self, export: &'g str) -> impl Iterator<Item = &'g str> {
+        self.methods()
+            .filter(move |method| method.rsplit_once('.').is_some_and(|(e, _)| e == export))
+    }
+
+    /// Calls between methods of the same recursive group
+    fn recursive_calls(&self) -> HashSet<(&str, &str)> {
+        let groups = self.recursive_groups();
+        let in_group = |caller: &str, target: &str| {
+            groups
+                .iter()
+                .any(|group| group.iter().any(|m| m == caller) && group.iter().any(|m| m == target))
+        };
+
+        self.callees
+            .iter()
+            .flat_map(|(caller, targets)| {
+                targets
+                    .iter()
+                    .map(move |target| (caller.as_str(), target.as_str()))
+            })
+            .filter(|(caller, target)| in_group(caller, target))
+            .collect()
+    }
+
+    /// Methods grouped by module, in module order
+    fn module_groups(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+        for method in self.methods() {
+            let module = module_of(method);
+            match groups.last_mut() {
+                Some((last, members)) if *last == module => members.push(method),
+                _ => groups.push((module, vec![method])),
+            }
+        }
+        groups
+    }
+
+    /// Render every method with what it calls, followed by the recursive groups
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+
+        for (method, targets) in &self.callees {
+            out.push_str(&format!("{method}\n"));
+            let children: Vec<String> = targets
+                .iter()
+                .cloned()
+                .chain(
+                    self.implementations(method)
+                        .iter()
+                        .map(|target| format!("{target} (dispatch)")),
+                )
+                .chain(
+                    self.unresolved_calls(method)
+                        .iter()
+                        .map(|call| format!("{call} (unresolved)")),
+                )
+                .collect();
+            for (index, child) in children.iter().enumerate() {
+                let branch = if index + 1 == children.len() {
+                    "└── "
+                } else {
+                    "├── "
+                };
+                out.push_str(&format!("{branch}{child}\n"));
+            }
+        }
+
+        let groups = self.recursive_groups();
+        if !groups.is_empty() {
+            out.push_str("\nRecursion:\n");
+            for group in groups {
+                let modules: BTreeSet<&str> =
+                    group.iter().map(|method| module_of(method)).collect();
+                let scope = if modules.len() > 1 {
+                    " (across modules)"
+                } else {
+                    ""
+                };
+                out.push_str(&format!("  {}{scope}\n", group.join(", ")));
+            }
+        }
+
+        out
+    }
+
+    /// Render as JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render as a Graphviz DOT digraph, clustering methods by module
+    ///
+    /// Calls within a recursive group are orange, and trait dispatch is dashed.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph calls {\n");
+        out.push_str("    rankdir=LR;\n");
+        out.push_str("    node [shape=box];\n");
+
+        for (index, (module, methods)) in self.module_groups().iter().enumerate() {
+            out.push('\n');
+            out.push_str(&format!("    subgraph cluster_{index} {{\n"));
+            out.push_str(&format!("        label={};\n", dot_quote(module)));
+            for method in methods {
+                out.push_str(&format!(
+                    "        {} [label={}];\n",
+                    dot_quote(method),
+                    dot_quote(method_label(method))
+                ));
+            }
+            out.push_str("    }\n");
+        }
+
+        let recursive = self.recursive_calls();
+        if self.callees.values().any(|targets| !targets.is_empty()) || !self.dispatches.is_empty() {
+            out.push('\n');
+        }
+        for (caller, targets) in &self.callees {
+            for target in targets {
+                let attributes = if recursive.contains(&(caller.as_str(), target.as_str())) {
+                    " [color=orange, penwidth=2]"
+                } else {
+                    ""
+                };
+                out.push_str(&format!(
+                    "    {} -> {}{attributes};\n",
+                    dot_quote(caller),
+                    dot_quote(target)
+                ));
+            }
+        }
+        for (method, implementations) in &self.dispatches {
+            for implementation in implementations {
+                out.push_str(&format!(
+                    "    {} -> {} [style=dashed, label=\"dispatch\"];\n",
+                    dot_quote(method),
+                    dot_quote(implementation)
+                ));
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render as a Mermaid flowchart, with a subgraph per module
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart LR\n");
+
+        for (index, (module, methods)) in self.module_groups().iter().enumerate() {
+            out.push_str(&format!("    subgraph module_{index} [\"{module}\"]\n"));
+            for method in methods {
+                out.push_str(&format!(
+                    "        {}[\"{}\"]\n",
+                    mermaid_id(method),
+                    method_label(method)
+                ));
+            }
+            out.push_str("    end\n");
+        }
+
+        let recursive = self.recursive_calls();
+        let mut highlighted = Vec::new();
+        let mut link = 0;
+        for (caller, targets) in &self.callees {
+            for target in targets {
+                if recursive.contains(&(caller.as_str(), target.as_str())) {
+                    highlighted.push(link.to_string());
+                }
+                out.push_str(&format!(
+                    "    {} --> {}\n",
+                    mermaid_id(caller),
+                    mermaid_id(target)
+                ));
+                link += 1;
+            }
+        }
+        for (method, implementations) in &self.dispatches {
+            for implementation in implementations {
+                out.push_str(&format!(
+                    "    {} -.->|dispatch| {}\n",
+                    mermaid_id(method),
+                    mermaid_id(implementation)
+                ));
+            }
+        }
+        if !highlighted.is_empty() {
+            out.push_str(&format!(
+                "    linkStyle {} stroke:orange,stroke-width:2px\n",
+                highlighted.join(",")
+            ));
+        }
+
+        out
+    }
+}
+
+/// Module part of a `module.Export.method` key
+fn module_of(method: &str) -> &str {
+    method.split('.').next().unwrap_or(method)
+}
+
+/// `Export.method` part of a `module.Export.method` key
+fn method_label(method: &str) -> &str {
+    method.split_once('.').map_or(method, |(_, rest)| rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+    use crate::types::Manifest;
+
+    /// `api.Handler.handle` calls into `service`, whose `walk` and `visit` are
+    /// mutually recursive with `tree.Tree.visit`; `service.Store` is a trait
+    /// implemented by `service.MemoryStore`
+    fn project() -> Project {
+        let manifest: Manifest = serde_json::from_str(
+            r#"{"version": "0.1.0", "project": {"name": "test", "language": "typescript"}, "modules": ["api", "service", "tree"]}"#,
+        )
+        .unwrap();
+        let modules = [
+            r#"{"module": "api", "version": "1.0.0", "dependencies": {}, "exports": {
+                "Handler": {"type": "class", "methods": {
+                    "handle": {"inputs": [], "returns": {"type": "void"},
+                               "calls": ["service.Service.walk", "service.Store.save", "api.log"]},
+                    "log": {"inputs": [], "returns": {"type": "void"}, "calls": ["util.format"]}
+                }}
+            }}"#,
+            r#"{"module": "service", "version": "1.0.0", "dependencies": {}, "exports": {
+                "Service": {"type": "class", "methods": {
+                    "walk": {"inputs": [], "returns": {"type": "void"}, "calls": ["tree.Tree.visit"]}
+                }},
+                "Store": {"type": "trait", "methods": {"save": {"inputs": [], "returns": {"type": "void"}}}},
+                "MemoryStore": {"type": "class", "implements": ["Store"], "methods": {
+                    "save": {"inputs": [], "returns": {"type": "void"}}
+                }}
+            }}"#,
+            r#"{"module": "tree", "version": "1.0.0", "dependencies": {}, "exports": {
+                "Tree": {"type": "class", "methods": {
+                    "visit": {"inputs": [], "returns": {"type": "void"},
+                              "calls": ["service.Service.walk", "tree.visit"]},
+                    "size": {"inputs": [], "returns": {"type": "number"}}
+                }}
+            }}"#,
+        ];
+
+        Project {
+            manifest,
+            modules: modules
+                .iter()
+                .map(|module| serde_json::from_str(module).unwrap())
+                .collect(),
+            rules: None,
+            source_map: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_calls_resolved_to_methods() {
+        let graph = CallGraph::new(&project());
+
+        assert_eq!(
+            graph.callees("api.Handler.handle"),
+            [
+                "api.Handler.log",
+                "service.Service.walk",
+                "service.Store.save"
+            ]
+        );
+        assert_eq!(graph.unresolved_calls("api.Handler.log"), ["util.format"]);
+        assert_eq!(
+            graph.implementations("service.Store.save"),
+            ["service.MemoryStore.save"]
+        );
+        assert_eq!(
+            graph.callers("service.Service.walk"),
+            vec!["api.Handler.handle", "tree.Tree.visit"]
+        );
+    }
+
+    #[test]
+    fn test_reachability_follows_calls_and_dispatch() {
+        let graph = CallGraph::new(&project());
+        let reachable = graph.reachable(["api.Handler"]);
+
+        assert!(reachable.contains("service.MemoryStore.save"));
+        assert!(reachable.contains("tree.Tree"));
+        assert!(reachable.contains("util.format"));
+        assert!(!reachable.contains("tree.Tree.size"));
+        assert!(reachable.exports().contains("util.format"));
+        assert!(graph.reaches("service.Service.walk", "tree.Tree.visit"));
+        assert!(!graph.reaches("tree.Tree.size", "service.Service"));
+    }
+
+    #[test]
+    fn test_recursive_groups() {
+        let graph = CallGraph::new(&project());
+
+        assert_eq!(
+            graph.recursive_groups(),
+            vec![vec!["service.Service.walk", "tree.Tree.visit"]]
+        );
+        assert_eq!(graph.cross_module_recursion().len(), 1);
+        assert!(graph
+            .to_text()
+            .contains("service.Service.walk, tree.Tree.visit (across modules)"));
+    }
+
+    #[test]
+    fn test_implementations_in_other_modules_reached() {
+        let project = TestProject::new("typescript")
+            .module(
+                r#"{"module": "api", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "Handler": {"type": "class", "entry_point": true, "methods": {
+                        "handle": {"inputs": [], "returns": {"type": "void"}, "calls": ["service.Store.save"]}
+                    }}
+                }}"#,
+            )
+            .module(
+                r#"{"module": "service", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "Store": {"type": "trait", "methods": {"save": {"inputs": [], "returns": {"type": "void"}}}}
+                }}"#,
+            )
+            .module(
+                r#"{"module": "storage", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "DiskStore": {"type": "class", "implements": ["service.Store"], "methods": {
+                        "save": {"inputs": [], "returns": {"type": "void"}}
+                    }}
+                }}"#,
+            )
+            .build();
+        let graph = CallGraph::new(&project);
+
+        assert_eq!(
+            graph.implementations("service.Store.save"),
+            ["storage.DiskStore.save"]
+        );
+        assert!(graph
+            .reachable(["api.Handler"])
+            .contains("storage.DiskStore.save"));
+    }
+
+    #[test]
+    fn test_call_graph_to_dot_and_mermaid() {
+        let graph = CallGraph::new(&project());
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("label=\"tree\";"));
+        assert!(dot.contains("\"tree.Tree.visit\" [label=\"Tree.visit\"];"));
+        assert!(dot.contains(
+            "\"tree.Tree.visit\" -> \"service.Service.walk\" [color=orange, penwidth=2];"
+        ));
+        assert!(dot.contains("\"api.Handler.handle\" -> \"api.Handler.log\";"));
+        assert!(dot.contains(
+            "\"service.Store.save\" -> \"service.MemoryStore.save\" [style=dashed, label=\"dispatch\"];"
+        ));
+
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("subgraph module_2 [\"tree\"]"));
+        assert!(mermaid.contains("service_Store_save -.->|dispatch| service_MemoryStore_save"));
+        assert!(mermaid.contains("linkStyle 3,4,5 stroke:orange,stroke-width:2px"));
+    }
+}
@@ -393,6 +393,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -403,6 +409,10 @@ mod tests {
             layer: Some("application".to_string()),
             exports: auth_exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         });
 
         Project {
@@ -416,9 +426,11 @@ mod tests {
                 modules: vec!["auth".to_string()],
                 strict_validation: false,
                 metadata: None,
+                external_types: Vec::new(),
             },
             modules,
             rules: None,
+            source_map: Default::default(),
         }
     }
 
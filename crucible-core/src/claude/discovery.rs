@@ -1,6 +1,7 @@
 //! Architecture discovery from existing codebases
 
 use crate::error::Result;
+use crate::source_map::SourceMap;
 use crate::types::{ArchitecturePattern, Language, Manifest, Project, ProjectConfig};
 use std::path::Path;
 
@@ -38,12 +39,14 @@ impl ArchitectureDiscovery {
             modules: vec![],
             strict_validation: true,
             metadata: None,
+            external_types: Vec::new(),
         };
 
         Ok(Project {
             manifest,
             modules: vec![],
             rules: None,
+            source_map: SourceMap::new(),
         })
     }
 
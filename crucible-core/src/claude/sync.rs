@@ -491,9 +491,11 @@ mod tests {
                 modules: vec![],
                 strict_validation: false,
                 metadata: None,
+                external_types: Vec::new(),
             },
             modules: vec![],
             rules: None,
+            source_map: Default::default(),
         };
 
         let _manager = SyncManager::new(project);
@@ -255,8 +255,8 @@ impl ValidationHooks {
                 ));
                 content.push_str(&format!("**Message**: {}\n\n", error.message));
 
-                if let Some(location) = &error.location {
-                    content.push_str(&format!("**Location**: `{location}`\n\n"));
+                if let Some(location) = describe_location(error) {
+                    content.push_str(&format!("**Location**: {location}\n\n"));
                 }
 
                 // Generate and add suggestions
@@ -284,8 +284,8 @@ impl ValidationHooks {
 
             for warning in &result.warnings {
                 content.push_str(&format!("- **{}**: {}\n", warning.rule, warning.message));
-                if let Some(location) = &warning.location {
-                    content.push_str(&format!("  *Location*: `{location}`\n"));
+                if let Some(location) = describe_location(warning) {
+                    content.push_str(&format!("  *Location*: {location}\n"));
                 }
 
                 // Add brief suggestions for warnings
@@ -331,7 +331,7 @@ impl ValidationHooks {
             content.push_str("## ❌ Validation Errors\n\n");
             for error in &result.errors {
                 content.push_str(&format!("- **{}**: {}\n", error.rule, error.message));
-                if let Some(location) = &error.location {
+                if let Some(location) = describe_location(error) {
                     content.push_str(&format!("  Location: {location}\n"));
                 }
             }
@@ -342,7 +342,7 @@ impl ValidationHooks {
             content.push_str("## ⚠️ Warnings\n\n");
             for warning in &result.warnings {
                 content.push_str(&format!("- **{}**: {}\n", warning.rule, warning.message));
-                if let Some(location) = &warning.location {
+                if let Some(location) = describe_location(warning) {
                     content.push_str(&format!("  Location: {location}\n"));
                 }
             }
@@ -352,6 +352,16 @@ impl ValidationHooks {
     }
 }
 
+/// Dotted location of an issue followed by its file position, if known
+fn describe_location(issue: &ValidationIssue) -> Option<String> {
+    match (&issue.location, &issue.source) {
+        (Some(location), Some(source)) => Some(format!("`{location}` ({source})")),
+        (Some(location), None) => Some(format!("`{location}`")),
+        (None, Some(source)) => Some(source.to_string()),
+        (None, None) => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,6 +380,7 @@ mod tests {
                 modules: vec!["auth".to_string()],
                 strict_validation: false,
                 metadata: None,
+                external_types: Vec::new(),
             },
             modules: vec![Module {
                 module: "auth".to_string(),
@@ -378,8 +389,13 @@ mod tests {
                 layer: Some("application".to_string()),
                 exports: HashMap::new(),
                 dependencies: HashMap::new(),
+                suppress: Vec::new(),
+                role: None,
+                service: None,
+                contracts: false,
             }],
             rules: None,
+            source_map: Default::default(),
         }
     }
 
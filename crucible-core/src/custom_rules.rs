@@ -0,0 +1,714 @@
+//! Custom rule engine for the `custom_rules` section of rules.json
+//!
+//! Supported rule types:
+//! - `naming-convention`: names of the target must match `pattern`
+//! - `max-dependencies`: the target may not exceed `config.max` dependencies
+//! - `no-dependency`: targets whose name (or layer, for modules) matches
+//!   `pattern` must not depend on other modules
+//!
+//! Every rule can be narrowed with the optional `config.layer`,
+//! `config.module` (regex on the module name), `config.export_type` and
+//! `config.name` (regex on the target's own name) keys.
+
+use crate::types::{CustomRule, Export, ExportType, Module, Project, Severity};
+use crate::validator::ValidationIssue;
+use regex::Regex;
+use std::collections::HashSet;
+
+const CUSTOM_RULES_DOC: &str =
+    "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#architectural-rules-rulesjson";
+
+/// Architecture element a custom rule is evaluated against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleTarget {
+    Module,
+    Export,
+    Method,
+    Property,
+}
+
+impl RuleTarget {
+    /// Parse the `target` field of a custom rule
+    pub fn parse(target: &str) -> Option<Self> {
+        match target {
+            "module" => Some(Self::Module),
+            "export" => Some(Self::Export),
+            "method" => Some(Self::Method),
+            "property" => Some(Self::Property),
+            _ => None,
+        }
+    }
+}
+
+/// A named architecture element matched by a rule target
+struct Subject<'a> {
+    name: &'a str,
+    location: String,
+    module: &'a Module,
+    export: Option<&'a Export>,
+    /// Calls made by the subject (methods only)
+    calls: Option<&'a [String]>,
+}
+
+/// Optional scope restrictions shared by all rule types
+struct Scope {
+    layer: Option<String>,
+    module: Option<Regex>,
+    export_type: Option<ExportType>,
+    name: Option<Regex>,
+}
+
+impl Scope {
+    fn includes_module(&self, module: &Module) -> bool {
+        if let Some(layer) = &self.layer {
+            if module.layer.as_ref() != Some(layer) {
+                return false;
+            }
+        }
+        if let Some(pattern) = &self.module {
+            if !pattern.is_match(&module.module) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn includes_export(&self, export: &Export) -> bool {
+        match &self.export_type {
+            Some(export_type) => &export.export_type == export_type,
+            None => true,
+        }
+    }
+
+    fn includes_name(&self, name: &str) -> bool {
+        match &self.name {
+            Some(pattern) => pattern.is_match(name),
+            None => true,
+        }
+    }
+}
+
+/// Evaluates custom rules against a project
+pub struct CustomRuleEngine<'a> {
+    project: &'a Project,
+}
+
+impl<'a> CustomRuleEngine<'a> {
+    /// Create an engine for the given project
+    pub fn new(project: &'a Project) -> Self {
+        Self { project }
+    }
+
+    /// Evaluate every rule and collect the resulting issues
+    pub fn evaluate_all(&self, rules: &[CustomRule]) -> Vec<ValidationIssue> {
+        rules.iter().flat_map(|rule| self.evaluate(rule)).collect()
+    }
+
+    /// Evaluate a single custom rule
+    ///
+    /// Misconfigured rules (unknown type or target, invalid pattern, missing
+    /// config) are reported as errors rather than being skipped.
+    pub fn evaluate(&self, rule: &CustomRule) -> Vec<ValidationIssue> {
+        let Some(target) = RuleTarget::parse(&rule.target) else {
+            return vec![config_error(
+                rule,
+                format!("unknown target '{}'", rule.target),
+                "Use one of: module, export, method, property.",
+            )];
+        };
+
+        let scope = match parse_scope(rule) {
+            Ok(scope) => scope,
+            Err(issue) => return vec![*issue],
+        };
+
+        let pattern = match rule.pattern.as_deref().map(Regex::new).transpose() {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                return vec![config_error(
+                    rule,
+                    format!("invalid pattern: {e}"),
+                    "Fix the regular expression in the 'pattern' field.",
+                )]
+            }
+        };
+
+        let subjects = self.collect_subjects(target, &scope);
+
+        match rule.rule_type.as_str() {
+            "naming-convention" => match pattern {
+                Some(pattern) => check_naming_convention(rule, &pattern, &subjects),
+                None => vec![config_error(
+                    rule,
+                    "naming-convention requires a 'pattern'".to_string(),
+                    "Add a regular expression to the 'pattern' field.",
+                )],
+            },
+            "max-dependencies" => {
+                let Some(max) = rule.config.get("max").and_then(|v| v.as_u64()) else {
+                    return vec![config_error(
+                        rule,
+                        "max-dependencies requires a non-negative integer 'config.max'".to_string(),
+                        "Example: \"config\": {\"max\": 5}",
+                    )];
+                };
+                if target == RuleTarget::Property {
+                    return vec![unsupported_target(rule)];
+                }
+                check_max_dependencies(rule, max as usize, &subjects)
+            }
+            "no-dependency" => {
+                if target == RuleTarget::Property {
+                    return vec![unsupported_target(rule)];
+                }
+                check_no_dependency(rule, pattern.as_ref(), &subjects)
+            }
+            other => vec![config_error(
+                rule,
+                format!("unknown rule type '{other}'"),
+                "Use one of: naming-convention, max-dependencies, no-dependency.",
+            )],
+        }
+    }
+
+    /// Collect all elements of the project matching the target and scope
+    fn collect_subjects(&self, target: RuleTarget, scope: &Scope) -> Vec<Subject<'a>> {
+        let mut subjects = Vec::new();
+
+        for module in &self.project.modules {
+            if !scope.includes_module(module) {
+                continue;
+            }
+
+            if target == RuleTarget::Module {
+                subjects.push(Subject {
+                    name: &module.module,
+                    location: module.module.clone(),
+                    module,
+                    export: None,
+                    calls: None,
+                });
+                continue;
+            }
+
+            let mut exports: Vec<_> = module.exports.iter().collect();
+            exports.sort_by_key(|(name, _)| *name);
+
+            for (export_name, export) in exports {
+                if !scope.includes_export(export) {
+                    continue;
+                }
+
+                match target {
+                    RuleTarget::Export => subjects.push(Subject {
+                        name: export_name,
+                        location: format!("{}.{}", module.module, export_name),
+                        module,
+                        export: Some(export),
+                        calls: None,
+                    }),
+                    RuleTarget::Method => {
+                        let mut methods: Vec<_> = export.methods.iter().flatten().collect();
+                        methods.sort_by_key(|(name, _)| *name);
+                        for (method_name, method) in methods {
+                            subjects.push(Subject {
+                                name: method_name,
+                                location: format!(
+                                    "{}.{}.{}",
+                                    module.module, export_name, method_name
+                                ),
+                                module,
+                                export: Some(export),
+                                calls: Some(&method.calls),
+                            });
+                        }
+                    }
+                    RuleTarget::Property => {
+                        let mut properties: Vec<_> = export
+                            .properties
+                            .iter()
+                            .flatten()
+                            .map(|(name, _)| {
+                                (name, format!("{}.{}.{}", module.module, export_name, name))
+                            })
+                            .chain(export.payload.iter().flatten().map(|(name, _)| {
+                                (
+                                    name,
+                                    format!("{}.{}.payload.{}", module.module, export_name, name),
+                                )
+                            }))
+                            .collect();
+                        properties.sort();
+                        for (property_name, location) in properties {
+                            subjects.push(Subject {
+                                name: property_name,
+                                location,
+                                module,
+                                export: Some(export),
+                                calls: None,
+                            });
+                        }
+                    }
+                    RuleTarget::Module => unreachable!(),
+                }
+            }
+        }
+
+        subjects.retain(|subject| scope.includes_name(subject.name));
+        subjects
+    }
+}
+
+/// Parse the scope keys from a rule's config
+fn parse_scope(rule: &CustomRule) -> Result<Scope, Box<ValidationIssue>> {
+    let layer = string_config(rule, "layer", "domain")?.map(String::from);
+    let module = regex_config(rule, "module", "^domain$", "module names")?;
+    let name = regex_config(rule, "name", "Repo", "the names the rule applies to")?;
+
+    let export_type = match rule.config.get("export_type") {
+        Some(value) => Some(serde_json::from_value(value.clone()).map_err(|_| {
+            Box::new(config_error(
+                rule,
+                format!("invalid 'config.export_type': {value}"),
+                "Use one of: class, function, interface, type, enum, event, trait.",
+            ))
+        })?),
+        None => None,
+    };
+
+    Ok(Scope {
+        layer,
+        module,
+        export_type,
+        name,
+    })
+}
+
+/// A string key of a rule's config, reporting other JSON types as an error
+fn string_config<'r>(
+    rule: &'r CustomRule,
+    key: &str,
+    example: &str,
+) -> Result<Option<&'r str>, Box<ValidationIssue>> {
+    let Some(value) = rule.config.get(key) else {
+        return Ok(None);
+    };
+    match value.as_str() {
+        Some(value) => Ok(Some(value)),
+        None => Err(Box::new(config_error(
+            rule,
+            format!("'config.{key}' must be a string"),
+            &format!("Example: \"config\": {{\"{key}\": \"{example}\"}}"),
+        ))),
+    }
+}
+
+/// A regular expression key of a rule's config
+fn regex_config(
+    rule: &CustomRule,
+    key: &str,
+    example: &str,
+    matching: &str,
+) -> Result<Option<Regex>, Box<ValidationIssue>> {
+    let Some(pattern) = string_config(rule, key, example)? else {
+        return Ok(None);
+    };
+    Regex::new(pattern).map(Some).map_err(|e| {
+        Box::new(config_error(
+            rule,
+            format!("invalid 'config.{key}' pattern: {e}"),
+            &format!("Use a regular expression matching {matching}."),
+        ))
+    })
+}
+
+fn check_naming_convention(
+    rule: &CustomRule,
+    pattern: &Regex,
+    subjects: &[Subject],
+) -> Vec<ValidationIssue> {
+    subjects
+        .iter()
+        .filter(|subject| !pattern.is_match(subject.name))
+        .map(|subject| {
+            let mut issue = ValidationIssue::with_comparison(
+                rule.id.clone(),
+                rule.severity.clone(),
+                format!(
+                    "{} '{}' does not follow naming convention",
+                    capitalize(&rule.target),
+                    subject.name
+                ),
+                Some(subject.location.clone()),
+                subject.name.to_string(),
+                format!("name matching '{}'", pattern.as_str()),
+            )
+            .with_doc_link(CUSTOM_RULES_DOC.to_string());
+            if let Some(description) = &rule.description {
+                issue = issue.with_suggestion(description.clone());
+            }
+            issue
+        })
+        .collect()
+}
+
+fn check_max_dependencies(
+    rule: &CustomRule,
+    max: usize,
+    subjects: &[Subject],
+) -> Vec<ValidationIssue> {
+    subjects
+        .iter()
+        .filter_map(|subject| {
+            let count = dependency_targets(subject).len();
+            if count <= max {
+                return None;
+            }
+            Some(
+                ValidationIssue::with_comparison(
+                    rule.id.clone(),
+                    rule.severity.clone(),
+                    format!(
+                        "{} '{}' has {count} dependencies (maximum is {max})",
+                        capitalize(&rule.target),
+                        subject.name
+                    ),
+                    Some(subject.location.clone()),
+                    format!("{count} dependencies"),
+                    format!("at most {max} dependencies"),
+                )
+                .with_suggestion(rule.description.clone().unwrap_or_else(|| {
+                    "Split the element or move shared functionality into a common module."
+                        .to_string()
+                }))
+                .with_doc_link(CUSTOM_RULES_DOC.to_string()),
+            )
+        })
+        .collect()
+}
+
+fn check_no_dependency(
+    rule: &CustomRule,
+    pattern: Option<&Regex>,
+    subjects: &[Subject],
+) -> Vec<ValidationIssue> {
+    subjects
+        .iter()
+        .filter(|subject| match pattern {
+            Some(pattern) => {
+                pattern.is_match(subject.name)
+                    || (subject.export.is_none()
+                        && subject
+                            .module
+                            .layer
+                            .as_deref()
+                            .is_some_and(|layer| pattern.is_match(layer)))
+            }
+            None => true,
+        })
+        .filter_map(|subject| {
+            let targets = dependency_targets(subject);
+            if targets.is_empty() {
+                return None;
+            }
+            let mut targets: Vec<_> = targets.into_iter().collect();
+            targets.sort();
+            Some(
+                ValidationIssue::new(
+                    rule.id.clone(),
+                    rule.severity.clone(),
+                    format!(
+                        "{} '{}' must not have dependencies but depends on: {}",
+                        capitalize(&rule.target),
+                        subject.name,
+                        targets.join(", ")
+                    ),
+                    Some(subject.location.clone()),
+                )
+                .with_suggestion(rule.description.clone().unwrap_or_else(|| {
+                    "Remove the dependencies or move the element to a layer that allows them."
+                        .to_string()
+                }))
+                .with_doc_link(CUSTOM_RULES_DOC.to_string()),
+            )
+        })
+        .collect()
+}
+
+/// Distinct modules a subject depends on
+fn dependency_targets<'s>(subject: &'s Subject) -> HashSet<&'s str> {
+    if let Some(calls) = subject.calls {
+        return calls
+            .iter()
+            .filter_map(|call| call.split('.').next())
+            .filter(|target| *target != subject.module.module)
+            .collect();
+    }
+
+    match subject.export {
+        Some(export) => export
+            .dependencies
+            .iter()
+            .flatten()
+            .map(|dep| dep.module.as_str())
+            .collect(),
+        None => subject
+            .module
+            .dependencies
+            .keys()
+            .map(String::as_str)
+            .collect(),
+    }
+}
+
+fn unsupported_target(rule: &CustomRule) -> ValidationIssue {
+    config_error(
+        rule,
+        format!(
+            "rule type '{}' does not support target '{}'",
+            rule.rule_type, rule.target
+        ),
+        "Use target module, export or method.",
+    )
+}
+
+/// Build an error for a custom rule that cannot be evaluated
+fn config_error(rule: &CustomRule, problem: String, suggestion: &str) -> ValidationIssue {
+    ValidationIssue::new(
+        rule.id.clone(),
+        Severity::Error,
+        format!("Custom rule '{}' is invalid: {problem}", rule.id),
+        Some("rules.json (custom_rules)".to_string()),
+    )
+    .with_suggestion(suggestion.to_string())
+    .with_doc_link(CUSTOM_RULES_DOC.to_string())
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// Evaluate a custom rule against a `domain` layer exporting two traits and
+    /// an `application` layer module calling one of them
+    fn evaluate(rule: &str) -> Vec<ValidationIssue> {
+        let project = TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "domain",
+                "version": "1.0.0",
+                "layer": "domain",
+                "exports": {
+                    "UserRepository": {
+                        "type": "trait",
+                        "methods": {"find": {"inputs": [], "returns": {"type": "void"}}}
+                    },
+                    "UserStore": {
+                        "type": "trait",
+                        "methods": {"save": {"inputs": [], "returns": {"type": "void"}}}
+                    }
+                },
+                "dependencies": {}
+            }"#,
+            )
+            .module(
+                r#"{
+                "module": "app",
+                "version": "1.0.0",
+                "layer": "application",
+                "exports": {
+                    "UserService": {
+                        "type": "class",
+                        "methods": {
+                            "load": {"inputs": [], "returns": {"type": "void"}, "calls": ["domain.UserRepository.find"]}
+                        }
+                    }
+                },
+                "dependencies": {"domain": "^1.0.0"}
+            }"#,
+            )
+            .build();
+        let rule: CustomRule = serde_json::from_str(rule).unwrap();
+
+        CustomRuleEngine::new(&project).evaluate(&rule)
+    }
+
+    fn locations(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues
+            .iter()
+            .filter_map(|issue| issue.location.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_naming_convention_reports_mismatched_exports() {
+        let issues = evaluate(
+            r#"{
+            "id": "repository-naming",
+            "type": "naming-convention",
+            "target": "export",
+            "pattern": "^[A-Z][a-zA-Z]*Repository$",
+            "severity": "warning",
+            "config": {"module": "^domain$"}
+        }"#,
+        );
+
+        assert_eq!(locations(&issues), vec!["domain.UserStore"]);
+        assert_eq!(issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_naming_convention_scoped_by_export_type() {
+        let issues = evaluate(
+            r#"{
+            "id": "service-naming",
+            "type": "naming-convention",
+            "target": "export",
+            "pattern": "Service$",
+            "severity": "error",
+            "config": {"export_type": "class"}
+        }"#,
+        );
+
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn test_naming_convention_scoped_by_name() {
+        let issues = evaluate(
+            r#"{
+            "id": "repository-naming",
+            "type": "naming-convention",
+            "target": "export",
+            "pattern": "^[A-Z][a-zA-Z]*Repository$",
+            "severity": "warning",
+            "config": {"name": "Repo|Store"}
+        }"#,
+        );
+
+        assert_eq!(locations(&issues), vec!["domain.UserStore"]);
+    }
+
+    #[test]
+    fn test_naming_convention_on_methods() {
+        let issues = evaluate(
+            r#"{
+            "id": "method-naming",
+            "type": "naming-convention",
+            "target": "method",
+            "pattern": "^(find|load)",
+            "severity": "error"
+        }"#,
+        );
+
+        assert_eq!(locations(&issues), vec!["domain.UserStore.save"]);
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_max_dependencies_uses_config() {
+        let issues = evaluate(
+            r#"{
+            "id": "max-module-dependencies",
+            "type": "max-dependencies",
+            "target": "module",
+            "severity": "warning",
+            "config": {"max": 0}
+        }"#,
+        );
+
+        assert_eq!(locations(&issues), vec!["app"]);
+    }
+
+    #[test]
+    fn test_max_dependencies_requires_max() {
+        let issues = evaluate(
+            r#"{
+            "id": "max-module-dependencies",
+            "type": "max-dependencies",
+            "target": "module",
+            "severity": "warning"
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(issues[0].message.contains("config.max"));
+    }
+
+    #[test]
+    fn test_no_dependency_matches_layer() {
+        let issues = evaluate(
+            r#"{
+            "id": "application-must-be-pure",
+            "type": "no-dependency",
+            "target": "module",
+            "pattern": "^application$",
+            "severity": "error"
+        }"#,
+        );
+
+        assert_eq!(locations(&issues), vec!["app"]);
+        assert!(issues[0].message.contains("domain"));
+    }
+
+    #[test]
+    fn test_unknown_rule_type_is_an_error() {
+        let issues = evaluate(
+            r#"{
+            "id": "mystery",
+            "type": "does-not-exist",
+            "target": "module",
+            "severity": "info"
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(issues[0].message.contains("unknown rule type"));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_an_error() {
+        let issues = evaluate(
+            r#"{
+            "id": "broken",
+            "type": "naming-convention",
+            "target": "export",
+            "pattern": "([A-Z",
+            "severity": "warning"
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(issues[0].message.contains("invalid pattern"));
+    }
+
+    #[test]
+    fn test_non_string_module_scope_is_an_error() {
+        let issues = evaluate(
+            r#"{
+            "id": "scoped",
+            "type": "naming-convention",
+            "target": "export",
+            "pattern": "^User",
+            "severity": "warning",
+            "config": {"module": 42}
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .message
+            .contains("'config.module' must be a string"));
+    }
+}
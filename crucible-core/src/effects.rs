@@ -0,0 +1,180 @@
+//! Transitive effect inference along the [`CallGraph`]
+//!
+//! A method performs its own declared effects plus every effect of the
+//! methods it calls. [`EffectAnalysis`] computes that set for every method so
+//! rules can compare it against what the method declares.
+//!
+//! Only effect identifiers such as `database.write` take part. Effects
+//! containing whitespace are free-form descriptions and are ignored.
+
+use crate::calls::CallGraph;
+use crate::types::Project;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Declared and inferred effects of one method
+#[derive(Debug, Clone, Default)]
+pub struct MethodEffects {
+    /// Effects listed in the method's `effects`
+    pub declared: BTreeSet<String>,
+    /// Effects performed by callees, mapped to the direct callee they come through
+    pub inferred: BTreeMap<String, String>,
+    /// Whether the method has any calls
+    pub has_calls: bool,
+    /// Whether every call resolves to a known method, so `inferred` is complete
+    pub calls_resolved: bool,
+}
+
+impl MethodEffects {
+    /// Every effect the method performs, declared or inferred
+    pub fn all(&self) -> BTreeSet<&str> {
+        self.declared
+            .iter()
+            .chain(self.inferred.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Whether the method declares an effect covering `effect`
+    pub fn declares(&self, effect: &str) -> bool {
+        self.declared
+            .iter()
+            .any(|declared| effect_matches(declared, effect))
+    }
+}
+
+/// Effects of every method in a project, keyed by `module.Export.method`
+#[derive(Debug, Clone, Default)]
+pub struct EffectAnalysis {
+    methods: BTreeMap<String, MethodEffects>,
+}
+
+impl EffectAnalysis {
+    /// Infer the transitive effects of every method in the project
+    pub fn new(project: &Project) -> Self {
+        let calls = CallGraph::new(project);
+        let mut methods = BTreeMap::new();
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                let Some(export_methods) = &export.methods else {
+                    continue;
+                };
+                for (method_name, method) in export_methods {
+                    let key = format!("{}.{}.{}", module.module, export_name, method_name);
+                    let calls_resolved = calls.unresolved_calls(&key).is_empty();
+                    methods.insert(
+                        key,
+                        MethodEffects {
+                            declared: method
+                                .effects
+                                .iter()
+                                .filter(|effect| is_effect_identifier(effect))
+                                .cloned()
+                                .collect(),
+                            has_calls: !method.calls.is_empty(),
+                            calls_resolved,
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+        }
+
+        // Propagate to a fixed point; recursive calls converge because the
+        // effect sets only grow
+        loop {
+            let mut changed = false;
+            for key in calls.methods() {
+                for target in calls.callees(key) {
+                    if target == key {
+                        continue;
+                    }
+                    let target_effects: Vec<String> = methods[target]
+                        .all()
+                        .into_iter()
+                        .map(String::from)
+                        .collect();
+                    let effects = methods.get_mut(key).expect("method was collected");
+                    for effect in target_effects {
+                        if let Entry::Vacant(entry) = effects.inferred.entry(effect) {
+                            entry.insert(target.clone());
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        Self { methods }
+    }
+
+    /// Effects of one method, by `module.Export.method`
+    pub fn get(&self, method: &str) -> Option<&MethodEffects> {
+        self.methods.get(method)
+    }
+
+    /// All methods and their effects, ordered by `module.Export.method`
+    pub fn methods(&self) -> impl Iterator<Item = (&str, &MethodEffects)> {
+        self.methods
+            .iter()
+            .map(|(key, effects)| (key.as_str(), effects))
+    }
+}
+
+/// Whether an effect is an identifier rather than a free-form description
+fn is_effect_identifier(effect: &str) -> bool {
+    !effect.is_empty() && !effect.contains(char::is_whitespace)
+}
+
+/// Whether an effect pattern covers an effect
+///
+/// A pattern is either an exact effect name or `category.*`, which covers
+/// every effect in the category.
+pub fn effect_matches(pattern: &str, effect: &str) -> bool {
+    if pattern == effect {
+        return true;
+    }
+    match pattern.strip_suffix(".*") {
+        Some(category) => effect
+            .strip_prefix(category)
+            .is_some_and(|rest| rest.starts_with('.')),
+        None => false,
+    }
+}
+
+/// The category of an effect: `database` for `database.write` and `database.*`
+pub fn effect_category(effect: &str) -> &str {
+    effect.split('.').next().unwrap_or(effect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effect_matches() {
+        assert!(effect_matches("database.write", "database.write"));
+        assert!(effect_matches("database.*", "database.write"));
+        assert!(!effect_matches("database.*", "databases.write"));
+        assert!(!effect_matches("database.read", "database.write"));
+        assert!(!effect_matches("network.*", "network"));
+    }
+
+    #[test]
+    fn test_effect_category() {
+        assert_eq!(effect_category("database.write"), "database");
+        assert_eq!(effect_category("database.*"), "database");
+        assert_eq!(effect_category("logging"), "logging");
+    }
+
+    #[test]
+    fn test_effect_identifiers() {
+        assert!(is_effect_identifier("database.write"));
+        assert!(is_effect_identifier("logging"));
+        assert!(!is_effect_identifier("stores module with timestamp"));
+    }
+}
@@ -14,6 +14,13 @@ pub enum CrucibleError {
         source: io::Error,
     },
 
+    #[error("Failed to write file {path}: {source}")]
+    FileWrite {
+        path: String,
+        #[source]
+        source: io::Error,
+    },
+
     #[error("Failed to parse {file}: {message}")]
     ParseError { file: String, message: String },
 
@@ -55,6 +62,17 @@ mod tests {
         assert!(msg.contains("Failed to read file"));
     }
 
+    #[test]
+    fn test_file_write_error_display() {
+        let err = CrucibleError::FileWrite {
+            path: "/path/to/file.json".to_string(),
+            source: io::Error::new(io::ErrorKind::PermissionDenied, "permission denied"),
+        };
+        let msg = format!("{err}");
+        assert!(msg.contains("/path/to/file.json"));
+        assert!(msg.contains("Failed to write file"));
+    }
+
     #[test]
     fn test_parse_error_display() {
         let err = CrucibleError::ParseError {
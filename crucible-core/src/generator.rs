@@ -1,7 +1,9 @@
 //! Code generator for Crucible architectures
 
 use crate::error::{CrucibleError, Result};
-use crate::types::{ExportType, Module, Project};
+use crate::type_system::{type_usages, TypeDialect, TypeParser};
+use crate::types::{Export, ExportType, Module, Project};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 
@@ -43,11 +45,20 @@ impl Generator {
         ));
         output.push_str(&format!("// Version: {}\n\n", module.version));
 
+        // Imports of external types and of traits implemented from other modules
+        let mut imports = self.external_imports(module);
+        imports.extend(implemented_imports(module));
+        if !imports.is_empty() {
+            output.push_str(&imports.join("\n"));
+            output.push_str("\n\n");
+        }
+
         // Generate exports
         for (name, export) in &module.exports {
+            let generics = type_param_list(export);
             match export.export_type {
                 ExportType::Interface => {
-                    output.push_str(&format!("export interface {name} {{\n"));
+                    output.push_str(&format!("export interface {name}{generics} {{\n"));
                     if let Some(props) = &export.properties {
                         for (prop_name, prop) in props {
                             let optional = if prop.required { "" } else { "?" };
@@ -60,7 +71,22 @@ impl Generator {
                     output.push_str("}\n\n");
                 }
                 ExportType::Class => {
-                    output.push_str(&format!("export class {name} {{\n"));
+                    // Implemented traits are referenced by name, without their
+                    // module, followed by any type arguments as written
+                    let implements = if export.implements.is_empty() {
+                        String::new()
+                    } else {
+                        let names: Vec<String> = export
+                            .implements
+                            .iter()
+                            .map(|reference| {
+                                let (name, args) = split_type_args(reference);
+                                format!("{}{args}", name.rsplit('.').next().unwrap_or(name))
+                            })
+                            .collect();
+                        format!(" implements {}", names.join(", "))
+                    };
+                    output.push_str(&format!("export class {name}{generics}{implements} {{\n"));
                     if let Some(methods) = &export.methods {
                         for (method_name, method) in methods {
                             output.push_str(&format!("  {method_name}("));
@@ -85,7 +111,7 @@ impl Generator {
                     if let Some(methods) = &export.methods {
                         // For function exports, we expect a single "function" entry
                         if let Some((_, method)) = methods.iter().next() {
-                            output.push_str(&format!("export function {name}("));
+                            output.push_str(&format!("export function {name}{generics}("));
 
                             // Parameters
                             let params: Vec<String> = method
@@ -111,9 +137,13 @@ impl Generator {
                     }
                     output.push_str("}\n\n");
                 }
+                ExportType::Type if export.alias.is_some() => {
+                    let alias = export.alias.as_deref().unwrap_or_default();
+                    output.push_str(&format!("export type {name}{generics} = {alias};\n\n"));
+                }
                 ExportType::Type => {
                     // Type alias - generate as TypeScript type
-                    output.push_str(&format!("export type {name} = {{\n"));
+                    output.push_str(&format!("export type {name}{generics} = {{\n"));
                     if let Some(props) = &export.properties {
                         for (prop_name, prop) in props {
                             let optional = if prop.required { "" } else { "?" };
@@ -171,7 +201,7 @@ impl Generator {
                 ExportType::Trait => {
                     // Trait - generate as TypeScript interface with optional async methods
                     output.push_str(&format!("/**\n * Trait: {name}\n */\n"));
-                    output.push_str(&format!("export interface {name} {{\n"));
+                    output.push_str(&format!("export interface {name}{generics} {{\n"));
                     if let Some(methods) = &export.methods {
                         for (method_name, method) in methods {
                             // Parameters
@@ -206,6 +236,106 @@ impl Generator {
 
         Ok(output)
     }
+
+    /// Import statements for the external types a module uses, by package
+    ///
+    /// `Request` is imported by name; `express.Request` imports the `express`
+    /// namespace. External types without a package need no import.
+    fn external_imports(&self, module: &Module) -> Vec<String> {
+        let parser = TypeParser::for_dialect(TypeDialect::for_project(&self.project));
+        let mut used = BTreeSet::new();
+        for usage in type_usages(module) {
+            if let Ok(type_ref) = parser.parse(usage.type_str) {
+                used.extend(type_ref.referenced_names().into_iter().map(str::to_string));
+            }
+        }
+
+        // Package -> (named imports, namespace imports)
+        let mut packages: BTreeMap<&str, (BTreeSet<&str>, BTreeSet<&str>)> = BTreeMap::new();
+        for external in &self.project.manifest.external_types {
+            let Some(package) = external.package.as_deref() else {
+                continue;
+            };
+            if !used.contains(&external.name) {
+                continue;
+            }
+            let imports = packages.entry(package).or_default();
+            match external.name.split_once('.') {
+                Some((namespace, _)) => imports.1.insert(namespace),
+                None => imports.0.insert(external.name.as_str()),
+            };
+        }
+
+        let mut lines = Vec::new();
+        for (package, (names, namespaces)) in packages {
+            for namespace in namespaces {
+                lines.push(format!("import type * as {namespace} from '{package}';"));
+            }
+            if !names.is_empty() {
+                let names: Vec<&str> = names.into_iter().collect();
+                lines.push(format!(
+                    "import type {{ {} }} from '{package}';",
+                    names.join(", ")
+                ));
+            }
+        }
+        lines
+    }
+}
+
+/// `import type` lines for the traits and interfaces a module's classes
+/// implement from other modules, which the generated `implements` clauses name
+fn implemented_imports(module: &Module) -> Vec<String> {
+    let mut modules: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for export in module.exports.values() {
+        for reference in &export.implements {
+            let (reference, _) = split_type_args(reference);
+            if let Some((module_name, name)) = reference.split_once('.') {
+                if module_name != module.module {
+                    modules.entry(module_name).or_default().insert(name);
+                }
+            }
+        }
+    }
+
+    modules
+        .into_iter()
+        .map(|(module_name, names)| {
+            let names: Vec<&str> = names.into_iter().collect();
+            format!(
+                "import type {{ {} }} from './{module_name}';",
+                names.join(", ")
+            )
+        })
+        .collect()
+}
+
+/// TypeScript type parameter list of an export, e.g. `<T extends Entity, K>`
+///
+/// Bounds are referenced by name, without their module.
+/// A type name and its type argument list, if any: `ports.Repository<User>`
+/// splits into `ports.Repository` and `<User>`
+fn split_type_args(reference: &str) -> (&str, &str) {
+    reference.split_at(reference.find('<').unwrap_or(reference.len()))
+}
+
+fn type_param_list(export: &Export) -> String {
+    if export.type_params.is_empty() {
+        return String::new();
+    }
+    let params: Vec<String> = export
+        .type_params
+        .iter()
+        .map(|param| match &param.bound {
+            Some(bound) => format!(
+                "{} extends {}",
+                param.name,
+                bound.rsplit('.').next().unwrap_or(bound)
+            ),
+            None => param.name.clone(),
+        })
+        .collect();
+    format!("<{}>", params.join(", "))
 }
 
 #[cfg(test)]
@@ -226,6 +356,7 @@ mod tests {
             modules: vec![],
             strict_validation: true,
             metadata: None,
+            external_types: Vec::new(),
         }
     }
 
@@ -261,6 +392,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -271,12 +408,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -288,6 +430,95 @@ mod tests {
         assert!(output.contains("count?: number;"));
     }
 
+    #[test]
+    fn test_generate_external_type_imports() {
+        let mut props = HashMap::new();
+        for (name, prop_type) in [
+            ("request", "express.Request"),
+            ("amount", "Decimal | null"),
+            ("createdAt", "Moment"),
+        ] {
+            props.insert(
+                name.to_string(),
+                Property {
+                    prop_type: prop_type.to_string(),
+                    required: true,
+                    description: None,
+                    annotations: vec![],
+                },
+            );
+        }
+
+        let mut exports = HashMap::new();
+        exports.insert(
+            "Payment".to_string(),
+            Export {
+                export_type: ExportType::Interface,
+                methods: None,
+                properties: Some(props),
+                values: None,
+                dependencies: None,
+                payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
+            },
+        );
+
+        let module = Module {
+            module: "payments".to_string(),
+            version: "1.0.0".to_string(),
+            layer: None,
+            description: None,
+            exports,
+            dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
+        };
+
+        let mut manifest = create_test_manifest();
+        manifest.external_types = vec![
+            ExternalType {
+                name: "express.Request".to_string(),
+                package: Some("express".to_string()),
+            },
+            ExternalType {
+                name: "Decimal".to_string(),
+                package: Some("decimal.js".to_string()),
+            },
+            ExternalType {
+                name: "Moment".to_string(),
+                package: None,
+            },
+            ExternalType {
+                name: "Unused".to_string(),
+                package: Some("unused".to_string()),
+            },
+        ];
+
+        let project = Project {
+            manifest,
+            modules: vec![module],
+            rules: None,
+            source_map: Default::default(),
+        };
+
+        let generator = Generator::new(project);
+        let module_ref = &generator.project.modules[0];
+        let output = generator.generate_typescript_module(module_ref).unwrap();
+
+        assert!(output.contains(
+            "import type { Decimal } from 'decimal.js';\nimport type * as express from 'express';\n\n"
+        ));
+        assert!(!output.contains("Moment'"));
+        assert!(!output.contains("'unused'"));
+    }
+
     #[test]
     fn test_generate_class() {
         let inputs = vec![Parameter {
@@ -311,6 +542,10 @@ mod tests {
                 effects: vec![],
                 is_async: false,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -324,6 +559,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -334,12 +575,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -351,6 +597,171 @@ mod tests {
         assert!(output.contains("throw new Error('Not implemented');"));
     }
 
+    #[test]
+    fn test_generate_class_implements() {
+        let mut exports = HashMap::new();
+        exports.insert(
+            "PostgresTodoRepository".to_string(),
+            Export {
+                export_type: ExportType::Class,
+                methods: None,
+                properties: None,
+                values: None,
+                dependencies: None,
+                payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: vec![
+                    "ports.TodoRepository".to_string(),
+                    "Auditable".to_string(),
+                    "ports.Repository<domain.Todo>".to_string(),
+                ],
+                type_params: Vec::new(),
+                alias: None,
+            },
+        );
+
+        let module = Module {
+            module: "postgres".to_string(),
+            version: "1.0.0".to_string(),
+            layer: None,
+            description: None,
+            exports,
+            dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
+        };
+
+        let project = Project {
+            manifest: create_test_manifest(),
+            modules: vec![module],
+            rules: None,
+            source_map: Default::default(),
+        };
+
+        let generator = Generator::new(project);
+        let module_ref = &generator.project.modules[0];
+        let output = generator.generate_typescript_module(module_ref).unwrap();
+
+        assert!(output.contains(
+            "export class PostgresTodoRepository implements TodoRepository, Auditable, \
+             Repository<domain.Todo> {"
+        ));
+        assert!(output.contains("import type { Repository, TodoRepository } from './ports';\n"));
+        assert!(!output.contains("Auditable }"));
+    }
+
+    #[test]
+    fn test_generate_type_params() {
+        let mut exports = HashMap::new();
+        exports.insert(
+            "Repository".to_string(),
+            Export {
+                export_type: ExportType::Trait,
+                methods: None,
+                properties: None,
+                values: None,
+                dependencies: None,
+                payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: vec![
+                    TypeParam {
+                        name: "T".to_string(),
+                        bound: Some("domain.Entity".to_string()),
+                    },
+                    TypeParam {
+                        name: "K".to_string(),
+                        bound: None,
+                    },
+                ],
+                alias: None,
+            },
+        );
+
+        let module = Module {
+            module: "ports".to_string(),
+            version: "1.0.0".to_string(),
+            layer: None,
+            description: None,
+            exports,
+            dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
+        };
+
+        let project = Project {
+            manifest: create_test_manifest(),
+            modules: vec![module],
+            rules: None,
+            source_map: Default::default(),
+        };
+
+        let generator = Generator::new(project);
+        let module_ref = &generator.project.modules[0];
+        let output = generator.generate_typescript_module(module_ref).unwrap();
+
+        assert!(output.contains("export interface Repository<T extends Entity, K> {"));
+    }
+
+    #[test]
+    fn test_generate_type_alias() {
+        let mut exports = HashMap::new();
+        exports.insert(
+            "Paged".to_string(),
+            Export {
+                export_type: ExportType::Type,
+                methods: None,
+                properties: None,
+                values: None,
+                dependencies: None,
+                payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: vec![TypeParam {
+                    name: "T".to_string(),
+                    bound: None,
+                }],
+                alias: Some("T[] | null".to_string()),
+            },
+        );
+
+        let module = Module {
+            module: "shared".to_string(),
+            version: "1.0.0".to_string(),
+            layer: None,
+            description: None,
+            exports,
+            dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
+        };
+
+        let project = Project {
+            manifest: create_test_manifest(),
+            modules: vec![module],
+            rules: None,
+            source_map: Default::default(),
+        };
+
+        let generator = Generator::new(project);
+        let module_ref = &generator.project.modules[0];
+        let output = generator.generate_typescript_module(module_ref).unwrap();
+
+        assert!(output.contains("export type Paged<T> = T[] | null;"));
+    }
+
     #[test]
     fn test_generate_function() {
         let inputs = vec![
@@ -382,6 +793,10 @@ mod tests {
                 effects: vec![],
                 is_async: false,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -395,6 +810,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -405,12 +826,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -437,6 +863,12 @@ mod tests {
                 ]),
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -447,12 +879,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -474,12 +911,17 @@ mod tests {
             description: None,
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -501,12 +943,17 @@ mod tests {
             description: None,
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -530,6 +977,10 @@ mod tests {
             description: None,
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let module2 = Module {
@@ -539,12 +990,17 @@ mod tests {
             description: None,
             exports: HashMap::new(),
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module1, module2],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -570,6 +1026,10 @@ mod tests {
                 effects: vec![],
                 is_async: false,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -583,6 +1043,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -593,12 +1059,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -640,6 +1111,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: Some(payload),
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -650,12 +1127,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -691,6 +1173,10 @@ mod tests {
                 effects: vec![],
                 is_async: true,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
         methods.insert(
@@ -706,6 +1192,10 @@ mod tests {
                 effects: vec![],
                 is_async: false,
                 annotations: vec![],
+                suppress: Vec::new(),
+                handles: Vec::new(),
+                emits: Vec::new(),
+                subscribes: Vec::new(),
             },
         );
 
@@ -719,6 +1209,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -729,12 +1225,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
@@ -779,6 +1280,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -789,12 +1296,17 @@ mod tests {
             description: None,
             exports,
             dependencies: HashMap::new(),
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let project = Project {
             manifest: create_test_manifest(),
             modules: vec![module],
             rules: None,
+            source_map: Default::default(),
         };
 
         let generator = Generator::new(project);
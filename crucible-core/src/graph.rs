@@ -1,12 +1,73 @@
 //! Dependency graph operations
+//!
+//! [`ModuleGraph`] renders the module dependency graph as an indented text
+//! tree, Graphviz DOT, Mermaid or JSON, grouping modules by layer and marking
+//! dependencies that break layer rules. It also answers transitive queries
+//! (who depends on a module, which paths lead from one module to another),
+//! and [`explain_dependency`] says why each dependency on such a path exists.
+//! Cycles are found per strongly connected component, along with a smallest
+//! set of dependencies whose removal breaks all of them.
 
-use crate::types::Module;
-use petgraph::algo::is_cyclic_directed;
+use crate::type_system::{type_usages, TypeDialect, TypeParser};
+use crate::types::{Architecture, Module, Project};
+use petgraph::algo::{is_cyclic_directed, tarjan_scc};
 use petgraph::graph::DiGraph;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
-/// Build a dependency graph from modules
+/// Other modules a module references through qualified type names
+/// (`user.User`) in its method signatures, properties and event payloads
+pub fn type_dependencies(
+    module: &Module,
+    modules: &[Module],
+    dialect: TypeDialect,
+) -> BTreeSet<String> {
+    let parser = TypeParser::for_dialect(dialect);
+    let mut dependencies = BTreeSet::new();
+
+    for usage in type_usages(module) {
+        let Ok(type_ref) = parser.parse(usage.type_str) else {
+            continue;
+        };
+        for name in type_ref.referenced_names() {
+            let Some((module_name, _)) = name.split_once('.') else {
+                continue;
+            };
+            if module_name != module.module && modules.iter().any(|m| m.module == module_name) {
+                dependencies.insert(module_name.to_string());
+            }
+        }
+    }
+
+    dependencies
+}
+
+/// Modules a module depends on: its declared dependencies plus the modules it
+/// references through qualified types
+pub fn module_dependencies(
+    module: &Module,
+    modules: &[Module],
+    dialect: TypeDialect,
+) -> BTreeSet<String> {
+    let mut dependencies: BTreeSet<String> = module.dependencies.keys().cloned().collect();
+    dependencies.extend(type_dependencies(module, modules, dialect));
+    dependencies
+}
+
+/// Build a dependency graph from modules, with an edge for every declared
+/// dependency and every qualified type reference
+///
+/// Types are parsed in the default dialect; projects in other languages use
+/// [`build_dependency_graph_for_dialect`].
 pub fn build_dependency_graph(modules: &[Module]) -> DiGraph<String, ()> {
+    build_dependency_graph_for_dialect(modules, TypeDialect::default())
+}
+
+/// Build a dependency graph from modules, parsing type references in `dialect`
+pub fn build_dependency_graph_for_dialect(
+    modules: &[Module],
+    dialect: TypeDialect,
+) -> DiGraph<String, ()> {
     let mut graph = DiGraph::new();
     let mut node_map = HashMap::new();
 
@@ -19,8 +80,8 @@ pub fn build_dependency_graph(modules: &[Module]) -> DiGraph<String, ()> {
     // Add edges
     for module in modules {
         if let Some(from_node) = node_map.get(&module.module) {
-            for dep_name in module.dependencies.keys() {
-                if let Some(to_node) = node_map.get(dep_name) {
+            for dep_name in module_dependencies(module, modules, dialect) {
+                if let Some(to_node) = node_map.get(&dep_name) {
                     graph.add_edge(*from_node, *to_node, ());
                 }
             }
@@ -30,14 +91,927 @@ pub fn build_dependency_graph(modules: &[Module]) -> DiGraph<String, ()> {
     graph
 }
 
+/// Cycle-breaking edge sets are searched exhaustively up to this many
+/// candidate dependencies, and greedily beyond
+const EXACT_BREAKING_SET_LIMIT: usize = 16;
+
 /// Detect if there are cycles in the dependency graph
 pub fn detect_cycles(graph: &DiGraph<String, ()>) -> bool {
     is_cyclic_directed(graph)
 }
 
+/// Strongly connected components that contain a cycle, of a graph given as
+/// the targets of each node
+///
+/// Each component is sorted, and so is the list.
+pub(crate) fn cyclic_components_of(edges: &BTreeMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut graph: DiGraph<&str, ()> = DiGraph::new();
+    let mut indices = HashMap::new();
+    for node in edges.keys().chain(edges.values().flatten()) {
+        indices
+            .entry(node.as_str())
+            .or_insert_with(|| graph.add_node(node.as_str()));
+    }
+    for (from, targets) in edges {
+        for to in targets {
+            graph.add_edge(indices[from.as_str()], indices[to.as_str()], ());
+        }
+    }
+
+    let mut components: Vec<Vec<String>> = tarjan_scc(&graph)
+        .into_iter()
+        .filter(|component| component.len() > 1 || graph.contains_edge(component[0], component[0]))
+        .map(|component| {
+            let mut nodes: Vec<String> = component
+                .iter()
+                .map(|&index| graph[index].to_string())
+                .collect();
+            nodes.sort();
+            nodes
+        })
+        .collect();
+    components.sort();
+    components
+}
+
+/// Shortest cycle through the members of a strongly connected component,
+/// starting at its smallest node; among equally short cycles the smallest
+///
+/// Searches breadth-first from each member, so it takes polynomial time
+/// however many cycles the component has.
+pub(crate) fn shortest_cycle(
+    component: &[String],
+    edges: &BTreeMap<String, Vec<String>>,
+) -> Option<Vec<String>> {
+    let members: HashSet<&str> = component.iter().map(String::as_str).collect();
+    let mut shortest: Option<Vec<String>> = None;
+
+    for start in component {
+        let mut parents: HashMap<&str, &str> = HashMap::new();
+        let mut queue = VecDeque::from([start.as_str()]);
+        let mut closing = None;
+        'search: while let Some(node) = queue.pop_front() {
+            for target in edges.get(node).into_iter().flatten() {
+                let target = target.as_str();
+                if target == start {
+                    closing = Some(node);
+                    break 'search;
+                }
+                if members.contains(target) && !parents.contains_key(target) {
+                    parents.insert(target, node);
+                    queue.push_back(target);
+                }
+            }
+        }
+        let Some(mut node) = closing else {
+            continue;
+        };
+
+        let mut cycle = vec![node.to_string()];
+        while node != start {
+            node = parents[node];
+            cycle.push(node.to_string());
+        }
+        cycle.reverse();
+        let smallest = (0..cycle.len())
+            .min_by_key(|&index| &cycle[index])
+            .expect("cycle has members");
+        cycle.rotate_left(smallest);
+
+        let shorter = shortest
+            .as_ref()
+            .is_none_or(|current| (cycle.len(), &cycle) < (current.len(), current));
+        if shorter {
+            shortest = Some(cycle);
+        }
+    }
+    shortest
+}
+
+/// Module dependency graph of a project with the layer of each module, ready
+/// to render
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleGraph {
+    /// Layers in the order the architecture declares them, followed by any
+    /// other layers modules use
+    pub layers: Vec<String>,
+    /// Modules, sorted by name
+    pub nodes: Vec<GraphNode>,
+    /// Dependencies, sorted by source and target
+    pub edges: Vec<GraphEdge>,
+}
+
+/// A module found by a transitive query, and how many dependencies away it is
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Reached {
+    pub module: String,
+    pub distance: usize,
+}
+
+/// Why one module depends on another: every path between them, and the
+/// reasons behind each dependency on those paths
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DependencyExplanation {
+    pub from: String,
+    pub to: String,
+    pub paths: Vec<Vec<String>>,
+    /// Whether more paths exist than were listed
+    pub truncated: bool,
+    pub edges: Vec<EdgeReasons>,
+}
+
+/// What makes `from` depend on `to` directly
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct EdgeReasons {
+    pub from: String,
+    pub to: String,
+    pub reasons: Vec<String>,
+}
+
+/// A module in a [`ModuleGraph`]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GraphNode {
+    pub module: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layer: Option<String>,
+}
+
+/// A dependency between two modules in a [`ModuleGraph`]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    /// Whether the layer of `from` isn't allowed to depend on the layer of `to`
+    pub layer_violation: bool,
+}
+
+impl ModuleGraph {
+    /// Build the graph of modules, with layer violations judged against
+    /// `architecture`
+    pub fn new(
+        modules: &[Module],
+        architecture: Option<&Architecture>,
+        dialect: TypeDialect,
+    ) -> Self {
+        let mut nodes: Vec<GraphNode> = modules
+            .iter()
+            .map(|module| GraphNode {
+                module: module.module.clone(),
+                layer: module.layer.clone(),
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.module.cmp(&b.module));
+
+        let mut layers: Vec<String> = architecture
+            .map(|architecture| architecture.layers.iter().map(|l| l.name.clone()).collect())
+            .unwrap_or_default();
+        let other_layers: BTreeSet<&String> = nodes
+            .iter()
+            .filter_map(|node| node.layer.as_ref())
+            .filter(|layer| !layers.contains(layer))
+            .collect();
+        layers.extend(other_layers.into_iter().cloned());
+
+        let module_layers: HashMap<&str, &str> = nodes
+            .iter()
+            .filter_map(|node| Some((node.module.as_str(), node.layer.as_deref()?)))
+            .collect();
+
+        let graph = build_dependency_graph_for_dialect(modules, dialect);
+        let mut edges: Vec<GraphEdge> = graph
+            .edge_indices()
+            .filter_map(|edge| graph.edge_endpoints(edge))
+            .map(|(from, to)| {
+                let (from, to) = (&graph[from], &graph[to]);
+                let layer_violation = match (
+                    architecture,
+                    module_layers.get(from.as_str()),
+                    module_layers.get(to.as_str()),
+                ) {
+                    (Some(architecture), Some(from_layer), Some(to_layer)) => architecture
+                        .layers
+                        .iter()
+                        .find(|layer| layer.name == *from_layer)
+                        .is_some_and(|layer| !layer.can_depend_on.iter().any(|l| l == to_layer)),
+                    _ => false,
+                };
+                GraphEdge {
+                    from: from.clone(),
+                    to: to.clone(),
+                    layer_violation,
+                }
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+        edges.dedup_by(|a, b| a.from == b.from && a.to == b.to);
+
+        Self {
+            layers,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Build the graph of a project's modules and architecture
+    pub fn from_project(project: &Project) -> Self {
+        let architecture = project
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.architecture.as_ref());
+        Self::new(
+            &project.modules,
+            architecture,
+            TypeDialect::for_project(project),
+        )
+    }
+
+    /// Dependencies of a module, sorted by target
+    pub fn edges_from<'g>(&'g self, module: &'g str) -> impl Iterator<Item = &'g GraphEdge> {
+        self.edges.iter().filter(move |edge| edge.from == module)
+    }
+
+    /// Whether the graph has a module of this name
+    pub fn contains(&self, module: &str) -> bool {
+        self.nodes.iter().any(|node| node.module == module)
+    }
+
+    /// Modules `module` depends on directly or transitively, nearest first
+    pub fn dependencies(&self, module: &str) -> Vec<Reached> {
+        self.reachable(module, true)
+    }
+
+    /// Modules that depend on `module` directly or transitively, nearest first
+    pub fn dependents(&self, module: &str) -> Vec<Reached> {
+        self.reachable(module, false)
+    }
+
+    /// Shortest chain of dependencies leading from `from` to `to`, both
+    /// included; a path from a module to itself is a cycle
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let mut previous: HashMap<&str, &str> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(module) = queue.pop_front() {
+            for edge in self.edges_from(module) {
+                let target = edge.to.as_str();
+                if target == to {
+                    let mut path = vec![to.to_string(), module.to_string()];
+                    let mut current = module;
+                    while current != from {
+                        current = previous[current];
+                        path.push(current.to_string());
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if target != from && !previous.contains_key(target) {
+                    previous.insert(target, module);
+                    queue.push_back(target);
+                }
+            }
+        }
+        None
+    }
+
+    /// Chains of dependencies leading from `from` to `to` that don't visit a
+    /// module twice, shortest first
+    ///
+    /// A densely connected graph has exponentially many paths, so only the
+    /// `limit` shortest are listed; the flag says whether any were left out.
+    pub fn all_paths(&self, from: &str, to: &str, limit: usize) -> (Vec<Vec<String>>, bool) {
+        let mut search = PathSearch {
+            graph: self,
+            to,
+            length: 0,
+            limit,
+            paths: Vec::new(),
+            truncated: false,
+        };
+        if self.shortest_path(from, to).is_some() {
+            // Search one path length at a time, so the paths kept when the
+            // limit is reached are the shortest ones
+            for length in 1..=self.nodes.len() {
+                search.length = length;
+                search.from(from, &mut vec![from]);
+                if search.truncated {
+                    break;
+                }
+            }
+        }
+        search
+            .paths
+            .sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        (search.paths, search.truncated)
+    }
+
+    /// Targets of each module's dependencies, sorted
+    fn adjacency(&self) -> BTreeMap<String, Vec<String>> {
+        let mut adjacency: BTreeMap<String, Vec<String>> = self
+            .nodes
+            .iter()
+            .map(|node| (node.module.clone(), Vec::new()))
+            .collect();
+        for edge in &self.edges {
+            adjacency
+                .entry(edge.from.clone())
+                .or_default()
+                .push(edge.to.clone());
+        }
+        for targets in adjacency.values_mut() {
+            targets.sort();
+            targets.dedup();
+        }
+        adjacency
+    }
+
+    /// Strongly connected components that contain a cycle: groups of
+    /// modules that all depend on each other, or a module depending on itself
+    ///
+    /// Each component is sorted, and so is the list.
+    pub fn cyclic_components(&self) -> Vec<Vec<String>> {
+        cyclic_components_of(&self.adjacency())
+    }
+
+    /// Elementary cycles, each starting at its smallest module, shortest
+    /// cycles first
+    ///
+    /// A densely connected graph has exponentially many cycles, so at most
+    /// `limit` are listed; the flag says whether any were left out.
+    pub fn cycles(&self, limit: usize) -> (Vec<Vec<String>>, bool) {
+        let mut cycles = Vec::new();
+        let mut truncated = false;
+        for component in self.cyclic_components() {
+            let (found, more) = self.component_cycles(&component, limit - cycles.len());
+            cycles.extend(found);
+            if more {
+                truncated = true;
+                break;
+            }
+        }
+        cycles.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        (cycles, truncated)
+    }
+
+    /// Elementary cycles within one component, at most `limit` of them, and
+    /// whether any were left out
+    pub fn component_cycles(&self, component: &[String], limit: usize) -> (Vec<Vec<String>>, bool) {
+        let adjacency = self.adjacency();
+        let members: HashSet<&str> = component.iter().map(String::as_str).collect();
+        let mut search = CycleSearch {
+            adjacency: &adjacency,
+            members: &members,
+            limit,
+            cycles: Vec::new(),
+            truncated: false,
+        };
+        for start in component {
+            search.from(start, start, &mut vec![]);
+        }
+        search
+            .cycles
+            .sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        (search.cycles, search.truncated)
+    }
+
+    /// A smallest set of dependencies whose removal breaks every cycle, as
+    /// `(from, to)` pairs
+    ///
+    /// Computed per component by [`ModuleGraph::component_breaking_edges`].
+    pub fn cycle_breaking_edges(&self) -> Vec<(String, String)> {
+        self.cyclic_components()
+            .iter()
+            .flat_map(|component| self.component_breaking_edges(component))
+            .collect()
+    }
+
+    /// A smallest set of dependencies within a component whose removal
+    /// breaks all of its cycles, as sorted `(from, to)` pairs
+    ///
+    /// The set is exact while the component has few dependencies. Beyond that
+    /// it is built greedily: from the shortest remaining cycle, remove the
+    /// dependency that leaves the fewest modules on cycles. Neither way lists
+    /// the cycles themselves.
+    pub fn component_breaking_edges(&self, component: &[String]) -> Vec<(String, String)> {
+        let members: HashSet<&str> = component.iter().map(String::as_str).collect();
+        let mut adjacency: BTreeMap<String, Vec<String>> = self.adjacency();
+        adjacency.retain(|from, _| members.contains(from.as_str()));
+        for targets in adjacency.values_mut() {
+            targets.retain(|to| members.contains(to.as_str()));
+        }
+
+        let without = |removed: &[(&str, &str)]| {
+            let mut remaining = adjacency.clone();
+            for (from, to) in removed {
+                if let Some(targets) = remaining.get_mut(*from) {
+                    targets.retain(|target| target != to);
+                }
+            }
+            remaining
+        };
+
+        let candidates: Vec<(&str, &str)> = adjacency
+            .iter()
+            .flat_map(|(from, targets)| targets.iter().map(move |to| (from.as_str(), to.as_str())))
+            .collect();
+
+        let mut chosen = None;
+        if candidates.len() <= EXACT_BREAKING_SET_LIMIT {
+            let breaks_all = |set: &[(&str, &str)]| cyclic_components_of(&without(set)).is_empty();
+            chosen = (0..=candidates.len()).find_map(|size| {
+                first_combination(&candidates, size, &mut Vec::new(), &breaks_all)
+            });
+        }
+        let mut chosen = chosen.unwrap_or_else(|| {
+            let mut chosen: Vec<(&str, &str)> = Vec::new();
+            loop {
+                let remaining = without(&chosen);
+                let components = cyclic_components_of(&remaining);
+                let Some(cycle) = components
+                    .iter()
+                    .filter_map(|component| shortest_cycle(component, &remaining))
+                    .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+                else {
+                    break;
+                };
+                let best = cycle
+                    .iter()
+                    .zip(cycle.iter().cycle().skip(1))
+                    .map(|(from, to)| {
+                        let edge = candidates
+                            .iter()
+                            .find(|(f, t)| f == from && t == to)
+                            .copied()
+                            .expect("cycle follows graph edges");
+                        let mut removed = chosen.clone();
+                        removed.push(edge);
+                        let on_cycles: usize = cyclic_components_of(&without(&removed))
+                            .iter()
+                            .map(Vec::len)
+                            .sum();
+                        (on_cycles, edge)
+                    })
+                    .min()
+                    .expect("cycle has edges")
+                    .1;
+                chosen.push(best);
+            }
+            chosen
+        });
+        chosen.sort();
+
+        chosen
+            .into_iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect()
+    }
+
+    /// Breadth-first search from `start`, following edges in one direction
+    fn reachable(&self, start: &str, forward: bool) -> Vec<Reached> {
+        let mut distances: HashMap<&str, usize> = HashMap::from([(start, 0)]);
+        let mut reached = Vec::new();
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(module) = queue.pop_front() {
+            let distance = distances[module] + 1;
+            for edge in &self.edges {
+                let (source, target) = if forward {
+                    (&edge.from, &edge.to)
+                } else {
+                    (&edge.to, &edge.from)
+                };
+                if source != module || distances.contains_key(target.as_str()) {
+                    continue;
+                }
+                distances.insert(target, distance);
+                reached.push(Reached {
+                    module: target.clone(),
+                    distance,
+                });
+                queue.push_back(target);
+            }
+        }
+
+        reached.sort_by(|a, b| (a.distance, &a.module).cmp(&(b.distance, &b.module)));
+        reached
+    }
+
+    /// Modules of each layer in [`Self::layers`] order, followed by the
+    /// modules without a layer
+    fn layer_groups(&self) -> (Vec<(&str, Vec<&GraphNode>)>, Vec<&GraphNode>) {
+        let mut groups: Vec<(&str, Vec<&GraphNode>)> = self
+            .layers
+            .iter()
+            .map(|layer| (layer.as_str(), Vec::new()))
+            .collect();
+        let mut ungrouped = Vec::new();
+        for node in &self.nodes {
+            match groups
+                .iter_mut()
+                .find(|(layer, _)| Some(*layer) == node.layer.as_deref())
+            {
+                Some((_, members)) => members.push(node),
+                None => ungrouped.push(node),
+            }
+        }
+        groups.retain(|(_, members)| !members.is_empty());
+        (groups, ungrouped)
+    }
+
+    /// Indented tree of dependencies, starting from the modules nothing
+    /// depends on
+    ///
+    /// A module's dependencies are listed the first time it appears; later
+    /// occurrences are marked `(see above)`, and references back into the
+    /// current path `(cycle)`.
+    pub fn to_text(&self) -> String {
+        let depended_on: HashSet<&str> = self.edges.iter().map(|edge| edge.to.as_str()).collect();
+        let layers: HashMap<&str, Option<&str>> = self
+            .nodes
+            .iter()
+            .map(|node| (node.module.as_str(), node.layer.as_deref()))
+            .collect();
+
+        let mut out = String::new();
+        let mut expanded = HashSet::new();
+        let roots = self
+            .nodes
+            .iter()
+            .filter(|node| !depended_on.contains(node.module.as_str()));
+        // Modules only reachable through a cycle get a tree of their own
+        let remaining = self.nodes.iter();
+
+        for node in roots.chain(remaining) {
+            if expanded.contains(node.module.as_str()) {
+                continue;
+            }
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&node_label(&node.module, node.layer.as_deref()));
+            out.push('\n');
+            self.write_subtree(
+                &node.module,
+                "",
+                &layers,
+                &mut vec![node.module.as_str()],
+                &mut expanded,
+                &mut out,
+            );
+        }
+        out
+    }
+
+    fn write_subtree<'g>(
+        &'g self,
+        module: &'g str,
+        prefix: &str,
+        layers: &HashMap<&str, Option<&str>>,
+        path: &mut Vec<&'g str>,
+        expanded: &mut HashSet<&'g str>,
+        out: &mut String,
+    ) {
+        expanded.insert(module);
+        let edges: Vec<&GraphEdge> = self.edges_from(module).collect();
+
+        for (index, edge) in edges.iter().enumerate() {
+            let last = index + 1 == edges.len();
+            let target = edge.to.as_str();
+            let layer = layers.get(target).copied().flatten();
+
+            let mut line = format!(
+                "{prefix}{}{}",
+                if last { "└── " } else { "├── " },
+                node_label(target, layer)
+            );
+            if edge.layer_violation {
+                line.push_str(" [layer violation]");
+            }
+
+            let descend = if path.contains(&target) {
+                line.push_str(" (cycle)");
+                false
+            } else if expanded.contains(target) {
+                if self.edges_from(target).next().is_some() {
+                    line.push_str(" (see above)");
+                }
+                false
+            } else {
+                true
+            };
+            out.push_str(&line);
+            out.push('\n');
+
+            if descend {
+                let child_prefix = format!("{prefix}{}", if last { "    " } else { "│   " });
+                path.push(target);
+                self.write_subtree(target, &child_prefix, layers, path, expanded, out);
+                path.pop();
+            }
+        }
+    }
+
+    /// Pretty-printed JSON with the layers, nodes and edges
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Graphviz DOT, with a cluster per layer and layer violations in red
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph dependencies {\n");
+        out.push_str("    rankdir=TB;\n");
+        out.push_str("    node [shape=box];\n");
+
+        let (groups, ungrouped) = self.layer_groups();
+        for (index, (layer, members)) in groups.iter().enumerate() {
+            out.push('\n');
+            out.push_str(&format!("    subgraph cluster_{index} {{\n"));
+            out.push_str(&format!("        label={};\n", dot_quote(layer)));
+            for node in members {
+                out.push_str(&format!("        {};\n", dot_quote(&node.module)));
+            }
+            out.push_str("    }\n");
+        }
+        if !ungrouped.is_empty() {
+            out.push('\n');
+            for node in ungrouped {
+                out.push_str(&format!("    {};\n", dot_quote(&node.module)));
+            }
+        }
+
+        if !self.edges.is_empty() {
+            out.push('\n');
+        }
+        for edge in &self.edges {
+            let attributes = if edge.layer_violation {
+                " [color=red, penwidth=2, label=\"layer violation\"]"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "    {} -> {}{attributes};\n",
+                dot_quote(&edge.from),
+                dot_quote(&edge.to)
+            ));
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart, with a subgraph per layer and layer violations in
+    /// red
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart TD\n");
+
+        let (groups, ungrouped) = self.layer_groups();
+        for (index, (layer, members)) in groups.iter().enumerate() {
+            out.push_str(&format!("    subgraph layer_{index} [\"{layer}\"]\n"));
+            for node in members {
+                out.push_str(&format!("        {}\n", mermaid_node(&node.module)));
+            }
+            out.push_str("    end\n");
+        }
+        for node in ungrouped {
+            out.push_str(&format!("    {}\n", mermaid_node(&node.module)));
+        }
+
+        let mut violations = Vec::new();
+        for (index, edge) in self.edges.iter().enumerate() {
+            let arrow = if edge.layer_violation {
+                violations.push(index.to_string());
+                "-->|layer violation|"
+            } else {
+                "-->"
+            };
+            out.push_str(&format!(
+                "    {} {arrow} {}\n",
+                mermaid_id(&edge.from),
+                mermaid_id(&edge.to)
+            ));
+        }
+        if !violations.is_empty() {
+            out.push_str(&format!(
+                "    linkStyle {} stroke:red,stroke-width:2px\n",
+                violations.join(",")
+            ));
+        }
+
+        out
+    }
+}
+
+/// Depth-first enumeration of the elementary cycles of one component, up to
+/// a limit
+struct CycleSearch<'g> {
+    adjacency: &'g BTreeMap<String, Vec<String>>,
+    members: &'g HashSet<&'g str>,
+    limit: usize,
+    cycles: Vec<Vec<String>>,
+    truncated: bool,
+}
+
+impl<'g> CycleSearch<'g> {
+    /// Record the cycles through `module` whose smallest member is `start`
+    fn from(&mut self, start: &str, module: &'g str, path: &mut Vec<&'g str>) {
+        if self.truncated {
+            return;
+        }
+        path.push(module);
+        for target in self.adjacency.get(module).into_iter().flatten() {
+            let target = target.as_str();
+            if target == start {
+                if self.cycles.len() == self.limit {
+                    self.truncated = true;
+                    break;
+                }
+                self.cycles
+                    .push(path.iter().map(|m| m.to_string()).collect());
+            } else if target > start && self.members.contains(target) && !path.contains(&target) {
+                self.from(start, target, path);
+            }
+            if self.truncated {
+                break;
+            }
+        }
+        path.pop();
+    }
+}
+
+/// Depth-first search for the paths of one length to a module, stopping
+/// once `limit` paths have been found overall
+struct PathSearch<'g> {
+    graph: &'g ModuleGraph,
+    to: &'g str,
+    /// Number of dependencies on the paths searched for
+    length: usize,
+    limit: usize,
+    paths: Vec<Vec<String>>,
+    truncated: bool,
+}
+
+impl<'g> PathSearch<'g> {
+    fn from(&mut self, module: &'g str, path: &mut Vec<&'g str>) {
+        for edge in self.graph.edges_from(module) {
+            let target = edge.to.as_str();
+            if target == self.to {
+                if path.len() < self.length {
+                    continue;
+                }
+                if self.paths.len() == self.limit {
+                    self.truncated = true;
+                    return;
+                }
+                let mut found: Vec<String> = path.iter().map(|m| m.to_string()).collect();
+                found.push(target.to_string());
+                self.paths.push(found);
+            } else if path.len() < self.length && !path.contains(&target) {
+                path.push(target);
+                self.from(target, path);
+                path.pop();
+            }
+            if self.truncated {
+                return;
+            }
+        }
+    }
+}
+
+/// First combination of `size` candidates, in order, that satisfies `accept`
+fn first_combination<'c>(
+    candidates: &[(&'c str, &'c str)],
+    size: usize,
+    chosen: &mut Vec<(&'c str, &'c str)>,
+    accept: &impl Fn(&[(&'c str, &'c str)]) -> bool,
+) -> Option<Vec<(&'c str, &'c str)>> {
+    if chosen.len() == size {
+        return accept(chosen).then(|| chosen.clone());
+    }
+    for (index, candidate) in candidates.iter().enumerate() {
+        chosen.push(*candidate);
+        let found = first_combination(&candidates[index + 1..], size, chosen, accept);
+        chosen.pop();
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// Why `from` depends on `to`, through at most `limit` paths in the project's
+/// module graph
+pub fn explain_dependency(
+    project: &Project,
+    from: &str,
+    to: &str,
+    limit: usize,
+) -> DependencyExplanation {
+    let graph = ModuleGraph::from_project(project);
+    let (paths, truncated) = graph.all_paths(from, to, limit);
+
+    let mut hops = BTreeSet::new();
+    for path in &paths {
+        for hop in path.windows(2) {
+            hops.insert((hop[0].as_str(), hop[1].as_str()));
+        }
+    }
+    let edges = hops
+        .into_iter()
+        .map(|(from, to)| EdgeReasons {
+            from: from.to_string(),
+            to: to.to_string(),
+            reasons: dependency_reasons(project, from, to),
+        })
+        .collect();
+
+    DependencyExplanation {
+        from: from.to_string(),
+        to: to.to_string(),
+        paths,
+        truncated,
+        edges,
+    }
+}
+
+/// What makes module `from` depend on module `to` directly: a declared
+/// dependency, calls into it and qualified type references
+pub fn dependency_reasons(project: &Project, from: &str, to: &str) -> Vec<String> {
+    let Some(module) = project.modules.iter().find(|m| m.module == from) else {
+        return Vec::new();
+    };
+    let mut reasons = Vec::new();
+
+    if let Some(constraint) = module.dependencies.get(to) {
+        reasons.push(format!("declared dependency '{constraint}'"));
+    }
+
+    let mut exports: Vec<_> = module.exports.iter().collect();
+    exports.sort_by_key(|(name, _)| *name);
+    for (export_name, export) in exports {
+        let mut methods: Vec<_> = export.methods.iter().flatten().collect();
+        methods.sort_by_key(|(name, _)| *name);
+        for (method_name, method) in methods {
+            for call in &method.calls {
+                if call.split('.').next() == Some(to) {
+                    reasons.push(format!("{from}.{export_name}.{method_name} calls '{call}'"));
+                }
+            }
+        }
+    }
+
+    let parser = TypeParser::for_dialect(TypeDialect::for_project(project));
+    for usage in type_usages(module) {
+        let Ok(type_ref) = parser.parse(usage.type_str) else {
+            continue;
+        };
+        let references_target = type_ref
+            .referenced_names()
+            .iter()
+            .any(|name| name.split_once('.').is_some_and(|(owner, _)| owner == to));
+        if references_target {
+            reasons.push(format!("{} uses type '{}'", usage.location, usage.type_str));
+        }
+    }
+
+    reasons
+}
+
+/// `module (layer)`, or just `module` without a layer
+fn node_label(module: &str, layer: Option<&str>) -> String {
+    match layer {
+        Some(layer) => format!("{module} ({layer})"),
+        None => module.to_string(),
+    }
+}
+
+pub(crate) fn dot_quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Mermaid node id: the module name with anything but letters, digits and
+/// underscores replaced
+pub(crate) fn mermaid_id(module: &str) -> String {
+    module
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Mermaid node declaration, labelled with the module name
+fn mermaid_node(module: &str) -> String {
+    format!("{}[\"{module}\"]", mermaid_id(module))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestProject;
+    use crate::types::{ArchitecturePattern, Layer};
     use std::collections::HashMap;
 
     fn create_module(name: &str, deps: Vec<(&str, &str)>) -> Module {
@@ -53,6 +1027,10 @@ mod tests {
             description: None,
             exports: HashMap::new(),
             dependencies,
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         }
     }
 
@@ -181,4 +1159,349 @@ mod tests {
         assert!(!detect_cycles(&graph));
         assert_eq!(graph.node_count(), 5);
     }
+
+    fn layered_graph() -> ModuleGraph {
+        let mut modules = vec![
+            create_module("api", vec![("service", "1.0.0")]),
+            create_module("service", vec![("user", "1.0.0")]),
+            create_module("user", vec![("api", "1.0.0")]),
+            create_module("tools", vec![("user", "1.0.0")]),
+        ];
+        modules[0].layer = Some("application".to_string());
+        modules[1].layer = Some("application".to_string());
+        modules[2].layer = Some("domain".to_string());
+
+        let architecture = Architecture {
+            pattern: ArchitecturePattern::Layered,
+            layers: vec![
+                Layer {
+                    name: "application".to_string(),
+                    can_depend_on: vec!["application".to_string(), "domain".to_string()],
+                },
+                Layer {
+                    name: "domain".to_string(),
+                    can_depend_on: vec![],
+                },
+            ],
+        };
+        ModuleGraph::new(&modules, Some(&architecture), TypeDialect::TypeScript)
+    }
+
+    #[test]
+    fn test_module_graph_marks_layer_violations() {
+        let graph = layered_graph();
+
+        assert_eq!(graph.layers, vec!["application", "domain"]);
+        let violations: Vec<_> = graph
+            .edges
+            .iter()
+            .filter(|edge| edge.layer_violation)
+            .map(|edge| (edge.from.as_str(), edge.to.as_str()))
+            .collect();
+        assert_eq!(violations, vec![("user", "api")]);
+    }
+
+    #[test]
+    fn test_module_graph_to_text() {
+        let text = layered_graph().to_text();
+
+        assert_eq!(
+            text,
+            "tools\n\
+             └── user (domain)\n\
+             \x20   └── api (application) [layer violation]\n\
+             \x20       └── service (application)\n\
+             \x20           └── user (domain) (cycle)\n"
+        );
+    }
+
+    #[test]
+    fn test_module_graph_to_dot() {
+        let dot = layered_graph().to_dot();
+
+        assert!(dot.contains(
+            "    subgraph cluster_0 {\n        label=\"application\";\n        \"api\";\n        \"service\";\n    }"
+        ));
+        assert!(dot.contains("    \"tools\";\n"));
+        assert!(dot.contains("    \"api\" -> \"service\";\n"));
+        assert!(dot.contains(
+            "    \"user\" -> \"api\" [color=red, penwidth=2, label=\"layer violation\"];\n"
+        ));
+    }
+
+    #[test]
+    fn test_module_graph_to_mermaid() {
+        let mut modules = vec![create_module("user-service", vec![("user", "1.0.0")])];
+        modules.push(create_module("user", vec![]));
+        let mermaid = ModuleGraph::new(&modules, None, TypeDialect::TypeScript).to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("    user_service[\"user-service\"]\n"));
+        assert!(mermaid.contains("    user_service --> user\n"));
+        assert!(!mermaid.contains("linkStyle"));
+
+        let layered = layered_graph().to_mermaid();
+        assert!(layered
+            .contains("    subgraph layer_1 [\"domain\"]\n        user[\"user\"]\n    end\n"));
+        assert!(layered.contains("    user -->|layer violation| api\n"));
+        assert!(layered.contains("    linkStyle 3 stroke:red,stroke-width:2px\n"));
+    }
+
+    fn diamond_graph() -> ModuleGraph {
+        // api -> service -> database, api -> auth -> database, web -> api
+        let modules = vec![
+            create_module("api", vec![("service", "1.0.0"), ("auth", "1.0.0")]),
+            create_module("service", vec![("database", "1.0.0")]),
+            create_module("auth", vec![("database", "1.0.0")]),
+            create_module("database", vec![]),
+            create_module("web", vec![("api", "1.0.0")]),
+        ];
+        ModuleGraph::new(&modules, None, TypeDialect::TypeScript)
+    }
+
+    fn reached(list: &[Reached]) -> Vec<(&str, usize)> {
+        list.iter()
+            .map(|reached| (reached.module.as_str(), reached.distance))
+            .collect()
+    }
+
+    #[test]
+    fn test_transitive_dependencies_and_dependents() {
+        let graph = diamond_graph();
+
+        assert_eq!(
+            reached(&graph.dependencies("api")),
+            vec![("auth", 1), ("service", 1), ("database", 2)]
+        );
+        assert_eq!(
+            reached(&graph.dependents("database")),
+            vec![("auth", 1), ("service", 1), ("api", 2), ("web", 3)]
+        );
+        assert!(graph.dependents("web").is_empty());
+    }
+
+    #[test]
+    fn test_shortest_and_all_paths() {
+        let graph = diamond_graph();
+
+        assert_eq!(
+            graph.shortest_path("web", "database").unwrap(),
+            vec!["web", "api", "auth", "database"]
+        );
+        assert_eq!(
+            graph.all_paths("api", "database", 10),
+            (
+                vec![
+                    vec![
+                        "api".to_string(),
+                        "auth".to_string(),
+                        "database".to_string()
+                    ],
+                    vec![
+                        "api".to_string(),
+                        "service".to_string(),
+                        "database".to_string()
+                    ],
+                ],
+                false
+            )
+        );
+        assert!(graph.shortest_path("database", "api").is_none());
+        assert_eq!(graph.all_paths("database", "api", 10), (vec![], false));
+
+        let (paths, truncated) = graph.all_paths("api", "database", 1);
+        assert_eq!(paths.len(), 1);
+        assert!(truncated);
+    }
+
+    #[test]
+    fn test_path_to_self_is_cycle() {
+        let modules = vec![
+            create_module("a", vec![("b", "1.0.0")]),
+            create_module("b", vec![("a", "1.0.0")]),
+        ];
+        let graph = ModuleGraph::new(&modules, None, TypeDialect::TypeScript);
+
+        assert_eq!(graph.shortest_path("a", "a").unwrap(), vec!["a", "b", "a"]);
+        assert_eq!(graph.all_paths("a", "a", 10).0, vec![vec!["a", "b", "a"]]);
+    }
+
+    fn cyclic_graph() -> ModuleGraph {
+        // a <-> b, b -> c -> a, d -> d, e -> a
+        let modules = vec![
+            create_module("a", vec![("b", "1.0.0")]),
+            create_module("b", vec![("a", "1.0.0"), ("c", "1.0.0")]),
+            create_module("c", vec![("a", "1.0.0")]),
+            create_module("d", vec![("d", "1.0.0")]),
+            create_module("e", vec![("a", "1.0.0")]),
+        ];
+        ModuleGraph::new(&modules, None, TypeDialect::TypeScript)
+    }
+
+    #[test]
+    fn test_cyclic_components() {
+        assert_eq!(
+            cyclic_graph().cyclic_components(),
+            vec![vec!["a", "b", "c"], vec!["d"]]
+        );
+        assert!(diamond_graph().cyclic_components().is_empty());
+    }
+
+    #[test]
+    fn test_shortest_cycle_of_component() {
+        // b -> c -> d -> b is the only cycle through b, c and d; a <-> d is shorter
+        let edges: BTreeMap<String, Vec<String>> = [
+            ("a", vec!["d"]),
+            ("b", vec!["c"]),
+            ("c", vec!["d"]),
+            ("d", vec!["a", "b"]),
+            ("e", vec!["e"]),
+        ]
+        .into_iter()
+        .map(|(from, to)| (from.to_string(), to.into_iter().map(String::from).collect()))
+        .collect();
+
+        let components = cyclic_components_of(&edges);
+        assert_eq!(components, vec![vec!["a", "b", "c", "d"], vec!["e"]]);
+        assert_eq!(
+            shortest_cycle(&components[0], &edges),
+            Some(vec!["a".to_string(), "d".to_string()])
+        );
+        assert_eq!(
+            shortest_cycle(&components[1], &edges),
+            Some(vec!["e".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_elementary_cycles() {
+        let (cycles, truncated) = cyclic_graph().cycles(10);
+        assert_eq!(cycles, vec![vec!["d"], vec!["a", "b"], vec!["a", "b", "c"]]);
+        assert!(!truncated);
+
+        let (cycles, truncated) = cyclic_graph().cycles(2);
+        assert_eq!(cycles.len(), 2);
+        assert!(truncated);
+    }
+
+    #[test]
+    fn test_cycle_breaking_edges() {
+        let edge = |from: &str, to: &str| (from.to_string(), to.to_string());
+
+        assert_eq!(
+            cyclic_graph().cycle_breaking_edges(),
+            vec![edge("a", "b"), edge("d", "d")]
+        );
+        assert!(diamond_graph().cycle_breaking_edges().is_empty());
+    }
+
+    /// `api` calls into `service`, `service` declares a dependency on
+    /// `database`, and `api` references a `database` type without declaring it
+    fn type_reference_project() -> Project {
+        TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "api",
+                "version": "1.0.0",
+                "exports": {
+                    "Handler": {
+                        "type": "class",
+                        "methods": {
+                            "handle": {
+                                "inputs": [{"name": "row", "type": "database.Row"}],
+                                "returns": {"type": "void"},
+                                "calls": ["service.Service.run"]
+                            }
+                        }
+                    }
+                },
+                "dependencies": {"service": "^1.0.0"}
+            }"#,
+            )
+            .module(
+                r#"{
+                "module": "service",
+                "version": "1.0.0",
+                "exports": {"Service": {"type": "class", "methods": {"run": {"inputs": [], "returns": {"type": "void"}}}}},
+                "dependencies": {"database": "^1.0.0"}
+            }"#,
+            )
+            .module(
+                r#"{
+                "module": "database",
+                "version": "1.0.0",
+                "exports": {"Row": {"type": "interface", "properties": {"id": {"type": "string"}}}},
+                "dependencies": {}
+            }"#,
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_queries_follow_type_references() {
+        let project = type_reference_project();
+        let graph = ModuleGraph::from_project(&project);
+
+        assert_eq!(
+            graph.shortest_path("api", "database").unwrap(),
+            vec!["api", "database"]
+        );
+        let dependents: Vec<_> = graph
+            .dependents("database")
+            .into_iter()
+            .map(|reached| reached.module)
+            .collect();
+        assert_eq!(dependents, vec!["api", "service"]);
+    }
+
+    #[test]
+    fn test_why_lists_paths_and_reasons() {
+        let explanation = explain_dependency(&type_reference_project(), "api", "database", 10);
+
+        assert_eq!(
+            explanation.paths,
+            vec![vec!["api", "database"], vec!["api", "service", "database"]]
+        );
+        assert!(!explanation.truncated);
+
+        let reasons: Vec<_> = explanation
+            .edges
+            .iter()
+            .map(|edge| (edge.from.as_str(), edge.to.as_str(), edge.reasons.clone()))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                (
+                    "api",
+                    "database",
+                    vec![
+                        "api.Handler.handle (parameter: row) uses type 'database.Row'".to_string()
+                    ]
+                ),
+                (
+                    "api",
+                    "service",
+                    vec![
+                        "declared dependency '^1.0.0'".to_string(),
+                        "api.Handler.handle calls 'service.Service.run'".to_string(),
+                    ]
+                ),
+                (
+                    "service",
+                    "database",
+                    vec!["declared dependency '^1.0.0'".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_why_stops_after_limit() {
+        let explanation = explain_dependency(&type_reference_project(), "api", "database", 1);
+
+        assert_eq!(explanation.paths, vec![vec!["api", "database"]]);
+        assert!(explanation.truncated);
+        assert_eq!(explanation.edges.len(), 1);
+    }
 }
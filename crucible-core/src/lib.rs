@@ -3,19 +3,31 @@
 //! This library implements the Crucible specification for
 //! AI-native application architecture.
 
+pub mod baseline;
 pub mod cache;
+pub mod calls;
 pub mod claude;
+pub mod custom_rules;
+pub mod effects;
 pub mod error;
 pub mod generator;
 pub mod graph;
+pub mod metrics;
 pub mod parser;
+pub mod rules;
+pub mod source_map;
+pub mod suppression;
+#[cfg(test)]
+mod testing;
 pub mod type_system;
 pub mod types;
 pub mod validator;
+pub mod versions;
 
 pub use error::{CrucibleError, Result};
 pub use generator::Generator;
 pub use parser::Parser;
+pub use rules::{RuleRegistry, ValidationRule};
 pub use types::{Manifest, Module, Project};
 pub use validator::{ChangeTracker, ValidationResult, Validator};
 
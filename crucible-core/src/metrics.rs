@@ -0,0 +1,202 @@
+//! Coupling and abstractness metrics over the module graph
+//!
+//! Follows Robert C. Martin's package metrics: a module's instability is the
+//! share of its couplings that point outward, its abstractness is the share of
+//! its exports that are traits or interfaces, and its distance from the main
+//! sequence measures how far the two are from balancing out.
+
+use crate::graph::ModuleGraph;
+use crate::types::{ExportType, Project};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Metrics of one module
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ModuleMetrics {
+    pub module: String,
+    /// Ca: modules that depend on this one
+    pub afferent_coupling: usize,
+    /// Ce: modules this one depends on
+    pub efferent_coupling: usize,
+    /// I = Ce / (Ca + Ce), from 0 (stable) to 1 (unstable); 0 without couplings
+    pub instability: f64,
+    /// A = trait and interface exports / all exports; 0 without exports
+    pub abstractness: f64,
+    /// D = |A + I - 1|, from 0 (on the main sequence) to 1
+    pub distance: f64,
+}
+
+impl ModuleMetrics {
+    /// Whether anything depends on the module or the module depends on anything
+    pub fn is_coupled(&self) -> bool {
+        self.afferent_coupling + self.efferent_coupling > 0
+    }
+}
+
+/// Metrics of every module in the project, sorted by module name
+///
+/// Couplings count distinct modules and come from the same dependencies as
+/// [`ModuleGraph`]: declared dependencies and qualified type references. A call
+/// to a module that isn't declared as a dependency doesn't count; the
+/// dependency rules report it instead.
+pub fn project_metrics(project: &Project) -> Vec<ModuleMetrics> {
+    let graph = ModuleGraph::from_project(project);
+
+    let mut dependents: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut dependencies: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for edge in graph.edges.iter().filter(|edge| edge.from != edge.to) {
+        dependencies
+            .entry(edge.from.as_str())
+            .or_default()
+            .insert(edge.to.as_str());
+        dependents
+            .entry(edge.to.as_str())
+            .or_default()
+            .insert(edge.from.as_str());
+    }
+
+    let mut metrics: Vec<ModuleMetrics> = project
+        .modules
+        .iter()
+        .map(|module| {
+            let name = module.module.as_str();
+            let afferent = dependents.get(name).map_or(0, BTreeSet::len);
+            let efferent = dependencies.get(name).map_or(0, BTreeSet::len);
+            let instability = ratio(efferent, afferent + efferent);
+
+            let abstract_exports = module
+                .exports
+                .values()
+                .filter(|export| {
+                    matches!(
+                        export.export_type,
+                        ExportType::Trait | ExportType::Interface
+                    )
+                })
+                .count();
+            let abstractness = ratio(abstract_exports, module.exports.len());
+
+            ModuleMetrics {
+                module: module.module.clone(),
+                afferent_coupling: afferent,
+                efferent_coupling: efferent,
+                instability,
+                abstractness,
+                distance: (abstractness + instability - 1.0).abs(),
+            }
+        })
+        .collect();
+    metrics.sort_by(|a, b| a.module.cmp(&b.module));
+    metrics
+}
+
+fn ratio(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Manifest;
+
+    fn project() -> Project {
+        let manifest: Manifest = serde_json::from_str(
+            r#"{"version": "0.1.0", "project": {"name": "test", "language": "typescript"}, "modules": ["api", "ports", "db"]}"#,
+        )
+        .unwrap();
+        let modules = [
+            r#"{"module": "api", "version": "1.0.0", "dependencies": {"ports": "^1.0.0", "db": "^1.0.0"}, "exports": {
+                "Handler": {"type": "class"}
+            }}"#,
+            r#"{"module": "ports", "version": "1.0.0", "dependencies": {}, "exports": {
+                "Users": {"type": "trait"},
+                "User": {"type": "interface"},
+                "UserId": {"type": "type", "alias": "string"}
+            }}"#,
+            r#"{"module": "db", "version": "1.0.0", "dependencies": {"ports": "^1.0.0"}, "exports": {}}"#,
+        ];
+
+        Project {
+            manifest,
+            modules: modules
+                .iter()
+                .map(|module| serde_json::from_str(module).unwrap())
+                .collect(),
+            rules: None,
+            source_map: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_coupling_and_instability() {
+        let metrics = project_metrics(&project());
+        let summary: Vec<_> = metrics
+            .iter()
+            .map(|m| {
+                (
+                    m.module.as_str(),
+                    m.afferent_coupling,
+                    m.efferent_coupling,
+                    m.instability,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![("api", 0, 2, 1.0), ("db", 1, 1, 0.5), ("ports", 2, 0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_abstractness_and_distance() {
+        let metrics = project_metrics(&project());
+
+        let ports = &metrics[2];
+        assert!((ports.abstractness - 2.0 / 3.0).abs() < 1e-9);
+        assert!((ports.distance - 1.0 / 3.0).abs() < 1e-9);
+
+        let db = &metrics[1];
+        assert_eq!(db.abstractness, 0.0);
+        assert_eq!(db.distance, 0.5);
+        assert_eq!(metrics[0].distance, 0.0);
+    }
+
+    #[test]
+    fn test_couplings_from_dependencies_and_type_references_only() {
+        let project = crate::testing::TestProject::new("typescript")
+            .module(
+                r#"{"module": "api", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "Handler": {"type": "class",
+                        "properties": {"user": {"type": "users.User"}},
+                        "methods": {"run": {"inputs": [], "returns": {"type": "void"}, "calls": ["jobs.Queue.push"]}}}
+                }}"#,
+            )
+            .module(
+                r#"{"module": "users", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "User": {"type": "interface"}
+                }}"#,
+            )
+            .module(
+                r#"{"module": "jobs", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "Queue": {"type": "class", "methods": {"push": {"inputs": [], "returns": {"type": "void"}}}}
+                }}"#,
+            )
+            .build();
+
+        let metrics = project_metrics(&project);
+        let couplings: Vec<_> = metrics
+            .iter()
+            .map(|m| (m.module.as_str(), m.afferent_coupling, m.efferent_coupling))
+            .collect();
+
+        assert_eq!(
+            couplings,
+            vec![("api", 0, 1), ("jobs", 0, 0), ("users", 1, 0)]
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::cache::ArchitectureCache;
 use crate::error::{CrucibleError, Result};
+use crate::source_map::SourceMap;
 use crate::types::{Manifest, Module, Project, Rules};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -30,6 +31,9 @@ impl Parser {
     }
 
     /// Parse the entire project with caching
+    ///
+    /// Source positions are recorded from the same file contents the
+    /// definitions are parsed from.
     pub fn parse_project(&self) -> Result<Project> {
         // Try to get cached project first
         let manifest_path = self.root_path.join("manifest.json");
@@ -42,14 +46,28 @@ impl Parser {
         }
 
         // Not cached, parse normally
-        let manifest = self.parse_manifest()?;
-        let modules = self.parse_modules(&manifest.modules)?;
-        let rules = self.parse_rules().ok();
+        let mut source_map = SourceMap::new();
+
+        let (manifest, content) = self.read_manifest()?;
+        source_map.add_manifest(&manifest_path, &content);
+
+        let mut modules = Vec::with_capacity(manifest.modules.len());
+        for name in &manifest.modules {
+            let (module, content) = self.read_module(name)?;
+            source_map.add_module(name, &self.module_path(name), &content);
+            modules.push(module);
+        }
+
+        let rules = self.read_rules().ok().map(|(rules, content)| {
+            source_map.add_rules(&self.root_path.join("rules.json"), &content);
+            rules
+        });
 
         let project = Project {
             manifest,
             modules,
             rules,
+            source_map,
         };
 
         // Cache the result
@@ -63,27 +81,56 @@ impl Parser {
 
     /// Parse the manifest.json file
     pub fn parse_manifest(&self) -> Result<Manifest> {
+        self.read_manifest().map(|(manifest, _)| manifest)
+    }
+
+    /// Parse a module definition file with caching
+    pub fn parse_module(&self, name: &str) -> Result<Module> {
+        self.read_module(name).map(|(module, _)| module)
+    }
+
+    /// Parse all modules listed in the manifest
+    pub fn parse_modules(&self, module_names: &[String]) -> Result<Vec<Module>> {
+        module_names
+            .iter()
+            .map(|name| self.parse_module(name))
+            .collect()
+    }
+
+    /// Parse the rules.json file
+    pub fn parse_rules(&self) -> Result<Rules> {
+        self.read_rules().map(|(rules, _)| rules)
+    }
+
+    fn module_path(&self, name: &str) -> PathBuf {
+        self.root_path.join("modules").join(format!("{name}.json"))
+    }
+
+    /// The manifest and the content it was parsed from
+    fn read_manifest(&self) -> Result<(Manifest, String)> {
         let manifest_path = self.root_path.join("manifest.json");
         let content = fs::read_to_string(&manifest_path).map_err(|e| CrucibleError::FileRead {
             path: manifest_path.display().to_string(),
             source: e,
         })?;
 
-        serde_json::from_str(&content).map_err(|e| CrucibleError::ParseError {
+        let manifest = serde_json::from_str(&content).map_err(|e| CrucibleError::ParseError {
             file: "manifest.json".to_string(),
             message: e.to_string(),
-        })
+        })?;
+        Ok((manifest, content))
     }
 
-    /// Parse a module definition file with caching
-    pub fn parse_module(&self, name: &str) -> Result<Module> {
-        let module_path = self.root_path.join("modules").join(format!("{name}.json"));
+    /// A module and the content it was parsed from, which the cache keeps
+    /// alongside it
+    fn read_module(&self, name: &str) -> Result<(Module, String)> {
+        let module_path = self.module_path(name);
 
         // Check cache first
         {
             let cache = self.cache.lock().unwrap();
-            if let Some(cached_module) = cache.get_module(&module_path)? {
-                return Ok(cached_module);
+            if let Some(cached) = cache.get_module(&module_path)? {
+                return Ok(cached);
             }
         }
 
@@ -102,32 +149,25 @@ impl Parser {
         // Cache the parsed module
         {
             let mut cache = self.cache.lock().unwrap();
-            cache.cache_module(module_path, module.clone())?;
+            cache.cache_module(module_path, module.clone(), content.clone())?;
         }
 
-        Ok(module)
-    }
-
-    /// Parse all modules listed in the manifest
-    pub fn parse_modules(&self, module_names: &[String]) -> Result<Vec<Module>> {
-        module_names
-            .iter()
-            .map(|name| self.parse_module(name))
-            .collect()
+        Ok((module, content))
     }
 
-    /// Parse the rules.json file
-    pub fn parse_rules(&self) -> Result<Rules> {
+    /// The rules and the content they were parsed from
+    fn read_rules(&self) -> Result<(Rules, String)> {
         let rules_path = self.root_path.join("rules.json");
         let content = fs::read_to_string(&rules_path).map_err(|e| CrucibleError::FileRead {
             path: rules_path.display().to_string(),
             source: e,
         })?;
 
-        serde_json::from_str(&content).map_err(|e| CrucibleError::ParseError {
+        let rules = serde_json::from_str(&content).map_err(|e| CrucibleError::ParseError {
             file: "rules.json".to_string(),
             message: e.to_string(),
-        })
+        })?;
+        Ok((rules, content))
     }
 
     /// Clear the cache
@@ -229,4 +269,31 @@ mod tests {
         let module2 = parser.parse_module("test").unwrap();
         assert_eq!(module2.module, "test");
     }
+
+    #[test]
+    fn test_parse_project_records_positions_of_cached_modules() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("modules")).unwrap();
+        fs::write(
+            dir.path().join("manifest.json"),
+            r#"{"version": "0.1.0", "project": {"name": "test", "language": "rust"}, "modules": ["test"]}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("modules").join("test.json"),
+            "{\n  \"module\": \"test\",\n  \"version\": \"1.0.0\",\n  \"exports\": {}\n}",
+        )
+        .unwrap();
+
+        let parser = Parser::new(dir.path());
+        parser.parse_module("test").unwrap();
+        let project = parser.parse_project().unwrap();
+
+        let position = project
+            .source_map
+            .module_position("test", "/version")
+            .unwrap();
+        assert_eq!((position.line, position.column), (3, 3));
+        assert!(project.source_map.manifest_position("/modules").is_some());
+    }
 }
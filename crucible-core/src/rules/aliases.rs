@@ -0,0 +1,272 @@
+//! Validation of `type` exports that alias another type expression
+//!
+//! An alias must expand to a finite type, and any properties it declares must
+//! be part of the object type it aliases, with the same types.
+
+use super::ValidationRule;
+use crate::type_system::{AliasResolver, TypeDialect, TypeParser};
+use crate::types::{ExportType, Project, Severity};
+use crate::validator::ValidationIssue;
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#type-aliases";
+
+/// Aliases must be acyclic and agree with the properties they declare
+pub struct TypeAliasesMustResolve;
+
+impl ValidationRule for TypeAliasesMustResolve {
+    fn id(&self) -> &str {
+        "type-aliases-must-resolve"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Type aliases must expand to a finite type that has the properties they declare"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let resolver = AliasResolver::for_project(project);
+        let parser = TypeParser::for_dialect(TypeDialect::for_project(project));
+
+        for cycle in resolver.cycles() {
+            let path = format!("{} -> {}", cycle.join(" -> "), cycle[0]);
+            let suggestion = "Make one of the references optional or nullable, or wrap it in an \
+                              array, so the type can end.";
+            issues.push(self.issue(
+                format!("Type alias cycle: {path}"),
+                &cycle[0],
+                suggestion.to_string(),
+            ));
+        }
+
+        for module in &project.modules {
+            let mut exports: Vec<_> = module
+                .exports
+                .iter()
+                .filter_map(|(name, export)| Some((name, export, export.alias.as_ref()?)))
+                .collect();
+            exports.sort_by_key(|(name, _, _)| *name);
+
+            for (export_name, export, alias) in exports {
+                let location = format!("{}.{}", module.module, export_name);
+
+                if export.export_type != ExportType::Type {
+                    let export_type = format!("{:?}", export.export_type).to_lowercase();
+                    issues.push(self.issue(
+                        format!("'{location}' has an alias but isn't a type (type: {export_type})"),
+                        &location,
+                        "Change the export's type to \"type\", or remove \"alias\".".to_string(),
+                    ));
+                    continue;
+                }
+
+                let Some(properties) = &export.properties else {
+                    continue;
+                };
+                // Unparseable aliases are reported by all-types-must-exist
+                let Ok(target) = parser.parse(alias) else {
+                    continue;
+                };
+                let Some(shape) = resolver.properties(&target) else {
+                    let message = format!(
+                        "'{location}' declares properties, but its alias '{alias}' isn't an \
+                         object type"
+                    );
+                    let suggestion = "Remove \"properties\", or alias an interface, class or \
+                                      type with properties.";
+                    issues.push(self.issue(message, &location, suggestion.to_string()));
+                    continue;
+                };
+
+                let mut properties: Vec<_> = properties.iter().collect();
+                properties.sort_by_key(|(name, _)| *name);
+
+                for (prop_name, prop) in properties {
+                    let prop_location = format!("{location}.{prop_name}");
+                    match shape.get(prop_name.as_str()) {
+                        None => issues.push(self.issue(
+                            format!("Property '{prop_location}' isn't part of alias '{alias}'"),
+                            &prop_location,
+                            format!("Remove '{prop_name}', or add it to '{alias}'."),
+                        )),
+                        Some(expected)
+                            if !resolver.equivalent(&prop.prop_type, &expected.prop_type) =>
+                        {
+                            issues.push(
+                                ValidationIssue::with_comparison(
+                                    self.id().to_string(),
+                                    self.default_severity(),
+                                    format!(
+                                        "Property '{prop_location}' doesn't match its type in \
+                                         alias '{alias}'"
+                                    ),
+                                    Some(prop_location),
+                                    prop.prop_type.clone(),
+                                    expected.prop_type.clone(),
+                                )
+                                .with_suggestion(format!(
+                                    "Declare '{prop_name}' with type '{}'.",
+                                    expected.prop_type
+                                ))
+                                .with_doc_link(DOC_LINK.to_string()),
+                            );
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+impl TypeAliasesMustResolve {
+    fn issue(&self, message: String, location: &str, suggestion: String) -> ValidationIssue {
+        ValidationIssue::new(
+            self.id().to_string(),
+            self.default_severity(),
+            message,
+            Some(location.to_string()),
+        )
+        .with_suggestion(suggestion)
+        .with_doc_link(DOC_LINK.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::builtin::AllTypesMustExist;
+    use crate::testing::TestProject;
+
+    /// A project whose single `domain` module has the given exports
+    fn project(exports: &str) -> TestProject {
+        TestProject::new("typescript").module(format!(
+            r#"{{"module": "domain", "version": "1.0.0", "exports": {exports}, "dependencies": {{}}}}"#
+        ))
+    }
+
+    fn alias_issues(exports: &str) -> Vec<ValidationIssue> {
+        TypeAliasesMustResolve.validate(&project(exports).build())
+    }
+
+    #[test]
+    fn test_recursion_through_arrays_and_optional_fields_valid() {
+        let result = project(
+            r#"{
+            "Forest": {"type": "type", "alias": "TreeNode[]"},
+            "TreeNode": {
+                "type": "type",
+                "properties": {
+                    "children": {"type": "Forest"},
+                    "parent": {"type": "TreeNode", "required": false},
+                    "next": {"type": "TreeNode | null"}
+                }
+            }
+        }"#,
+        )
+        .validate();
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_alias_cycle_reported() {
+        let issues = alias_issues(
+            r#"{
+            "A": {"type": "type", "alias": "B"},
+            "B": {"type": "type", "alias": "A | string"}
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .message
+            .contains("cycle: domain.A -> domain.B -> domain.A"));
+    }
+
+    #[test]
+    fn test_aliases_referring_to_each_other_reported_once() {
+        let names: Vec<String> = (0..10).map(|i| format!("T{i}")).collect();
+        let exports: Vec<String> = names
+            .iter()
+            .map(|name| {
+                let others: Vec<&str> = names
+                    .iter()
+                    .filter(|other| *other != name)
+                    .map(String::as_str)
+                    .collect();
+                format!(
+                    r#""{name}": {{"type": "type", "alias": "{}"}}"#,
+                    others.join(" | ")
+                )
+            })
+            .collect();
+
+        let issues = alias_issues(&format!("{{{}}}", exports.join(", ")));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .message
+            .contains("cycle: domain.T0 -> domain.T1 -> domain.T0"));
+    }
+
+    #[test]
+    fn test_required_property_cycle_reported() {
+        let issues = alias_issues(
+            r#"{
+            "Node": {"type": "type", "properties": {"next": {"type": "Node"}}}
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("domain.Node"));
+    }
+
+    #[test]
+    fn test_alias_properties_checked_against_resolved_shape() {
+        let issues = alias_issues(
+            r#"{
+            "UserId": {"type": "type", "alias": "string"},
+            "User": {"type": "interface", "properties": {"id": {"type": "UserId"}, "name": {"type": "string"}}},
+            "Admin": {
+                "type": "type",
+                "alias": "User",
+                "properties": {"id": {"type": "string"}, "name": {"type": "number"}, "role": {"type": "string"}}
+            }
+        }"#,
+        );
+
+        assert_eq!(issues.len(), 2, "{issues:?}");
+        assert_eq!(issues[0].location.as_deref(), Some("domain.Admin.name"));
+        assert_eq!(issues[0].expected.as_deref(), Some("string"));
+        assert!(issues[1]
+            .message
+            .contains("'domain.Admin.role' isn't part of alias 'User'"));
+    }
+
+    #[test]
+    fn test_invalid_aliases_reported() {
+        let project = project(
+            r#"{
+            "Status": {"type": "enum", "values": ["active"], "alias": "string"},
+            "Missing": {"type": "type", "alias": "Unknown[]"}
+        }"#,
+        )
+        .build();
+
+        let issues = TypeAliasesMustResolve.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("isn't a type (type: enum)"));
+
+        let issues = AllTypesMustExist.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("domain.Missing (alias)")
+        );
+    }
+}
@@ -0,0 +1,1473 @@
+//! Built-in validation rules
+
+use super::{resolve_export, resolve_implemented, ValidationRule};
+use crate::graph::{module_dependencies, type_dependencies, ModuleGraph};
+use crate::type_system::TypeDialect;
+use crate::types::{ExportType, Project, ReturnType, Severity, TypeParam};
+use crate::validator::{levenshtein_distance, ValidationIssue};
+use std::collections::HashMap;
+
+/// Cycles listed per group of mutually dependent modules before the rest are
+/// summarized in one issue
+const CYCLE_LIMIT: usize = 100;
+
+/// Check for circular dependencies between modules
+pub struct NoCircularDependencies;
+
+impl ValidationRule for NoCircularDependencies {
+    fn id(&self) -> &str {
+        "no-circular-dependencies"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Modules must not have circular dependencies"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let graph = ModuleGraph::from_project(project);
+        let mut issues = Vec::new();
+
+        for component in graph.cyclic_components() {
+            // A densely connected group has exponentially many cycles; list
+            // the first CYCLE_LIMIT and say that the rest were left out
+            let (cycles, truncated) = graph.component_cycles(&component, CYCLE_LIMIT);
+            let breaking_edges = graph.component_breaking_edges(&component);
+            let breaking_list = breaking_edges
+                .iter()
+                .map(|(from, to)| format!("'{from} -> {to}'"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let group_advice = format!(
+                " Removing {breaking_list} breaks every cycle among {}.",
+                component.join(", ")
+            );
+
+            for cycle in &cycles {
+                let chain = format!("{} -> {}", cycle.join(" -> "), cycle[0]);
+                let on_cycle: Vec<String> = breaking_edges
+                    .iter()
+                    .filter(|(from, to)| {
+                        cycle
+                            .iter()
+                            .zip(cycle.iter().cycle().skip(1))
+                            .any(|(a, b)| a == from && b == to)
+                    })
+                    .map(|(from, to)| format!("'{from} -> {to}'"))
+                    .collect();
+
+                let mut suggestion = format!(
+                    "Remove the dependency {} to break this cycle.",
+                    on_cycle.join(" or ")
+                );
+                if cycles.len() > 1 {
+                    suggestion.push_str(&group_advice);
+                }
+                issues.push(self.issue(
+                    format!("Circular dependency: {chain}"),
+                    &cycle[0],
+                    suggestion,
+                ));
+            }
+
+            if truncated {
+                issues.push(self.issue(
+                    format!(
+                        "Circular dependencies among {}: more than {CYCLE_LIMIT} cycles, \
+                         only the first {CYCLE_LIMIT} are listed",
+                        component.join(", ")
+                    ),
+                    &component[0],
+                    group_advice.trim_start().to_string(),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+impl NoCircularDependencies {
+    fn issue(&self, message: String, module: &str, suggestion: String) -> ValidationIssue {
+        ValidationIssue::new(
+            self.id().to_string(),
+            self.default_severity(),
+            message,
+            Some(module.to_string()),
+        )
+        .with_suggestion(format!(
+            "{suggestion} Use 'crucible graph why' to see what causes a dependency."
+        ))
+        .with_doc_link("https://github.com/anvanster/crucible#circular-dependencies".to_string())
+    }
+}
+
+/// Check that modules respect layer boundaries
+pub struct RespectLayerBoundaries;
+
+impl ValidationRule for RespectLayerBoundaries {
+    fn id(&self) -> &str {
+        "respect-layer-boundaries"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Modules can only depend on allowed layers"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        let Some(architecture) = project
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.architecture.as_ref())
+        else {
+            return issues;
+        };
+
+        // Create a map of module to layer
+        let mut module_layers = HashMap::new();
+        for module in &project.modules {
+            if let Some(layer) = &module.layer {
+                module_layers.insert(module.module.clone(), layer.clone());
+            }
+        }
+
+        // Check each module's dependencies
+        for module in &project.modules {
+            if let Some(from_layer) = module.layer.as_ref() {
+                // Find the layer definition
+                let layer_def = architecture.layers.iter().find(|l| &l.name == from_layer);
+
+                if let Some(layer) = layer_def {
+                    // Check each dependency, including qualified type references
+                    for dep_name in module_dependencies(
+                        module,
+                        &project.modules,
+                        TypeDialect::for_project(project),
+                    ) {
+                        if let Some(to_layer) = module_layers.get(&dep_name) {
+                            // Check if this dependency is allowed
+                            if !layer.can_depend_on.contains(to_layer) {
+                                let allowed_layers = layer.can_depend_on.join(", ");
+                                issues.push(
+                                    ValidationIssue::with_comparison(
+                                        "respect-layer-boundaries".to_string(),
+                                        self.default_severity(),
+                                        format!(
+                                            "Layer boundary violation: '{from_layer}' cannot depend on '{to_layer}'"
+                                        ),
+                                        Some(format!("{} -> {}", module.module, dep_name)),
+                                        format!("dependency on '{to_layer}' layer"),
+                                        format!("dependency on one of: {allowed_layers}"),
+                                    )
+                                    .with_suggestion(format!(
+                                        "Remove the dependency on '{dep_name}' from module '{}', \
+                                         or restructure your architecture to allow '{from_layer}' → '{to_layer}' dependencies.",
+                                        module.module
+                                    ))
+                                    .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/common-mistakes.md#layer-dependency-issues".to_string()),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that all referenced types exist
+pub struct AllTypesMustExist;
+
+impl ValidationRule for AllTypesMustExist {
+    fn id(&self) -> &str {
+        "all-types-must-exist"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "All referenced types must be defined"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        // Collect all available types
+        let mut available_types = HashMap::new();
+        for module in &project.modules {
+            for export_name in module.exports.keys() {
+                available_types.insert(format!("{}.{}", module.module, export_name), true);
+                available_types.insert(export_name.clone(), true);
+            }
+        }
+
+        // Add primitive types
+        for primitive in &["string", "number", "boolean", "void", "null", "Date"] {
+            available_types.insert(primitive.to_string(), true);
+        }
+
+        // Check all type references
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                let type_params = &export.type_params;
+
+                // Check that type parameter bounds exist
+                for param in type_params {
+                    let Some(bound) = &param.bound else {
+                        continue;
+                    };
+                    if let Err(reason) = is_type_available(bound, type_params, project) {
+                        issues.push(
+                            ValidationIssue::new(
+                                "all-types-must-exist".to_string(),
+                                self.default_severity(),
+                                format!(
+                                    "Invalid bound '{}' of type parameter '{}': {reason}",
+                                    bound, param.name
+                                ),
+                                Some(format!(
+                                    "{}.{} (type parameter: {})",
+                                    module.module, export_name, param.name
+                                )),
+                            )
+                            .with_suggestion(
+                                "Bound type parameters by a trait or interface exported from a \
+                                 module listed in dependencies."
+                                    .to_string(),
+                            )
+                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#generic-types".to_string()),
+                        );
+                    }
+                }
+
+                // Check the aliased type expression
+                if let Some(alias) = &export.alias {
+                    if let Err(reason) = is_type_available(alias, type_params, project) {
+                        let similar_types = find_similar_types(alias, &available_types);
+                        issues.push(
+                            ValidationIssue::new(
+                                "all-types-must-exist".to_string(),
+                                self.default_severity(),
+                                format!("Invalid alias '{alias}': {reason}"),
+                                Some(format!("{}.{} (alias)", module.module, export_name)),
+                            )
+                            .with_suggestion(type_suggestion(&similar_types))
+                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#type-aliases".to_string()),
+                        );
+                    }
+                }
+
+                // Check property types
+                if let Some(properties) = &export.properties {
+                    for (prop_name, prop) in properties {
+                        if let Err(reason) =
+                            is_type_available(&prop.prop_type, type_params, project)
+                        {
+                            let similar_types =
+                                find_similar_types(&prop.prop_type, &available_types);
+                            issues.push(
+                                ValidationIssue::new(
+                                    "all-types-must-exist".to_string(),
+                                    self.default_severity(),
+                                    format!(
+                                        "Invalid type '{}' in property '{}': {reason}",
+                                        prop.prop_type, prop_name
+                                    ),
+                                    Some(format!("{}.{}.{}", module.module, export_name, prop_name)),
+                                )
+                                .with_suggestion(type_suggestion(&similar_types))
+                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/type-system.md".to_string()),
+                            );
+                        }
+                    }
+                }
+
+                // Check method parameter and return types
+                if let Some(methods) = &export.methods {
+                    for (method_name, method) in methods {
+                        // Check input types
+                        for param in &method.inputs {
+                            if let Err(reason) =
+                                is_type_available(&param.param_type, type_params, project)
+                            {
+                                // Try to find similar type names for suggestion
+                                let similar_types =
+                                    find_similar_types(&param.param_type, &available_types);
+                                let issue = ValidationIssue::new(
+                                    "all-types-must-exist".to_string(),
+                                    self.default_severity(),
+                                    format!("Invalid type '{}' in parameter '{}': {reason}", param.param_type, param.name),
+                                    Some(format!(
+                                        "{}.{}.{} (parameter: {})",
+                                        module.module, export_name, method_name, param.name
+                                    )),
+                                )
+                                .with_suggestion(type_suggestion(&similar_types))
+                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/type-system.md".to_string());
+
+                                issues.push(issue);
+                            }
+                        }
+
+                        // Check return type (including array items if present)
+                        if let Err(reason) =
+                            is_return_type_available(&method.returns, type_params, project)
+                        {
+                            let type_desc = match &method.returns.inner {
+                                Some(inner) if method.returns.return_type == "array" => {
+                                    format!("array<{inner}>")
+                                }
+                                _ => method.returns.return_type.clone(),
+                            };
+
+                            let similar_types = find_similar_types(&type_desc, &available_types);
+                            let mut issue = ValidationIssue::new(
+                                "all-types-must-exist".to_string(),
+                                self.default_severity(),
+                                format!("Invalid return type '{type_desc}': {reason}"),
+                                Some(format!(
+                                    "{}.{}.{} (returns)",
+                                    module.module, export_name, method_name
+                                )),
+                            )
+                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/type-system.md".to_string());
+
+                            if !similar_types.is_empty() {
+                                issue = issue.with_suggestion(type_suggestion(&similar_types));
+                            } else {
+                                issue = issue.with_suggestion(
+                                    "Ensure the type is exported from a module listed in dependencies, \
+                                     or use a built-in type (string, number, boolean, void, Date, Promise<T>)."
+                                        .to_string(),
+                                );
+                            }
+
+                            issues.push(issue);
+                        }
+                    }
+                }
+
+                // Validate event payload types exist
+                if let (ExportType::Event, Some(payload)) = (&export.export_type, &export.payload) {
+                    for (field_name, prop) in payload {
+                        if is_type_available(&prop.prop_type, type_params, project).is_err() {
+                            issues.push(
+                                ValidationIssue::new(
+                                    "all-types-must-exist".to_string(),
+                                    self.default_severity(),
+                                    format!(
+                                        "Type '{}' not found in event payload field '{}'",
+                                        prop.prop_type, field_name
+                                    ),
+                                    Some(format!("{}.{}.payload.{}", module.module, export_name, field_name)),
+                                )
+                                .with_suggestion(
+                                    "Ensure the type is exported from a module listed in dependencies, \
+                                     or use a built-in type (string, number, boolean, Date)."
+                                        .to_string(),
+                                )
+                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/type-system.md".to_string()),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that all function calls reference existing exports
+/// Call format: "module.Export.method" or "module.function"
+pub struct AllCallsMustExist;
+
+impl ValidationRule for AllCallsMustExist {
+    fn id(&self) -> &str {
+        "all-calls-must-exist"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "All method calls must reference existing exports"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        // Build a map of all available exports and their methods
+        let mut available_exports: HashMap<String, HashMap<String, bool>> = HashMap::new();
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                let full_export_name = format!("{}.{}", module.module, export_name);
+                let mut methods = HashMap::new();
+
+                // Add methods if they exist
+                if let Some(export_methods) = &export.methods {
+                    for method_name in export_methods.keys() {
+                        methods.insert(method_name.clone(), true);
+                    }
+                }
+
+                available_exports.insert(full_export_name, methods);
+            }
+        }
+
+        // Check all calls in the project
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                if let Some(methods) = &export.methods {
+                    for (method_name, method) in methods {
+                        for call in &method.calls {
+                            // Parse call: "module.Export.method" or "module.function"
+                            let parts: Vec<&str> = call.split('.').collect();
+
+                            if parts.len() < 2 {
+                                issues.push(
+                                    ValidationIssue::with_comparison(
+                                        "all-calls-must-exist".to_string(),
+                                        self.default_severity(),
+                                        format!("Invalid call format: '{call}'"),
+                                        Some(format!(
+                                            "{}.{}.{}",
+                                            module.module, export_name, method_name
+                                        )),
+                                        format!("'{call}'"),
+                                        "'module.Export.method' or 'module.function'".to_string(),
+                                    )
+                                    .with_suggestion(
+                                        "Use format 'module.function' for function calls or \
+                                         'module.Export.method' for method calls."
+                                            .to_string(),
+                                    )
+                                    .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#method-calls".to_string()),
+                                );
+                                continue;
+                            }
+
+                            let target_module = parts[0];
+                            let target_export = parts[1];
+
+                            // Check if this is a self-call (calling own export's methods)
+                            // Format: "module.method" where module == current && method is on same export
+                            if target_module == module.module && parts.len() == 2 {
+                                // Check if this is calling a method on the SAME export (self-call)
+                                if let Some(self_methods) = &export.methods {
+                                    if self_methods.contains_key(target_export) {
+                                        // This is a self-call - validate it exists (already checked above)
+                                        continue;
+                                    }
+                                }
+                                // Not a self-call, fall through to normal validation
+                            }
+
+                            // Check if calling own export's method with full format
+                            if target_module == module.module
+                                && parts.len() == 3
+                                && target_export == export_name
+                            {
+                                // Full format: "module.Export.method" calling same export
+                                let target_method = parts[2];
+                                if let Some(self_methods) = &export.methods {
+                                    if !self_methods.contains_key(target_method) {
+                                        issues.push(
+                                            ValidationIssue::new(
+                                                "all-calls-must-exist".to_string(),
+                                                self.default_severity(),
+                                                format!(
+                                                    "Method '{target_method}' not found on '{export_name}'"
+                                                ),
+                                                Some(format!(
+                                                    "{}.{}.{}",
+                                                    module.module, export_name, method_name
+                                                )),
+                                            )
+                                            .with_suggestion(format!(
+                                                "Ensure '{target_method}' is defined as a method in the '{export_name}' export.",
+                                            ))
+                                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#method-calls".to_string()),
+                                        );
+                                    }
+                                }
+                                continue;
+                            }
+
+                            // Check if it's a function call (2 parts) or method call (3 parts)
+                            if parts.len() == 2 {
+                                // Function call: module.function
+                                let full_name = format!("{target_module}.{target_export}");
+                                if !available_exports.contains_key(&full_name) {
+                                    issues.push(
+                                        ValidationIssue::new(
+                                            "all-calls-must-exist".to_string(),
+                                            self.default_severity(),
+                                            format!("Call target '{call}' not found"),
+                                            Some(format!(
+                                                "{}.{}.{}",
+                                                module.module, export_name, method_name
+                                            )),
+                                        )
+                                        .with_suggestion(format!(
+                                            "Ensure '{target_export}' is exported from module '{target_module}' \
+                                             and '{target_module}' is listed in dependencies."
+                                        ))
+                                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
+                                    );
+                                }
+                            } else if parts.len() == 3 {
+                                // Method call: module.Export.method
+                                let target_method = parts[2];
+                                let full_export = format!("{target_module}.{target_export}");
+
+                                if let Some(export_methods) = available_exports.get(&full_export) {
+                                    if !export_methods.contains_key(target_method) {
+                                        issues.push(
+                                            ValidationIssue::new(
+                                                "all-calls-must-exist".to_string(),
+                                                self.default_severity(),
+                                                format!(
+                                                    "Method '{target_method}' not found on '{target_module}.{target_export}'"
+                                                ),
+                                                Some(format!(
+                                                    "{}.{}.{}",
+                                                    module.module, export_name, method_name
+                                                )),
+                                            )
+                                            .with_suggestion(format!(
+                                                "Ensure '{target_method}' is defined as a method in export '{target_export}' of module '{target_module}'.",
+                                            ))
+                                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#methods".to_string()),
+                                        );
+                                    }
+                                } else {
+                                    issues.push(
+                                        ValidationIssue::new(
+                                            "all-calls-must-exist".to_string(),
+                                            self.default_severity(),
+                                            format!(
+                                                "Export '{target_module}.{target_export}' not found"
+                                            ),
+                                            Some(format!(
+                                                "{}.{}.{}",
+                                                module.module, export_name, method_name
+                                            )),
+                                        )
+                                        .with_suggestion(format!(
+                                            "Ensure '{target_export}' is exported from module '{target_module}' \
+                                             and '{target_module}' is listed in dependencies."
+                                        ))
+                                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
+                                    );
+                                }
+                            } else {
+                                issues.push(
+                                    ValidationIssue::with_comparison(
+                                        "all-calls-must-exist".to_string(),
+                                        self.default_severity(),
+                                        format!("Invalid call format: '{call}'"),
+                                        Some(format!(
+                                            "{}.{}.{}",
+                                            module.module, export_name, method_name
+                                        )),
+                                        format!("'{call}'"),
+                                        "'module.function' or 'module.Export.method'".to_string(),
+                                    )
+                                    .with_suggestion(
+                                        "Use format 'module.function' for function calls or \
+                                         'module.Export.method' for method calls."
+                                            .to_string(),
+                                    )
+                                    .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#method-calls".to_string()),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that all modules referenced in calls are declared as dependencies
+pub struct UsedDependenciesDeclared;
+
+impl ValidationRule for UsedDependenciesDeclared {
+    fn id(&self) -> &str {
+        "used-dependencies-declared"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "All used modules must be declared in dependencies"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            let mut used_modules = std::collections::HashSet::new();
+
+            // Collect all modules referenced in calls
+            for export in module.exports.values() {
+                // Modules owning implemented traits
+                for implemented in &export.implements {
+                    if let Some((owner, ..)) = resolve_implemented(project, module, implemented) {
+                        if owner.module != module.module {
+                            used_modules.insert(owner.module.clone());
+                        }
+                    }
+                }
+
+                if let Some(methods) = &export.methods {
+                    for method in methods.values() {
+                        for call in &method.calls {
+                            // Extract module name from call (first part before '.')
+                            if let Some(target_module) = call.split('.').next() {
+                                // Skip if calling own module
+                                if target_module != module.module {
+                                    used_modules.insert(target_module.to_string());
+                                }
+                            }
+                        }
+
+                        // Modules owning emitted and subscribed events
+                        for event in method.emits.iter().chain(&method.subscribes) {
+                            if let Some((owner, _)) = resolve_export(project, module, event) {
+                                if owner.module != module.module {
+                                    used_modules.insert(owner.module.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Modules owning qualified types in signatures, properties and payloads
+            used_modules.extend(type_dependencies(
+                module,
+                &project.modules,
+                TypeDialect::for_project(project),
+            ));
+
+            // Check that all used modules are in dependencies
+            for used_module in used_modules {
+                if !module.dependencies.contains_key(&used_module) {
+                    issues.push(
+                        ValidationIssue::new(
+                            "used-dependencies-declared".to_string(),
+                            self.default_severity(),
+                            format!(
+                                "Module '{used_module}' is used but not declared in dependencies"
+                            ),
+                            Some(module.module.clone()),
+                        )
+                        .with_suggestion(format!(
+                            "Add '{used_module}' to the dependencies field in module '{}'.\n\
+                             Example: \"dependencies\": {{\"{}user\": \"ExportName\", ... }}",
+                            module.module, used_module
+                        ))
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that all declared dependencies are actually used (warning only)
+pub struct DeclaredDependenciesMustBeUsed;
+
+impl ValidationRule for DeclaredDependenciesMustBeUsed {
+    fn id(&self) -> &str {
+        "declared-dependencies-must-be-used"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Declared dependencies should be used"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            let mut used_modules = std::collections::HashSet::new();
+
+            // Collect all modules referenced in calls
+            for export in module.exports.values() {
+                for implemented in &export.implements {
+                    if let Some((owner, ..)) = resolve_implemented(project, module, implemented) {
+                        used_modules.insert(owner.module.clone());
+                    }
+                }
+
+                if let Some(methods) = &export.methods {
+                    for method in methods.values() {
+                        for call in &method.calls {
+                            if let Some(target_module) = call.split('.').next() {
+                                used_modules.insert(target_module.to_string());
+                            }
+                        }
+                        for event in method.emits.iter().chain(&method.subscribes) {
+                            if let Some((owner, _)) = resolve_export(project, module, event) {
+                                used_modules.insert(owner.module.clone());
+                            }
+                        }
+                    }
+                }
+            }
+
+            used_modules.extend(type_dependencies(
+                module,
+                &project.modules,
+                TypeDialect::for_project(project),
+            ));
+
+            // Check for unused dependencies
+            for dep_name in module.dependencies.keys() {
+                if !used_modules.contains(dep_name) {
+                    issues.push(
+                        ValidationIssue::new(
+                            "declared-dependencies-must-be-used".to_string(),
+                            self.default_severity(),
+                            format!("Dependency '{dep_name}' is declared but not used"),
+                            Some(module.module.clone()),
+                        )
+                        .with_suggestion(format!(
+                            "Remove '{dep_name}' from the dependencies field in module '{}', \
+                             or add a method call that uses it.",
+                            module.module
+                        ))
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that events define payload rather than methods
+pub struct EventStructure;
+
+impl ValidationRule for EventStructure {
+    fn id(&self) -> &str {
+        "event-structure"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Events should define a payload, not methods"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                if export.export_type != ExportType::Event {
+                    continue;
+                }
+
+                // Events should have payload, not methods
+                if export.methods.is_some() && !export.methods.as_ref().unwrap().is_empty() {
+                    issues.push(
+                        ValidationIssue::new(
+                            "event-structure".to_string(),
+                            self.default_severity(),
+                            format!("Event '{export_name}' has methods defined. Events should define payload, not methods."),
+                            Some(format!("{}.{}", module.module, export_name)),
+                        )
+                        .with_suggestion(
+                            "Remove methods from the event and define the event data in the 'payload' field instead.".to_string(),
+                        )
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#events".to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that traits define methods only
+pub struct TraitStructure;
+
+impl ValidationRule for TraitStructure {
+    fn id(&self) -> &str {
+        "trait-structure"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Traits should only define methods"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                if export.export_type != ExportType::Trait {
+                    continue;
+                }
+
+                // Traits should have methods, not properties
+                if export.properties.is_some() && !export.properties.as_ref().unwrap().is_empty() {
+                    issues.push(
+                        ValidationIssue::new(
+                            "trait-structure".to_string(),
+                            self.default_severity(),
+                            format!("Trait '{export_name}' has properties defined. Traits should only define methods."),
+                            Some(format!("{}.{}", module.module, export_name)),
+                        )
+                        .with_suggestion(
+                            "Remove properties from the trait. Traits should only contain method signatures. \
+                             Use 'interface' if you need properties.".to_string(),
+                        )
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#traits".to_string()),
+                    );
+                }
+
+                // Traits should have at least one method
+                if export.methods.is_none() || export.methods.as_ref().unwrap().is_empty() {
+                    issues.push(
+                        ValidationIssue::new(
+                            "trait-structure".to_string(),
+                            self.default_severity(),
+                            format!("Trait '{export_name}' has no methods defined. Traits should define at least one method."),
+                            Some(format!("{}.{}", module.module, export_name)),
+                        )
+                        .with_suggestion(
+                            "Add at least one method to the trait.".to_string(),
+                        )
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#traits".to_string()),
+                    );
+                }
+
+                // Traits should not have payload; unlike the style checks
+                // above, this is invalid schema and always an error
+                if export.payload.is_some() {
+                    issues.push(
+                        ValidationIssue::new(
+                            "trait-structure".to_string(),
+                            Severity::Error,
+                            format!("Trait '{export_name}' has payload defined. Payload is only valid for events."),
+                            Some(format!("{}.{}", module.module, export_name)),
+                        )
+                        .with_suggestion(
+                            "Remove the 'payload' field from the trait. Use 'event' type if you need a payload.".to_string(),
+                        )
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#traits".to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check that payload is only used by event exports
+pub struct ExportStructure;
+
+impl ValidationRule for ExportStructure {
+    fn id(&self) -> &str {
+        "export-structure"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Only events may define a payload"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                // Events carry payloads; payloads on traits have been reported
+                // by trait-structure alone since before rules were registered
+                if matches!(export.export_type, ExportType::Event | ExportType::Trait) {
+                    continue;
+                }
+
+                // For non-event types, payload should not be used
+                if export.payload.is_some() {
+                    issues.push(
+                        ValidationIssue::new(
+                            "export-structure".to_string(),
+                            self.default_severity(),
+                            format!("Export '{export_name}' has payload defined but is not an event type."),
+                            Some(format!("{}.{}", module.module, export_name)),
+                        )
+                        .with_suggestion(
+                            "Remove the 'payload' field or change the type to 'event'.".to_string(),
+                        )
+                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#events".to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Check if a type is available (handles generics)
+fn is_type_available(
+    type_name: &str,
+    type_params: &[TypeParam],
+    project: &Project,
+) -> Result<(), String> {
+    // Use the new TypeValidator for enhanced type checking
+    use crate::type_system::TypeValidator;
+
+    let validator = TypeValidator::for_project(project);
+    validator.validate_type_string_in_scope(type_name, type_params, &project.modules)
+}
+
+/// Check if a return type is available (handles array items)
+fn is_return_type_available(
+    return_type: &ReturnType,
+    type_params: &[TypeParam],
+    project: &Project,
+) -> Result<(), String> {
+    use crate::type_system::{TypeParser, TypeValidator};
+
+    let parser = TypeParser::for_dialect(TypeDialect::for_project(project));
+    let validator = TypeValidator::for_project(project);
+
+    // Parse the return type with items if present
+    let type_ref = if return_type.return_type == "array" && return_type.inner.is_some() {
+        // Array with items
+        parser.parse_from_json("array", None, return_type.inner.as_deref(), None)?
+    } else {
+        // Regular type
+        parser.parse(&return_type.return_type)?
+    };
+
+    validator.validate_type_in_scope(&type_ref, type_params, &project.modules)
+}
+
+/// Suggestion for a type that doesn't exist, listing similar type names
+fn type_suggestion(similar_types: &[String]) -> String {
+    if similar_types.is_empty() {
+        "Ensure the type is exported from a module listed in dependencies, \
+         or use a built-in type (string, number, boolean, void, Date)."
+            .to_string()
+    } else {
+        format!(
+            "Did you mean one of: {}? If using a type from another module, \
+             ensure it's listed in the dependencies field.",
+            similar_types.join(", ")
+        )
+    }
+}
+
+/// Find type names similar to the given type (for suggestions)
+/// Uses simple Levenshtein distance for fuzzy matching
+fn find_similar_types(target_type: &str, available_types: &HashMap<String, bool>) -> Vec<String> {
+    let mut candidates: Vec<(String, usize)> = available_types
+        .keys()
+        .filter_map(|type_name| {
+            let distance = levenshtein_distance(target_type, type_name);
+            // Only suggest if distance is small relative to type name length
+            if distance <= 3 && distance < target_type.len() / 2 {
+                Some((type_name.clone(), distance))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Sort by distance (closest first) and take top 3
+    candidates.sort_by_key(|(_, dist)| *dist);
+    candidates
+        .into_iter()
+        .take(3)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// A project with one module per `(name, dependencies)` pair
+    fn dependency_project(modules: &[(&str, &[&str])]) -> Project {
+        modules
+            .iter()
+            .fold(
+                TestProject::new("typescript"),
+                |project, (name, dependencies)| {
+                    let dependencies: Vec<String> = dependencies
+                        .iter()
+                        .map(|dependency| format!("\"{dependency}\": \"^1.0.0\""))
+                        .collect();
+                    project.module(format!(
+                        r#"{{"module": "{name}", "version": "1.0.0", "exports": {{}}, "dependencies": {{{}}}}}"#,
+                        dependencies.join(", ")
+                    ))
+                },
+            )
+            .build()
+    }
+
+    fn messages(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_each_cycle_reported_shortest_first() {
+        let project = dependency_project(&[
+            ("api", &["service"]),
+            ("service", &["api", "database"]),
+            ("database", &["api"]),
+            ("logger", &[]),
+            ("jobs", &["queue"]),
+            ("queue", &["jobs"]),
+        ]);
+
+        let issues = NoCircularDependencies.validate(&project);
+        assert_eq!(
+            messages(&issues),
+            vec![
+                "Circular dependency: api -> service -> api",
+                "Circular dependency: api -> service -> database -> api",
+                "Circular dependency: jobs -> queue -> jobs",
+            ]
+        );
+        assert_eq!(issues[0].location.as_deref(), Some("api"));
+        assert_eq!(
+            issues[0].severity,
+            NoCircularDependencies.default_severity()
+        );
+    }
+
+    #[test]
+    fn test_suggestion_names_minimum_breaking_edges() {
+        let project = dependency_project(&[
+            ("api", &["service"]),
+            ("service", &["api", "database"]),
+            ("database", &["api"]),
+        ]);
+
+        let issues = NoCircularDependencies.validate(&project);
+        for issue in &issues {
+            assert!(issue.suggestion.as_deref().unwrap().starts_with(
+                "Remove the dependency 'api -> service' to break this cycle. \
+                 Removing 'api -> service' breaks every cycle among api, database, service."
+            ));
+        }
+    }
+
+    #[test]
+    fn test_densely_connected_modules_cycles_capped() {
+        let names: Vec<String> = (1..=9).map(|i| format!("m{i}")).collect();
+        let dependencies: Vec<Vec<&str>> = names
+            .iter()
+            .map(|name| {
+                names
+                    .iter()
+                    .filter(|other| *other != name)
+                    .map(String::as_str)
+                    .collect()
+            })
+            .collect();
+        let modules: Vec<(&str, &[&str])> = names
+            .iter()
+            .zip(&dependencies)
+            .map(|(name, dependencies)| (name.as_str(), dependencies.as_slice()))
+            .collect();
+
+        let issues = NoCircularDependencies.validate(&dependency_project(&modules));
+        assert_eq!(issues.len(), CYCLE_LIMIT + 1);
+        assert_eq!(issues[0].message, "Circular dependency: m1 -> m2 -> m1");
+        assert_eq!(
+            issues[CYCLE_LIMIT].message,
+            "Circular dependencies among m1, m2, m3, m4, m5, m6, m7, m8, m9: \
+             more than 100 cycles, only the first 100 are listed"
+        );
+        assert!(issues[CYCLE_LIMIT]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .starts_with("Removing 'm1 -> m2', "));
+    }
+
+    #[test]
+    fn test_acyclic_graph_has_no_cycle_issues() {
+        let project = dependency_project(&[("api", &["service"]), ("service", &[])]);
+
+        assert!(NoCircularDependencies.validate(&project).is_empty());
+    }
+
+    /// A layered project where `user` (domain) and `api` (application) only
+    /// reference each other through the return type of `api.UserApi.get` and
+    /// the type of the `User.owner` property
+    fn type_reference_project(api_type: &str, user_type: &str, api_dependencies: &str) -> Project {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "user",
+                "version": "1.0.0",
+                "layer": "domain",
+                "exports": {{
+                    "User": {{"type": "interface", "properties": {{"owner": {{"type": "{user_type}"}}}}}}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+            .module(format!(
+                r#"{{
+                "module": "api",
+                "version": "1.0.0",
+                "layer": "application",
+                "exports": {{
+                    "UserApi": {{
+                        "type": "class",
+                        "methods": {{"get": {{"inputs": [], "returns": {{"type": "{api_type}"}}}}}}
+                    }}
+                }},
+                "dependencies": {api_dependencies}
+            }}"#
+            ))
+            .rules(Some(
+                r#"{"architecture": {"pattern": "layered", "layers": [
+                    {"name": "application", "can_depend_on": ["domain"]},
+                    {"name": "domain", "can_depend_on": []}
+                ]}, "rules": []}"#,
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_declared_type_dependency_valid() {
+        let project = type_reference_project("user.User[]", "string", r#"{"user": "^1.0.0"}"#);
+
+        assert!(UsedDependenciesDeclared.validate(&project).is_empty());
+        assert!(DeclaredDependenciesMustBeUsed.validate(&project).is_empty());
+        assert!(RespectLayerBoundaries.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_undeclared_qualified_type_reported() {
+        let project = type_reference_project("Promise<user.User | null>", "string", "{}");
+
+        let issues = UsedDependenciesDeclared.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'user'"));
+        assert_eq!(issues[0].location.as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn test_type_reference_across_layers_reported() {
+        let project = type_reference_project("string", "api.UserApi", "{}");
+
+        let issues = RespectLayerBoundaries.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("user -> api"));
+    }
+
+    #[test]
+    fn test_type_reference_cycle_reported() {
+        let project = type_reference_project("user.User", "api.UserApi", r#"{"user": "^1.0.0"}"#);
+
+        assert_eq!(NoCircularDependencies.validate(&project).len(), 1);
+    }
+
+    /// A `language` project where `api.UserApi.get` returns `returns` and
+    /// declares `api_dependencies`, next to a `user` module exporting `User`
+    fn dialect_project(language: &str, returns: &str, api_dependencies: &str) -> Project {
+        TestProject::new(language)
+            .module(
+                r#"{
+                "module": "user",
+                "version": "1.0.0",
+                "exports": {"User": {"type": "interface", "properties": {"id": {"type": "string"}}}},
+                "dependencies": {}
+            }"#,
+            )
+            .module(format!(
+                r#"{{
+                "module": "api",
+                "version": "1.0.0",
+                "exports": {{
+                    "UserApi": {{
+                        "type": "class",
+                        "methods": {{"get": {{"inputs": [], "returns": {{"type": "{returns}"}}}}}}
+                    }}
+                }},
+                "dependencies": {api_dependencies}
+            }}"#
+            ))
+            .build()
+    }
+
+    const DEPENDS_ON_USER: &str = r#"{"user": "^1.0.0"}"#;
+
+    #[test]
+    fn test_rust_types_valid_in_rust_project() {
+        let rust = dialect_project(
+            "rust",
+            "Result<HashMap<String, Vec<user.User>>>",
+            DEPENDS_ON_USER,
+        );
+        assert!(AllTypesMustExist.validate(&rust).is_empty());
+
+        let typescript = dialect_project("typescript", "Vec<u64>", "{}");
+        assert_eq!(AllTypesMustExist.validate(&typescript).len(), 1);
+    }
+
+    #[test]
+    fn test_python_types_valid_in_python_project() {
+        let project = dialect_project("python", "Optional[list[user.User]]", DEPENDS_ON_USER);
+
+        assert!(AllTypesMustExist.validate(&project).is_empty());
+        assert!(DeclaredDependenciesMustBeUsed.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_python_qualified_type_requires_dependency() {
+        let project = dialect_project("python", "dict[str, user.User]", "{}");
+
+        let issues = UsedDependenciesDeclared.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'user'"));
+    }
+
+    /// A `language` project whose `api.Handler.handle` takes a `request` of
+    /// `param_type`, with `external_types` as the manifest's external types
+    fn external_types_project(
+        language: &str,
+        param_type: &str,
+        external_types: &str,
+    ) -> TestProject {
+        TestProject::new(language)
+            .manifest_field("external_types", external_types)
+            .module(format!(
+                r#"{{
+                "module": "api",
+                "version": "1.0.0",
+                "exports": {{
+                    "Handler": {{
+                        "type": "class",
+                        "methods": {{
+                            "handle": {{"inputs": [{{"name": "request", "type": "{param_type}"}}], "returns": {{"type": "void"}}}}
+                        }}
+                    }}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+    }
+
+    #[test]
+    fn test_undeclared_external_type_reported() {
+        let result = external_types_project("typescript", "express.Request", "[]").validate();
+
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert_eq!(result.errors[0].rule, "all-types-must-exist");
+    }
+
+    #[test]
+    fn test_declared_external_type_accepted() {
+        let result = external_types_project(
+            "typescript",
+            "express.Request",
+            r#"[{"name": "express.Request", "package": "express"}]"#,
+        )
+        .validate();
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_generic_external_type_accepted() {
+        let project = external_types_project(
+            "rust",
+            "Option<chrono::DateTime<Utc>>",
+            r#"[{"name": "chrono::DateTime", "package": "chrono"}, {"name": "Utc", "package": "chrono"}]"#,
+        )
+        .build();
+
+        assert!(AllTypesMustExist.validate(&project).is_empty());
+    }
+
+    /// A project where `api.UserApi.get` takes a `repo` parameter of
+    /// `param_type`, next to a `domain` module with generic exports
+    fn generics_project(param_type: &str) -> TestProject {
+        TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "domain",
+                "version": "1.0.0",
+                "exports": {
+                    "Entity": {"type": "trait", "methods": {}},
+                    "User": {"type": "class", "implements": ["Entity"]},
+                    "Tag": {"type": "interface", "properties": {"label": {"type": "string"}}},
+                    "Page": {
+                        "type": "interface",
+                        "type_params": [{"name": "TItem"}],
+                        "properties": {"items": {"type": "TItem[]"}, "total": {"type": "number"}}
+                    },
+                    "Repository": {
+                        "type": "trait",
+                        "type_params": [{"name": "T", "bound": "domain.Entity"}],
+                        "methods": {
+                            "find": {"inputs": [{"name": "id", "type": "string"}], "returns": {"type": "T | null"}},
+                            "list": {"inputs": [], "returns": {"type": "Page<T>"}}
+                        }
+                    }
+                },
+                "dependencies": {}
+            }"#,
+            )
+            .module(format!(
+                r#"{{
+                "module": "api",
+                "version": "1.0.0",
+                "exports": {{
+                    "UserApi": {{
+                        "type": "class",
+                        "methods": {{
+                            "get": {{"inputs": [{{"name": "repo", "type": "{param_type}"}}], "returns": {{"type": "void"}}}}
+                        }}
+                    }}
+                }},
+                "dependencies": {{"domain": "^1.0.0"}}
+            }}"#
+            ))
+    }
+
+    fn type_errors(param_type: &str) -> Vec<String> {
+        AllTypesMustExist
+            .validate(&generics_project(param_type).build())
+            .into_iter()
+            .map(|issue| issue.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_generic_exports_valid() {
+        for param_type in ["domain.Repository<User>", "Promise<domain.Page<Tag>>"] {
+            let result = generics_project(param_type).validate();
+
+            assert!(
+                result.errors.is_empty(),
+                "{param_type}: {:?}",
+                result.errors
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrong_arity_reported() {
+        for param_type in ["Page", "Page<User, Tag>"] {
+            let errors = type_errors(param_type);
+
+            assert_eq!(errors.len(), 1, "{param_type}: {errors:?}");
+            assert!(errors[0].contains("'Page<TItem>' expects 1 type argument(s)"));
+        }
+    }
+
+    #[test]
+    fn test_type_arguments_on_non_generic_reported() {
+        let errors = type_errors("User<Tag>");
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("'User' is not generic"));
+    }
+
+    #[test]
+    fn test_unsatisfied_bound_reported() {
+        let errors = type_errors("Repository<Tag>");
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("'Tag' doesn't satisfy the bound 'domain.Entity'"));
+    }
+
+    #[test]
+    fn test_type_parameter_out_of_scope_reported() {
+        let errors = type_errors("T");
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("Type 'T' not found"));
+    }
+
+    #[test]
+    fn test_trait_payload_reported_by_trait_structure_only() {
+        let project = TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "traits",
+                "version": "1.0.0",
+                "exports": {
+                    "BadTrait": {
+                        "type": "trait",
+                        "methods": {"doSomething": {"inputs": [], "returns": {"type": "void"}}},
+                        "payload": {"data": {"type": "string", "required": true}}
+                    },
+                    "BadClass": {"type": "class", "payload": {"data": {"type": "string"}}}
+                },
+                "dependencies": {}
+            }"#,
+            )
+            .build();
+
+        let trait_issues = TraitStructure.validate(&project);
+        assert_eq!(
+            messages(&trait_issues),
+            vec!["Trait 'BadTrait' has payload defined. Payload is only valid for events."]
+        );
+        assert_eq!(trait_issues[0].severity, Severity::Error);
+
+        let export_issues = ExportStructure.validate(&project);
+        assert_eq!(export_issues.len(), 1);
+        assert_eq!(
+            export_issues[0].location.as_deref(),
+            Some("traits.BadClass")
+        );
+    }
+}
@@ -0,0 +1,374 @@
+//! Rules reconciling declared effects with the `calls` graph
+//!
+//! See [`crate::effects`] for how transitive effects are inferred.
+
+use super::ValidationRule;
+use crate::effects::{effect_category, effect_matches, EffectAnalysis, MethodEffects};
+use crate::types::{Module, Project, Severity};
+use crate::validator::ValidationIssue;
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#effect-system";
+
+/// Every method in the project with its module, in a stable order
+fn methods_with_effects<'a>(
+    project: &'a Project,
+    analysis: &'a EffectAnalysis,
+) -> Vec<(&'a Module, String, &'a MethodEffects)> {
+    let mut methods = Vec::new();
+    for module in &project.modules {
+        for (export_name, export) in &module.exports {
+            let Some(export_methods) = &export.methods else {
+                continue;
+            };
+            for method_name in export_methods.keys() {
+                let key = format!("{}.{}.{}", module.module, export_name, method_name);
+                if let Some(effects) = analysis.get(&key) {
+                    methods.push((module, key, effects));
+                }
+            }
+        }
+    }
+    methods.sort_by(|a, b| a.1.cmp(&b.1));
+    methods
+}
+
+/// Effects performed through calls must be declared
+pub struct EffectsMustBeDeclared;
+
+impl ValidationRule for EffectsMustBeDeclared {
+    fn id(&self) -> &str {
+        "effects-must-be-declared"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Methods must declare the effects of the methods they call"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let analysis = EffectAnalysis::new(project);
+        let mut issues = Vec::new();
+
+        for (_, method, effects) in methods_with_effects(project, &analysis) {
+            for (effect, via) in &effects.inferred {
+                if effects.declares(effect) {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Method '{method}' performs '{effect}' through '{via}' but doesn't \
+                             declare it"
+                        ),
+                        Some(method.clone()),
+                    )
+                    .with_suggestion(format!("Add '{effect}' to the effects of '{method}'."))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// Declared effects that a method inherits from its callees must be produced
+/// by one of them
+pub struct EffectsMustBeProduced;
+
+impl ValidationRule for EffectsMustBeProduced {
+    fn id(&self) -> &str {
+        "effects-must-be-produced"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Effects declared by a calling method must be produced by its callees"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let analysis = EffectAnalysis::new(project);
+        let mut issues = Vec::new();
+
+        for (_, method, effects) in methods_with_effects(project, &analysis) {
+            // Methods without calls produce their effects themselves, and
+            // unresolved calls may produce anything
+            if !effects.has_calls || !effects.calls_resolved {
+                continue;
+            }
+
+            for declared in &effects.declared {
+                // Effects in categories none of the calls reach are performed
+                // by the method itself
+                let category = effect_category(declared);
+                if !effects
+                    .inferred
+                    .keys()
+                    .any(|effect| effect_category(effect) == category)
+                {
+                    continue;
+                }
+
+                let produced = effects.inferred.keys().any(|effect| {
+                    effect_matches(declared, effect) || effect_matches(effect, declared)
+                });
+                if produced {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Method '{method}' declares '{declared}', but none of its calls \
+                             produce it; they only produce other '{category}' effects"
+                        ),
+                        Some(method.clone()),
+                    )
+                    .with_suggestion(format!(
+                        "Remove '{declared}' from '{method}', or declare it on the method that \
+                         performs it."
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// Methods may only perform the effects their layer allows
+pub struct LayerEffectsAllowed;
+
+impl ValidationRule for LayerEffectsAllowed {
+    fn id(&self) -> &str {
+        "layer-effects-allowed"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Methods may only perform effects allowed in their layer"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let Some(policy) = project
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.effects.as_ref())
+        else {
+            return issues;
+        };
+
+        let analysis = EffectAnalysis::new(project);
+
+        for (module, method, effects) in methods_with_effects(project, &analysis) {
+            let Some(layer) = module.layer.as_deref() else {
+                continue;
+            };
+            let Some(allowed) = policy.layers.get(layer) else {
+                continue;
+            };
+
+            for effect in effects.all() {
+                if allowed
+                    .iter()
+                    .any(|pattern| effect_matches(pattern, effect))
+                {
+                    continue;
+                }
+
+                let origin = match effects.inferred.get(effect) {
+                    Some(via) if !effects.declared.contains(effect) => {
+                        format!(" through '{via}'")
+                    }
+                    _ => String::new(),
+                };
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Method '{method}' in layer '{layer}' performs '{effect}'{origin}, \
+                             which the layer doesn't allow"
+                        ),
+                        Some(method.clone()),
+                        effect.to_string(),
+                        if allowed.is_empty() {
+                            "no effects".to_string()
+                        } else {
+                            allowed.join(", ")
+                        },
+                    )
+                    .with_suggestion(format!(
+                        "Move the '{effect}' work into a layer that allows it, or add it to \
+                         effects.layers.{layer} in rules.json."
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// A layered project where `service.OrderService.place` calls into a
+    /// repository, with the given effects on `place` and on the repository's
+    /// `save`
+    fn project(service_effects: &str, repository_effects: &str, rules: Option<&str>) -> Project {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "service",
+                "version": "1.0.0",
+                "layer": "domain",
+                "exports": {{
+                    "OrderService": {{
+                        "type": "class",
+                        "methods": {{
+                            "place": {{"inputs": [], "returns": {{"type": "void"}}, "calls": ["service.validate", "repository.OrderRepository.save"], "effects": {service_effects}}},
+                            "validate": {{"inputs": [], "returns": {{"type": "boolean"}}, "calls": ["repository.OrderRepository.audit"], "effects": ["audit.log"]}}
+                        }}
+                    }}
+                }},
+                "dependencies": {{"repository": "^1.0.0"}}
+            }}"#
+            ))
+            .module(format!(
+                r#"{{
+                "module": "repository",
+                "version": "1.0.0",
+                "layer": "infrastructure",
+                "exports": {{
+                    "OrderRepository": {{
+                        "type": "class",
+                        "methods": {{
+                            "save": {{"inputs": [], "returns": {{"type": "void"}}, "effects": {repository_effects}}},
+                            "audit": {{"inputs": [], "returns": {{"type": "void"}}, "effects": ["audit.log"]}}
+                        }}
+                    }}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+            .rules(rules)
+            .build()
+    }
+
+    #[test]
+    fn test_declared_effects_valid() {
+        let project = project(
+            r#"["database.write", "audit.log"]"#,
+            r#"["database.write"]"#,
+            None,
+        );
+
+        assert!(EffectsMustBeDeclared.validate(&project).is_empty());
+        assert!(EffectsMustBeProduced.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_transitive_effect_must_be_declared() {
+        let issues = EffectsMustBeDeclared.validate(&project("[]", r#"["database.write"]"#, None));
+
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|issue| {
+            issue.location.as_deref() == Some("service.OrderService.place")
+                && issue.severity == Severity::Error
+        }));
+        assert!(issues[0].message.contains("audit.log"));
+        assert!(issues[1].message.contains("database.write"));
+    }
+
+    #[test]
+    fn test_effects_inferred_through_call_chain() {
+        let issues = EffectsMustBeDeclared.validate(&project("[]", r#"["database.write"]"#, None));
+
+        let issue = issues
+            .iter()
+            .find(|issue| issue.message.contains("audit.log"))
+            .expect("expected undeclared audit.log");
+        assert_eq!(
+            issue.location.as_deref(),
+            Some("service.OrderService.place")
+        );
+        assert!(issue.message.contains("service.OrderService.validate"));
+    }
+
+    #[test]
+    fn test_wildcard_declaration_covers_category() {
+        let project = project(
+            r#"["database.*", "audit.*"]"#,
+            r#"["database.write"]"#,
+            None,
+        );
+
+        assert!(EffectsMustBeDeclared.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_unproduced_effect_reported() {
+        let project = project(
+            r#"["database.write", "database.delete", "audit.log"]"#,
+            r#"["database.write"]"#,
+            None,
+        );
+
+        let issues = EffectsMustBeProduced.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("database.delete"));
+        assert_eq!(issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_effects_performed_directly_not_reported() {
+        let project = project(
+            r#"["database.write", "audit.log", "network.request", "state.write"]"#,
+            r#"["database.write"]"#,
+            None,
+        );
+
+        assert!(EffectsMustBeProduced.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_layer_effect_vocabulary() {
+        let rules = r#"{
+            "rules": [],
+            "effects": {"layers": {"domain": ["audit.*"], "infrastructure": ["database.*", "audit.*"]}}
+        }"#;
+        let project = project(
+            r#"["database.write", "audit.log"]"#,
+            r#"["database.write"]"#,
+            Some(rules),
+        );
+
+        let issues = LayerEffectsAllowed.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("service.OrderService.place")
+        );
+        assert_eq!(issues[0].found.as_deref(), Some("database.write"));
+    }
+}
@@ -0,0 +1,337 @@
+//! Publish/subscribe validation of `emits` and `subscribes`
+//!
+//! Event references are `module.Event`; an unqualified name refers to an event
+//! in the method's own module, or failing that to the first module exporting
+//! it. Referenced events must be reachable through declared dependencies,
+//! which `used-dependencies-declared` checks.
+
+use super::{resolve_export, ValidationRule};
+use crate::types::{ExportType, Module, Project, Severity};
+use crate::validator::ValidationIssue;
+use std::collections::{BTreeMap, BTreeSet};
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#events";
+
+/// Every `emits`/`subscribes` entry with the method it's listed on, in a
+/// stable order
+fn event_references(project: &Project) -> Vec<(&Module, String, &'static str, &String)> {
+    let mut references = Vec::new();
+    for module in &project.modules {
+        for (export_name, export) in &module.exports {
+            let Some(methods) = &export.methods else {
+                continue;
+            };
+            for (method_name, method) in methods {
+                let key = format!("{}.{}.{}", module.module, export_name, method_name);
+                let listed = method
+                    .emits
+                    .iter()
+                    .map(|event| ("emits", event))
+                    .chain(method.subscribes.iter().map(|event| ("subscribes", event)));
+                for (list, event) in listed {
+                    references.push((module, key.clone(), list, event));
+                }
+            }
+        }
+    }
+    references.sort_by(|a, b| (&a.1, a.2, a.3).cmp(&(&b.1, b.2, b.3)));
+    references
+}
+
+/// Emitted and subscribed events must be event exports
+pub struct EventsMustExist;
+
+impl ValidationRule for EventsMustExist {
+    fn id(&self) -> &str {
+        "events-must-exist"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Events in emits and subscribes must be exported events"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for (module, method, list, event) in event_references(project) {
+            let message = match resolve_export(project, module, event) {
+                None => format!("Event '{event}' in {list} of '{method}' doesn't exist"),
+                Some((owner, name)) => {
+                    let export_type = &owner.exports[&name].export_type;
+                    if *export_type == ExportType::Event {
+                        continue;
+                    }
+                    let export_type = format!("{export_type:?}").to_lowercase();
+                    format!(
+                        "'{event}' in {list} of '{method}' is not an event (type: {export_type})"
+                    )
+                }
+            };
+
+            issues.push(
+                ValidationIssue::new(
+                    self.id().to_string(),
+                    self.default_severity(),
+                    message,
+                    Some(method),
+                )
+                .with_suggestion(format!(
+                    "Export '{event}' with \"type\": \"event\" and a payload, or fix the name."
+                ))
+                .with_doc_link(DOC_LINK.to_string()),
+            );
+        }
+
+        issues
+    }
+}
+
+/// Publishers and subscribers of every referenced event, keyed by `module.Event`
+fn event_flows(project: &Project) -> BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> {
+    let mut flows: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+    for (module, method, list, event) in event_references(project) {
+        let Some((owner, name)) = resolve_export(project, module, event) else {
+            continue;
+        };
+        if owner.exports[&name].export_type != ExportType::Event {
+            continue;
+        }
+        let flow = flows
+            .entry(format!("{}.{}", owner.module, name))
+            .or_default();
+        match list {
+            "emits" => flow.0.insert(method),
+            _ => flow.1.insert(method),
+        };
+    }
+    flows
+}
+
+/// Emitted events must have a subscriber
+pub struct EmittedEventsMustBeHandled;
+
+impl ValidationRule for EmittedEventsMustBeHandled {
+    fn id(&self) -> &str {
+        "emitted-events-must-be-handled"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Emitted events should have at least one subscriber"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for (event, (emitters, subscribers)) in event_flows(project) {
+            if emitters.is_empty() || !subscribers.is_empty() {
+                continue;
+            }
+
+            let emitters: Vec<_> = emitters.into_iter().collect();
+            issues.push(
+                ValidationIssue::new(
+                    self.id().to_string(),
+                    self.default_severity(),
+                    format!(
+                        "Event '{event}' is emitted by {} but nothing subscribes to it",
+                        emitters.join(", ")
+                    ),
+                    Some(event.clone()),
+                )
+                .with_suggestion(format!(
+                    "Add '{event}' to the subscribes of the method that reacts to it, or \
+                     stop emitting it."
+                ))
+                .with_doc_link(DOC_LINK.to_string()),
+            );
+        }
+
+        issues
+    }
+}
+
+/// Subscribed events must have a publisher
+pub struct SubscribedEventsMustBeEmitted;
+
+impl ValidationRule for SubscribedEventsMustBeEmitted {
+    fn id(&self) -> &str {
+        "subscribed-events-must-be-emitted"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Subscribed events should be emitted by at least one method"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for (event, (emitters, subscribers)) in event_flows(project) {
+            if subscribers.is_empty() || !emitters.is_empty() {
+                continue;
+            }
+
+            let subscribers: Vec<_> = subscribers.into_iter().collect();
+            issues.push(
+                ValidationIssue::new(
+                    self.id().to_string(),
+                    self.default_severity(),
+                    format!(
+                        "Event '{event}' is subscribed to by {} but nothing emits it",
+                        subscribers.join(", ")
+                    ),
+                    Some(event.clone()),
+                )
+                .with_suggestion(format!(
+                    "Add '{event}' to the emits of the method that publishes it, or remove \
+                     the subscription."
+                ))
+                .with_doc_link(DOC_LINK.to_string()),
+            );
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::builtin::{DeclaredDependenciesMustBeUsed, UsedDependenciesDeclared};
+    use crate::testing::TestProject;
+
+    const DEPENDS_ON_ORDERS: &str = r#"{"orders": "^1.0.0"}"#;
+
+    /// A project where `orders.Checkout.run` publishes the `emits` events and
+    /// `notifications.Mailer.send` consumes the `subscribes` events
+    fn project(emits: &str, subscribes: &str, dependencies: &str) -> TestProject {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "orders",
+                "version": "1.0.0",
+                "exports": {{
+                    "Checkout": {{
+                        "type": "class",
+                        "methods": {{
+                            "run": {{"inputs": [], "returns": {{"type": "void"}}, "emits": {emits}}}
+                        }}
+                    }},
+                    "OrderPlaced": {{"type": "event", "payload": {{"orderId": {{"type": "string"}}}}}},
+                    "OrderCancelled": {{"type": "event", "payload": {{"orderId": {{"type": "string"}}}}}},
+                    "Order": {{"type": "interface", "properties": {{"id": {{"type": "string"}}}}}}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+            .module(format!(
+                r#"{{
+                "module": "notifications",
+                "version": "1.0.0",
+                "exports": {{
+                    "Mailer": {{
+                        "type": "class",
+                        "methods": {{
+                            "send": {{"inputs": [], "returns": {{"type": "void"}}, "subscribes": {subscribes}}}
+                        }}
+                    }}
+                }},
+                "dependencies": {dependencies}
+            }}"#
+            ))
+    }
+
+    #[test]
+    fn test_published_and_consumed_event_valid() {
+        let result = project(
+            r#"["OrderPlaced"]"#,
+            r#"["orders.OrderPlaced"]"#,
+            DEPENDS_ON_ORDERS,
+        )
+        .validate();
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_missing_and_non_event_references_reported() {
+        let project = project(
+            r#"["OrderPlaced", "OrderShipped"]"#,
+            r#"["orders.OrderPlaced", "orders.Order"]"#,
+            DEPENDS_ON_ORDERS,
+        )
+        .build();
+
+        let issues = EventsMustExist.validate(&project);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0]
+            .message
+            .contains("is not an event (type: interface)"));
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("notifications.Mailer.send")
+        );
+        assert!(issues[1].message.contains("'OrderShipped'"));
+        assert_eq!(issues[1].location.as_deref(), Some("orders.Checkout.run"));
+    }
+
+    #[test]
+    fn test_subscribing_requires_dependency() {
+        let project = project(r#"["OrderPlaced"]"#, r#"["orders.OrderPlaced"]"#, "{}").build();
+
+        let issues = UsedDependenciesDeclared.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'orders'"));
+        assert_eq!(issues[0].location.as_deref(), Some("notifications"));
+    }
+
+    #[test]
+    fn test_dependency_used_only_for_events_is_used() {
+        let project = project(
+            r#"["OrderPlaced"]"#,
+            r#"["orders.OrderPlaced"]"#,
+            DEPENDS_ON_ORDERS,
+        )
+        .build();
+
+        assert!(DeclaredDependenciesMustBeUsed.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_emitted_but_unhandled_event_reported() {
+        let project = project(
+            r#"["OrderPlaced", "OrderCancelled"]"#,
+            r#"["orders.OrderPlaced"]"#,
+            DEPENDS_ON_ORDERS,
+        )
+        .build();
+
+        let issues = EmittedEventsMustBeHandled.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("orders.OrderCancelled"));
+        assert!(issues[0].message.contains("orders.Checkout.run"));
+        assert!(SubscribedEventsMustBeEmitted.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_handled_but_never_emitted_event_reported() {
+        let project = project("[]", r#"["orders.OrderPlaced"]"#, DEPENDS_ON_ORDERS).build();
+
+        let issues = SubscribedEventsMustBeEmitted.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("orders.OrderPlaced"));
+        assert!(issues[0].message.contains("notifications.Mailer.send"));
+    }
+}
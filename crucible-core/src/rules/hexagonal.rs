@@ -0,0 +1,361 @@
+//! Rules for the hexagonal (ports & adapters) architecture pattern
+//!
+//! These rules only run when the manifest or rules.json selects the
+//! `hexagonal` pattern. A module's role comes from its `role` field, falling
+//! back to its layer name when the layer is called after a role (`domain`,
+//! `core`, `ports`, `adapters`, `application`).
+
+use super::{resolve_implemented, ValidationRule};
+use crate::types::{ArchitecturePattern, ExportType, Module, ModuleRole, Project, Severity};
+use crate::validator::ValidationIssue;
+
+const DOC_LINK: &str =
+    "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#hexagonal-architecture-ports--adapters";
+
+/// Check whether the project uses the hexagonal pattern
+fn is_hexagonal(project: &Project) -> bool {
+    project.manifest.project.architecture_pattern == Some(ArchitecturePattern::Hexagonal)
+        || project
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.architecture.as_ref())
+            .is_some_and(|architecture| architecture.pattern == ArchitecturePattern::Hexagonal)
+}
+
+/// Effective role of a module: the explicit role, or one implied by its layer name
+pub fn module_role(module: &Module) -> Option<ModuleRole> {
+    module.role.or_else(|| match module.layer.as_deref()? {
+        "domain" | "core" => Some(ModuleRole::Domain),
+        "port" | "ports" => Some(ModuleRole::Port),
+        "adapter" | "adapters" => Some(ModuleRole::Adapter),
+        "application" => Some(ModuleRole::Application),
+        _ => None,
+    })
+}
+
+/// Ports may only export traits and interfaces
+pub struct PortsAreAbstract;
+
+impl ValidationRule for PortsAreAbstract {
+    fn id(&self) -> &str {
+        "ports-must-be-abstract"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Port modules may only export traits and interfaces"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if !is_hexagonal(project) {
+            return issues;
+        }
+
+        for module in &project.modules {
+            if module_role(module) != Some(ModuleRole::Port) {
+                continue;
+            }
+
+            let mut exports: Vec<_> = module.exports.iter().collect();
+            exports.sort_by_key(|(name, _)| *name);
+
+            for (export_name, export) in exports {
+                if matches!(
+                    export.export_type,
+                    ExportType::Trait | ExportType::Interface
+                ) {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Port '{}' exports '{export_name}', which is not a trait or interface",
+                            module.module
+                        ),
+                        Some(format!("{}.{}", module.module, export_name)),
+                        format!("{:?}", export.export_type).to_lowercase(),
+                        "trait or interface".to_string(),
+                    )
+                    .with_suggestion(format!(
+                        "Declare '{export_name}' as a trait or interface, or move the \
+                         implementation into an adapter module."
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// The domain and its ports must not depend on adapters
+pub struct NoDependencyOnAdapters;
+
+impl ValidationRule for NoDependencyOnAdapters {
+    fn id(&self) -> &str {
+        "no-dependency-on-adapters"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Domain and port modules must not depend on adapters"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if !is_hexagonal(project) {
+            return issues;
+        }
+
+        for module in &project.modules {
+            let role = match module_role(module) {
+                Some(ModuleRole::Domain) => "Domain",
+                Some(ModuleRole::Port) => "Port",
+                _ => continue,
+            };
+
+            let mut dependencies: Vec<_> = module.dependencies.keys().collect();
+            dependencies.sort();
+
+            for dep_name in dependencies {
+                let Some(target) = project.modules.iter().find(|m| &m.module == dep_name) else {
+                    continue;
+                };
+                if module_role(target) != Some(ModuleRole::Adapter) {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "{role} module '{}' depends on adapter '{dep_name}'",
+                            module.module
+                        ),
+                        Some(format!("{} -> {}", module.module, dep_name)),
+                    )
+                    .with_suggestion(format!(
+                        "Define a port for what '{}' needs from '{dep_name}' and have \
+                         '{dep_name}' implement it.",
+                        module.module
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// Every port needs at least one adapter depending on it
+pub struct PortsHaveAdapters;
+
+impl ValidationRule for PortsHaveAdapters {
+    fn id(&self) -> &str {
+        "ports-must-have-adapters"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Every port must be implemented by at least one adapter"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if !is_hexagonal(project) {
+            return issues;
+        }
+
+        for port in &project.modules {
+            if module_role(port) != Some(ModuleRole::Port) {
+                continue;
+            }
+
+            // Depending on a port isn't enough: an adapter has to implement one
+            // of its traits or interfaces
+            let has_adapter = project
+                .modules
+                .iter()
+                .filter(|module| module_role(module) == Some(ModuleRole::Adapter))
+                .flat_map(|module| {
+                    module
+                        .exports
+                        .values()
+                        .flat_map(|export| &export.implements)
+                        .filter_map(move |implemented| {
+                            resolve_implemented(project, module, implemented)
+                        })
+                })
+                .any(|(owner, name, _)| {
+                    owner.module == port.module
+                        && matches!(
+                            owner.exports[&name].export_type,
+                            ExportType::Trait | ExportType::Interface
+                        )
+                });
+
+            if !has_adapter {
+                issues.push(
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!("Port '{}' has no adapter implementing it", port.module),
+                        Some(port.module.clone()),
+                    )
+                    .with_suggestion(format!(
+                        "Add an adapter module with a class that lists one of the traits or \
+                         interfaces of '{}' in \"implements\".",
+                        port.module
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    const DOMAIN: &str = r#"{
+        "module": "orders",
+        "version": "1.0.0",
+        "role": "domain",
+        "exports": {
+            "Order": {"type": "interface", "properties": {"id": {"type": "string"}}}
+        },
+        "dependencies": {}
+    }"#;
+
+    const PORT: &str = r#"{
+        "module": "order-store",
+        "version": "1.0.0",
+        "role": "port",
+        "exports": {
+            "OrderStore": {
+                "type": "trait",
+                "methods": {"save": {"inputs": [{"name": "order", "type": "orders.Order"}], "returns": {"type": "void"}}}
+            }
+        },
+        "dependencies": {"orders": "^1.0.0"}
+    }"#;
+
+    const ADAPTER: &str = r#"{
+        "module": "postgres",
+        "version": "1.0.0",
+        "role": "adapter",
+        "exports": {
+            "PostgresOrderStore": {
+                "type": "class",
+                "implements": ["order-store.OrderStore"],
+                "methods": {"save": {"inputs": [{"name": "order", "type": "orders.Order"}], "returns": {"type": "void"}}}
+            }
+        },
+        "dependencies": {"orders": "^1.0.0", "order-store": "^1.0.0"}
+    }"#;
+
+    /// A rust project following `pattern`
+    fn project(pattern: &str, modules: &[&str]) -> Project {
+        modules
+            .iter()
+            .fold(
+                TestProject::new("rust").pattern(pattern),
+                |project, module| project.module(*module),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_valid_hexagonal_project() {
+        let project = project("hexagonal", &[DOMAIN, PORT, ADAPTER]);
+
+        assert!(PortsAreAbstract.validate(&project).is_empty());
+        assert!(NoDependencyOnAdapters.validate(&project).is_empty());
+        assert!(PortsHaveAdapters.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_port_with_concrete_export() {
+        let port = PORT.replace(r#""type": "trait""#, r#""type": "class""#);
+        let project = project("hexagonal", &[DOMAIN, &port, ADAPTER]);
+
+        let issues = PortsAreAbstract.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("order-store.OrderStore")
+        );
+        assert_eq!(issues[0].found.as_deref(), Some("class"));
+    }
+
+    #[test]
+    fn test_domain_depending_on_adapter() {
+        let domain = DOMAIN.replace(
+            r#""dependencies": {}"#,
+            r#""dependencies": {"postgres": "^1.0.0"}"#,
+        );
+        let project = project("hexagonal", &[&domain, PORT, ADAPTER]);
+
+        let issues = NoDependencyOnAdapters.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("orders -> postgres"));
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_port_without_adapter() {
+        let issues = PortsHaveAdapters.validate(&project("hexagonal", &[DOMAIN, PORT]));
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("order-store"));
+    }
+
+    #[test]
+    fn test_adapter_depending_on_port_without_implementing_it() {
+        let adapter = ADAPTER.replace(r#""implements": ["order-store.OrderStore"],"#, "");
+        let issues = PortsHaveAdapters.validate(&project("hexagonal", &[DOMAIN, PORT, &adapter]));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("order-store"));
+    }
+
+    #[test]
+    fn test_roles_inferred_from_layer_names() {
+        let port = PORT
+            .replace(r#""role": "port""#, r#""layer": "ports""#)
+            .replace(r#""type": "trait""#, r#""type": "class""#);
+        let project = project("hexagonal", &[DOMAIN, &port]);
+
+        assert_eq!(PortsAreAbstract.validate(&project).len(), 1);
+        assert_eq!(PortsHaveAdapters.validate(&project).len(), 1);
+    }
+
+    #[test]
+    fn test_rules_inactive_for_other_patterns() {
+        let port = PORT.replace(r#""type": "trait""#, r#""type": "class""#);
+        let project = project("layered", &[DOMAIN, &port]);
+
+        assert!(PortsAreAbstract.validate(&project).is_empty());
+        assert!(PortsHaveAdapters.validate(&project).is_empty());
+    }
+}
@@ -0,0 +1,720 @@
+//! Validation of `implements` between classes and traits/interfaces
+//!
+//! A class implementing a trait or interface must provide each of its methods
+//! with compatible inputs, return type and `async` flag, and each of its
+//! properties with the same type. Type names are resolved to the module
+//! exporting them, relative to the module declaring each side, so `Todo` in
+//! `todos` matches `todos.Todo` elsewhere but `a.Id` doesn't match `b.Id`.
+//! Aliases are expanded, so `TodoId` matches `string` when `type TodoId = string`.
+
+use super::{resolve_implemented, ValidationRule};
+use crate::type_system::{
+    substitute, AliasResolver, TypeDialect, TypeParser, TypeReference, TypeValidator,
+};
+use crate::types::{Export, ExportType, Method, Module, Project, Property, Severity};
+use crate::validator::ValidationIssue;
+use std::collections::HashMap;
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#implements";
+
+/// Compares the member types of a class with those of something it
+/// implements, each resolved relative to the module declaring it, with the
+/// type arguments of the `implements` entry substituted on the trait side
+struct TypeComparison<'a> {
+    parser: &'a TypeParser,
+    aliases: &'a AliasResolver<'a>,
+    class_module: &'a Module,
+    trait_module: &'a Module,
+    type_args: HashMap<&'a str, &'a TypeReference>,
+}
+
+impl TypeComparison<'_> {
+    /// Whether a class member type is the same as the implemented one; types
+    /// that don't parse are compared as written
+    fn same_type(&self, found: &str, expected: &str) -> bool {
+        match (self.parser.parse(found), self.parser.parse(expected)) {
+            (Ok(found), Ok(expected)) => {
+                self.aliases.resolve_in(self.class_module, &found)
+                    == self
+                        .aliases
+                        .resolve_in(self.trait_module, &substitute(&expected, &self.type_args))
+            }
+            _ => found.trim() == expected.trim(),
+        }
+    }
+}
+
+/// Human-readable signature, e.g. `async (id: string, limit?: number) -> Todo`
+fn signature(method: &Method) -> String {
+    let inputs: Vec<String> = method
+        .inputs
+        .iter()
+        .map(|param| {
+            let optional = if param.optional { "?" } else { "" };
+            format!("{}{optional}: {}", param.name, param.param_type)
+        })
+        .collect();
+    let returns = match &method.returns.inner {
+        Some(inner) => format!("{}<{inner}>", method.returns.return_type),
+        None => method.returns.return_type.clone(),
+    };
+    let prefix = if method.is_async { "async " } else { "" };
+    format!("{prefix}({}) -> {returns}", inputs.join(", "))
+}
+
+/// Why a class method can't stand in for a trait method, if it can't
+fn method_mismatches(
+    class_method: &Method,
+    trait_method: &Method,
+    types: &TypeComparison,
+) -> Vec<String> {
+    let mut mismatches = Vec::new();
+
+    for (index, expected) in trait_method.inputs.iter().enumerate() {
+        let Some(found) = class_method.inputs.get(index) else {
+            mismatches.push(format!("missing parameter '{}'", expected.name));
+            continue;
+        };
+        if !types.same_type(&found.param_type, &expected.param_type) {
+            mismatches.push(format!(
+                "parameter '{}' is '{}', expected '{}'",
+                found.name, found.param_type, expected.param_type
+            ));
+        }
+        if expected.optional && !found.optional {
+            mismatches.push(format!("parameter '{}' must be optional", found.name));
+        }
+    }
+    for extra in class_method.inputs.iter().skip(trait_method.inputs.len()) {
+        if !extra.optional {
+            mismatches.push(format!("extra parameter '{}' must be optional", extra.name));
+        }
+    }
+
+    let returns_match = types.same_type(
+        &class_method.returns.return_type,
+        &trait_method.returns.return_type,
+    ) && match (&class_method.returns.inner, &trait_method.returns.inner) {
+        (Some(found), Some(expected)) => types.same_type(found, expected),
+        (found, expected) => found.is_none() && expected.is_none(),
+    };
+    if !returns_match {
+        mismatches.push("return type differs".to_string());
+    }
+
+    if class_method.is_async != trait_method.is_async {
+        mismatches.push(if trait_method.is_async {
+            "must be async".to_string()
+        } else {
+            "must not be async".to_string()
+        });
+    }
+
+    mismatches
+}
+
+/// A class and a trait or interface it implements
+struct Implementation<'a> {
+    class_key: String,
+    class_module: &'a Module,
+    class: &'a Export,
+    trait_key: String,
+    trait_module: &'a Module,
+    target: &'a Export,
+    /// Type arguments of the `implements` entry, by the trait's parameter
+    /// names, resolved relative to the class's module
+    type_args: Vec<(&'a str, TypeReference)>,
+}
+
+/// Every class with its resolved `implements` targets, in a stable order
+///
+/// Targets that don't resolve, or pass the wrong number of type arguments,
+/// are left out; `implements-must-reference-trait` reports them.
+fn implementations<'a>(project: &'a Project, aliases: &AliasResolver) -> Vec<Implementation<'a>> {
+    let mut implementations = Vec::new();
+    for module in &project.modules {
+        for (export_name, export) in &module.exports {
+            for implemented in &export.implements {
+                let Some((trait_module, trait_name, args)) =
+                    resolve_implemented(project, module, implemented)
+                else {
+                    continue;
+                };
+                let target = &trait_module.exports[&trait_name];
+                if !matches!(
+                    target.export_type,
+                    ExportType::Trait | ExportType::Interface
+                ) || args.len() != target.type_params.len()
+                {
+                    continue;
+                }
+
+                implementations.push(Implementation {
+                    class_key: format!("{}.{}", module.module, export_name),
+                    class_module: module,
+                    class: export,
+                    trait_key: format!("{}.{}", trait_module.module, trait_name),
+                    trait_module,
+                    target,
+                    type_args: target
+                        .type_params
+                        .iter()
+                        .map(|param| param.name.as_str())
+                        .zip(args.iter().map(|arg| aliases.resolve_in(module, arg)))
+                        .collect(),
+                });
+            }
+        }
+    }
+    implementations.sort_by(|a, b| (&a.class_key, &a.trait_key).cmp(&(&b.class_key, &b.trait_key)));
+    implementations
+}
+
+/// `implements` must name traits or interfaces and be declared on classes
+pub struct ImplementsMustReferenceTrait;
+
+impl ValidationRule for ImplementsMustReferenceTrait {
+    fn id(&self) -> &str {
+        "implements-must-reference-trait"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Classes may only implement existing traits and interfaces"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let types = TypeValidator::for_project(project);
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            let mut exports: Vec<_> = module
+                .exports
+                .iter()
+                .filter(|(_, export)| !export.implements.is_empty())
+                .collect();
+            exports.sort_by_key(|(name, _)| *name);
+
+            for (export_name, export) in exports {
+                let location = format!("{}.{}", module.module, export_name);
+
+                if export.export_type != ExportType::Class {
+                    let export_type = format!("{:?}", export.export_type).to_lowercase();
+                    issues.push(self.issue(
+                        format!(
+                            "'{location}' can't implement traits because it isn't a class \
+                             (type: {export_type})"
+                        ),
+                        &location,
+                        "Move \"implements\" to a class export.".to_string(),
+                    ));
+                    continue;
+                }
+
+                for implemented in &export.implements {
+                    let Some((trait_module, trait_name, _)) =
+                        resolve_implemented(project, module, implemented)
+                    else {
+                        issues.push(self.issue(
+                            format!("'{location}' implements '{implemented}', which doesn't exist"),
+                            &location,
+                            format!(
+                                "Reference a trait or interface as 'module.Trait', or remove \
+                                 '{implemented}' from implements."
+                            ),
+                        ));
+                        continue;
+                    };
+
+                    let export_type = &trait_module.exports[&trait_name].export_type;
+                    if !matches!(export_type, ExportType::Trait | ExportType::Interface) {
+                        let export_type = format!("{export_type:?}").to_lowercase();
+                        issues.push(self.issue(
+                            format!(
+                                "'{location}' implements '{implemented}', which is not a trait \
+                                 or interface (type: {export_type})"
+                            ),
+                            &location,
+                            format!(
+                                "Reference a trait or interface as 'module.Trait', or remove \
+                                 '{implemented}' from implements."
+                            ),
+                        ));
+                        continue;
+                    }
+
+                    // Type arguments must fit the trait's type parameters
+                    if let Err(error) = types.validate_type_string_in_scope(
+                        implemented,
+                        &export.type_params,
+                        &project.modules,
+                    ) {
+                        issues.push(self.issue(
+                            format!("'{location}' implements '{implemented}': {error}"),
+                            &location,
+                            format!(
+                                "Pass '{}.{trait_name}' one type argument per type parameter.",
+                                trait_module.module
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+impl ImplementsMustReferenceTrait {
+    fn issue(&self, message: String, location: &str, suggestion: String) -> ValidationIssue {
+        ValidationIssue::new(
+            self.id().to_string(),
+            self.default_severity(),
+            message,
+            Some(location.to_string()),
+        )
+        .with_suggestion(suggestion)
+        .with_doc_link(DOC_LINK.to_string())
+    }
+}
+
+/// Classes must provide every member of what they implement
+pub struct ImplementationsMustMatch;
+
+impl ValidationRule for ImplementationsMustMatch {
+    fn id(&self) -> &str {
+        "implementations-must-match"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Classes must provide compatible methods and properties for what they implement"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let parser = TypeParser::for_dialect(TypeDialect::for_project(project));
+        let aliases = AliasResolver::for_project(project);
+
+        for implementation in implementations(project, &aliases) {
+            let Implementation {
+                class_key,
+                class,
+                trait_key,
+                target,
+                ..
+            } = &implementation;
+            let types = TypeComparison {
+                parser: &parser,
+                aliases: &aliases,
+                class_module: implementation.class_module,
+                trait_module: implementation.trait_module,
+                type_args: implementation
+                    .type_args
+                    .iter()
+                    .map(|(param, arg)| (*param, arg))
+                    .collect(),
+            };
+
+            let mut methods: Vec<_> = target.methods.iter().flatten().collect();
+            methods.sort_by_key(|(name, _)| *name);
+
+            for (method_name, trait_method) in methods {
+                let location = format!("{class_key}.{method_name}");
+                let expected = signature(trait_method);
+
+                let Some(class_method) = class
+                    .methods
+                    .as_ref()
+                    .and_then(|methods| methods.get(method_name))
+                else {
+                    issues.push(
+                        ValidationIssue::new(
+                            self.id().to_string(),
+                            self.default_severity(),
+                            format!(
+                                "'{class_key}' implements '{trait_key}' but has no method \
+                                 '{method_name}'"
+                            ),
+                            Some(location),
+                        )
+                        .with_suggestion(format!(
+                            "Add '{method_name}' to '{class_key}' with the signature {expected}."
+                        ))
+                        .with_doc_link(DOC_LINK.to_string()),
+                    );
+                    continue;
+                };
+
+                let mismatches = method_mismatches(class_method, trait_method, &types);
+                if mismatches.is_empty() {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Method '{location}' doesn't match '{trait_key}.{method_name}': {}",
+                            mismatches.join("; ")
+                        ),
+                        Some(location),
+                        signature(class_method),
+                        expected,
+                    )
+                    .with_suggestion(format!(
+                        "Change '{method_name}' to match its declaration in '{trait_key}'."
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+
+            let mut properties: Vec<_> = target.properties.iter().flatten().collect();
+            properties.sort_by_key(|(name, _)| *name);
+
+            for (prop_name, expected) in properties {
+                let found = class
+                    .properties
+                    .as_ref()
+                    .and_then(|properties| properties.get(prop_name));
+                if found.is_some_and(|found| property_matches(found, expected, &types)) {
+                    continue;
+                }
+
+                let location = format!("{class_key}.{prop_name}");
+                let message = match found {
+                    None => format!(
+                        "'{class_key}' implements '{trait_key}' but has no property '{prop_name}'"
+                    ),
+                    Some(_) => {
+                        format!("Property '{location}' doesn't match '{trait_key}.{prop_name}'")
+                    }
+                };
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        message,
+                        Some(location),
+                        found.map_or_else(|| "none".to_string(), |found| found.prop_type.clone()),
+                        expected.prop_type.clone(),
+                    )
+                    .with_suggestion(format!(
+                        "Declare '{prop_name}' on '{class_key}' with type '{}'.",
+                        expected.prop_type
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// A class property can stand in for an interface property of the same type,
+/// as long as it's required whenever the interface's is
+fn property_matches(found: &Property, expected: &Property, types: &TypeComparison) -> bool {
+    types.same_type(&found.prop_type, &expected.prop_type) && (found.required || !expected.required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    const PORTS: &str = r#"{
+        "module": "ports",
+        "version": "1.0.0",
+        "exports": {
+            "TodoRepository": {
+                "type": "trait",
+                "methods": {
+                    "find": {"inputs": [{"name": "id", "type": "string"}], "returns": {"type": "Todo | null"}, "async": true},
+                    "save": {"inputs": [{"name": "todo", "type": "ports.Todo"}], "returns": {"type": "void"}, "async": true}
+                }
+            },
+            "Identified": {
+                "type": "interface",
+                "properties": {"id": {"type": "string", "required": true}}
+            },
+            "Todo": {"type": "interface", "properties": {"id": {"type": "string"}}},
+            "TodoCreated": {"type": "event", "payload": {"id": {"type": "string"}}}
+        },
+        "dependencies": {}
+    }"#;
+
+    const MATCHING_METHODS: &str = r#"{
+        "find": {"inputs": [{"name": "id", "type": "string"}, {"name": "lock", "type": "boolean", "optional": true}], "returns": {"type": "ports.Todo | null"}, "async": true},
+        "save": {"inputs": [{"name": "todo", "type": "Todo"}], "returns": {"type": "void"}, "async": true}
+    }"#;
+
+    /// A project where `postgres.PostgresTodoRepository` implements the
+    /// `implements` list of `ports` traits with the given methods
+    fn project(implements: &str, methods: &str) -> TestProject {
+        TestProject::new("typescript").module(PORTS).module(format!(
+            r#"{{
+            "module": "postgres",
+            "version": "1.0.0",
+            "exports": {{
+                "PostgresTodoRepository": {{
+                    "type": "class",
+                    "implements": {implements},
+                    "methods": {methods}
+                }}
+            }},
+            "dependencies": {{"ports": "^1.0.0"}}
+        }}"#
+        ))
+    }
+
+    #[test]
+    fn test_matching_implementation_valid() {
+        let result = project(r#"["ports.TodoRepository"]"#, MATCHING_METHODS).validate();
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(!result
+            .warnings
+            .iter()
+            .any(|issue| issue.rule == "declared-dependencies-must-be-used"));
+    }
+
+    #[test]
+    fn test_missing_method_reported() {
+        let project = project(
+            r#"["ports.TodoRepository"]"#,
+            r#"{"find": {"inputs": [{"name": "id", "type": "string"}], "returns": {"type": "Todo | null"}, "async": true}}"#,
+        )
+        .build();
+
+        let issues = ImplementationsMustMatch.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("postgres.PostgresTodoRepository.save")
+        );
+        assert!(issues[0].message.contains("has no method 'save'"));
+    }
+
+    #[test]
+    fn test_incompatible_signature_reported() {
+        let project = project(
+            r#"["ports.TodoRepository"]"#,
+            r#"{
+                "find": {"inputs": [{"name": "id", "type": "number"}], "returns": {"type": "Todo"}},
+                "save": {"inputs": [{"name": "todo", "type": "Todo"}, {"name": "force", "type": "boolean"}], "returns": {"type": "void"}, "async": true}
+            }"#,
+        )
+        .build();
+
+        let issues = ImplementationsMustMatch.validate(&project);
+        assert_eq!(issues.len(), 2);
+
+        let find = &issues[0];
+        assert_eq!(
+            find.location.as_deref(),
+            Some("postgres.PostgresTodoRepository.find")
+        );
+        assert!(find.message.contains("parameter 'id' is 'number'"));
+        assert!(find.message.contains("return type differs"));
+        assert!(find.message.contains("must be async"));
+        assert_eq!(find.found.as_deref(), Some("(id: number) -> Todo"));
+        assert_eq!(
+            find.expected.as_deref(),
+            Some("async (id: string) -> Todo | null")
+        );
+
+        assert!(issues[1]
+            .message
+            .contains("extra parameter 'force' must be optional"));
+    }
+
+    #[test]
+    fn test_interface_properties_checked() {
+        let project = project(
+            r#"["ports.TodoRepository", "ports.Identified"]"#,
+            MATCHING_METHODS,
+        )
+        .build();
+
+        let issues = ImplementationsMustMatch.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("postgres.PostgresTodoRepository.id")
+        );
+    }
+
+    #[test]
+    fn test_invalid_implements_targets_reported() {
+        let project = project(
+            r#"["ports.TodoCreated", "ports.Missing", "ports.TodoRepository"]"#,
+            MATCHING_METHODS,
+        )
+        .build();
+
+        let issues = ImplementsMustReferenceTrait.validate(&project);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0]
+            .message
+            .contains("is not a trait or interface (type: event)"));
+        assert!(issues[1]
+            .message
+            .contains("'ports.Missing', which doesn't exist"));
+        assert!(ImplementationsMustMatch.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_implementations_compared_through_aliases() {
+        let project = TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "domain",
+                "version": "1.0.0",
+                "exports": {
+                    "UserId": {"type": "type", "alias": "string"},
+                    "Users": {
+                        "type": "trait",
+                        "methods": {"find": {"inputs": [{"name": "id", "type": "UserId"}], "returns": {"type": "void"}}}
+                    },
+                    "MemoryUsers": {
+                        "type": "class",
+                        "implements": ["Users"],
+                        "methods": {"find": {"inputs": [{"name": "id", "type": "string"}], "returns": {"type": "void"}}}
+                    }
+                },
+                "dependencies": {}
+            }"#,
+            )
+            .build();
+
+        assert!(ImplementationsMustMatch.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_types_compared_by_qualified_name() {
+        let module = |name: &str, class: &str| {
+            format!(
+                r#"{{
+                "module": "{name}",
+                "version": "1.0.0",
+                "exports": {{
+                    "Id": {{"type": "interface", "properties": {{"value": {{"type": "string"}}}}}},
+                    "{class}": {{
+                        "type": "class",
+                        "implements": ["ports.Finder"],
+                        "methods": {{"find": {{"inputs": [{{"name": "id", "type": "Id"}}], "returns": {{"type": "void"}}}}}}
+                    }}
+                }},
+                "dependencies": {{"ports": "^1.0.0"}}
+            }}"#
+            )
+        };
+        let project = TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "ports",
+                "version": "1.0.0",
+                "exports": {
+                    "Finder": {
+                        "type": "trait",
+                        "methods": {"find": {"inputs": [{"name": "id", "type": "a.Id"}], "returns": {"type": "void"}}}
+                    }
+                },
+                "dependencies": {"a": "^1.0.0"}
+            }"#,
+            )
+            .module(module("a", "AFinder"))
+            .module(module("b", "BFinder"))
+            .build();
+
+        let issues = ImplementationsMustMatch.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("b.BFinder.find"));
+        assert!(issues[0]
+            .message
+            .contains("parameter 'id' is 'Id', expected 'a.Id'"));
+    }
+
+    /// A project where `store.UserStore` implements `implements` of the
+    /// generic `ports.Repository<T>` with the given `save` parameter type
+    fn generic_project(implements: &str, saved_type: &str) -> Project {
+        TestProject::new("typescript")
+            .module(
+                r#"{
+                "module": "ports",
+                "version": "1.0.0",
+                "exports": {
+                    "Repository": {
+                        "type": "trait",
+                        "type_params": [{"name": "T"}],
+                        "methods": {
+                            "find": {"inputs": [{"name": "id", "type": "string"}], "returns": {"type": "T | null"}},
+                            "save": {"inputs": [{"name": "item", "type": "T"}], "returns": {"type": "void"}}
+                        }
+                    }
+                },
+                "dependencies": {}
+            }"#,
+            )
+            .module(format!(
+                r#"{{
+                "module": "store",
+                "version": "1.0.0",
+                "exports": {{
+                    "User": {{"type": "interface", "properties": {{"id": {{"type": "string"}}}}}},
+                    "UserStore": {{
+                        "type": "class",
+                        "implements": ["{implements}"],
+                        "methods": {{
+                            "find": {{"inputs": [{{"name": "id", "type": "string"}}], "returns": {{"type": "User | null"}}}},
+                            "save": {{"inputs": [{{"name": "item", "type": "{saved_type}"}}], "returns": {{"type": "void"}}}}
+                        }}
+                    }}
+                }},
+                "dependencies": {{"ports": "^1.0.0"}}
+            }}"#
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_generic_trait_implemented_with_type_arguments() {
+        let project = generic_project("ports.Repository<store.User>", "store.User");
+
+        assert!(ImplementsMustReferenceTrait.validate(&project).is_empty());
+        assert!(ImplementationsMustMatch.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_type_arguments_substituted_before_comparing() {
+        let project = generic_project("ports.Repository<User>", "string");
+
+        let issues = ImplementationsMustMatch.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("store.UserStore.save"));
+        assert!(issues[0]
+            .message
+            .contains("parameter 'item' is 'string', expected 'T'"));
+    }
+
+    #[test]
+    fn test_generic_trait_arity_checked() {
+        let project = generic_project("ports.Repository", "User");
+
+        let issues = ImplementsMustReferenceTrait.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0]
+            .message
+            .contains("'ports.Repository<T>' expects 1 type argument(s), got 0"));
+        assert!(ImplementationsMustMatch.validate(&project).is_empty());
+    }
+}
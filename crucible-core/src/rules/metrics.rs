@@ -0,0 +1,208 @@
+//! Thresholds on module metrics configured in rules.json
+//!
+//! See [`crate::metrics`] for how the metrics are computed.
+
+use super::ValidationRule;
+use crate::metrics::{project_metrics, ModuleMetrics};
+use crate::types::{Project, Severity};
+use crate::validator::ValidationIssue;
+
+const DOC_LINK: &str =
+    "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#architecture-metrics";
+
+/// Module metrics must stay within the limits set in rules.json
+pub struct MetricsWithinThresholds;
+
+impl ValidationRule for MetricsWithinThresholds {
+    fn id(&self) -> &str {
+        "metrics-within-thresholds"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn description(&self) -> &str {
+        "Module coupling, instability and distance from the main sequence must stay within the configured limits"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let Some(thresholds) = project
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.metrics.as_ref())
+        else {
+            return issues;
+        };
+
+        for metrics in project_metrics(project) {
+            let module = &metrics.module;
+
+            if let Some(limit) = thresholds.max_afferent_coupling {
+                if metrics.afferent_coupling > limit {
+                    issues.push(self.issue(
+                        &metrics,
+                        format!(
+                            "Module '{module}' is depended on by {} modules",
+                            metrics.afferent_coupling
+                        ),
+                        metrics.afferent_coupling.to_string(),
+                        limit.to_string(),
+                        format!(
+                            "Split '{module}' so fewer modules rely on it, or raise \
+                             metrics.max_afferent_coupling in rules.json."
+                        ),
+                    ));
+                }
+            }
+
+            if let Some(limit) = thresholds.max_efferent_coupling {
+                if metrics.efferent_coupling > limit {
+                    issues.push(self.issue(
+                        &metrics,
+                        format!(
+                            "Module '{module}' depends on {} modules",
+                            metrics.efferent_coupling
+                        ),
+                        metrics.efferent_coupling.to_string(),
+                        limit.to_string(),
+                        format!(
+                            "Move responsibilities out of '{module}', or raise \
+                             metrics.max_efferent_coupling in rules.json."
+                        ),
+                    ));
+                }
+            }
+
+            if let Some(limit) = thresholds.max_instability {
+                if metrics.instability > limit {
+                    issues.push(self.issue(
+                        &metrics,
+                        format!(
+                            "Module '{module}' has instability {:.2}",
+                            metrics.instability
+                        ),
+                        format!("{:.2}", metrics.instability),
+                        format!("{limit:.2}"),
+                        format!(
+                            "Reduce what '{module}' depends on, or raise \
+                             metrics.max_instability in rules.json."
+                        ),
+                    ));
+                }
+            }
+
+            // The main sequence says nothing about a module nothing is coupled to
+            if let Some(limit) = thresholds.max_distance {
+                if metrics.is_coupled() && metrics.distance > limit {
+                    let advice = if metrics.abstractness + metrics.instability < 1.0 {
+                        format!(
+                            "'{module}' is stable but concrete; extract traits or interfaces \
+                             for its dependents to rely on"
+                        )
+                    } else {
+                        format!(
+                            "'{module}' is abstract but little depends on it; remove \
+                             abstractions nothing implements or uses"
+                        )
+                    };
+                    issues.push(self.issue(
+                        &metrics,
+                        format!(
+                            "Module '{module}' is {:.2} from the main sequence",
+                            metrics.distance
+                        ),
+                        format!("{:.2}", metrics.distance),
+                        format!("{limit:.2}"),
+                        format!("{advice}, or raise metrics.max_distance in rules.json."),
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+impl MetricsWithinThresholds {
+    fn issue(
+        &self,
+        metrics: &ModuleMetrics,
+        message: String,
+        found: String,
+        limit: String,
+        suggestion: String,
+    ) -> ValidationIssue {
+        ValidationIssue::with_comparison(
+            self.id().to_string(),
+            self.default_severity(),
+            message,
+            Some(metrics.module.clone()),
+            found,
+            format!("at most {limit}"),
+        )
+        .with_suggestion(suggestion)
+        .with_doc_link(DOC_LINK.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// `api` and `jobs` depend on `domain`, whose only export is a class, with
+    /// the given metrics thresholds in rules.json
+    fn validate(metrics: Option<&str>) -> Vec<ValidationIssue> {
+        let modules = [
+            ("api", r#"{"domain": "^1.0.0"}"#),
+            ("jobs", r#"{"domain": "^1.0.0"}"#),
+            ("domain", "{}"),
+            ("tools", "{}"),
+        ];
+        let rules = metrics.map(|metrics| format!(r#"{{"rules": [], "metrics": {metrics}}}"#));
+
+        let project = modules
+            .iter()
+            .fold(
+                TestProject::new("typescript"),
+                |project, (name, dependencies)| {
+                    project.module(format!(
+                        r#"{{"module": "{name}", "version": "1.0.0", "exports": {{"Thing": {{"type": "class"}}}}, "dependencies": {dependencies}}}"#
+                    ))
+                },
+            )
+            .rules(rules.as_deref())
+            .build();
+
+        MetricsWithinThresholds.validate(&project)
+    }
+
+    #[test]
+    fn test_thresholds_produce_warnings() {
+        let warnings = validate(Some(r#"{"max_afferent_coupling": 1, "max_distance": 0.5}"#));
+
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert_eq!(
+            warnings[0].message,
+            "Module 'domain' is depended on by 2 modules"
+        );
+        assert_eq!(warnings[0].expected.as_deref(), Some("at most 1"));
+        assert_eq!(
+            warnings[1].message,
+            "Module 'domain' is 1.00 from the main sequence"
+        );
+        assert!(warnings[1]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("stable but concrete"));
+    }
+
+    #[test]
+    fn test_no_thresholds_no_warnings() {
+        assert!(validate(None).is_empty());
+        assert!(validate(Some("{}")).is_empty());
+    }
+}
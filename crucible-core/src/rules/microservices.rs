@@ -0,0 +1,365 @@
+//! Rules for the microservices architecture pattern
+//!
+//! These rules only run when the manifest or rules.json selects the
+//! `microservices` pattern. Modules are grouped into services by their
+//! `service` field; modules without a service and modules marked as
+//! `contracts` are shared and may be used from any service.
+
+use super::ValidationRule;
+use crate::type_system::{type_usages, TypeDialect, TypeParser};
+use crate::types::{ArchitecturePattern, Export, ExportType, Module, Project, Severity};
+use crate::validator::ValidationIssue;
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#microservices";
+
+/// Check whether the project uses the microservices pattern
+fn is_microservices(project: &Project) -> bool {
+    project.manifest.project.architecture_pattern == Some(ArchitecturePattern::Microservices)
+        || project
+            .rules
+            .as_ref()
+            .and_then(|rules| rules.architecture.as_ref())
+            .is_some_and(|architecture| architecture.pattern == ArchitecturePattern::Microservices)
+}
+
+/// Service owning a module, unless the module is shared (no service, or a contracts module)
+fn owning_service(module: &Module) -> Option<&str> {
+    if module.contracts {
+        return None;
+    }
+    module.service.as_deref()
+}
+
+/// Service of `target` when it differs from the service of `caller`
+fn foreign_service<'a>(caller: &Module, target: &'a Module) -> Option<&'a str> {
+    let caller_service = caller.service.as_deref()?;
+    let target_service = owning_service(target)?;
+    (caller_service != target_service).then_some(target_service)
+}
+
+/// Services may only call each other's public API and events
+pub struct NoCrossServiceInternalCalls;
+
+impl ValidationRule for NoCrossServiceInternalCalls {
+    fn id(&self) -> &str {
+        "no-cross-service-internal-calls"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Calls into another service must target its public API or events"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if !is_microservices(project) {
+            return issues;
+        }
+
+        for module in &project.modules {
+            let mut exports: Vec<_> = module.exports.iter().collect();
+            exports.sort_by_key(|(name, _)| *name);
+
+            for (export_name, export) in exports {
+                let Some(methods) = &export.methods else {
+                    continue;
+                };
+                let mut methods: Vec<_> = methods.iter().collect();
+                methods.sort_by_key(|(name, _)| *name);
+
+                for (method_name, method) in methods {
+                    for call in &method.calls {
+                        let parts: Vec<&str> = call.split('.').collect();
+                        if parts.len() < 2 {
+                            continue;
+                        }
+
+                        // Unknown targets are reported by all-calls-must-exist
+                        let Some(target) = project.modules.iter().find(|m| m.module == parts[0])
+                        else {
+                            continue;
+                        };
+                        let Some(target_service) = foreign_service(module, target) else {
+                            continue;
+                        };
+                        let Some(target_export) = target.exports.get(parts[1]) else {
+                            continue;
+                        };
+                        if target_export.public_api
+                            || target_export.export_type == ExportType::Event
+                        {
+                            continue;
+                        }
+
+                        let caller_service = module.service.as_deref().unwrap_or_default();
+                        issues.push(
+                            ValidationIssue::new(
+                                self.id().to_string(),
+                                self.default_severity(),
+                                format!(
+                                    "Service '{caller_service}' calls '{call}', which is internal \
+                                     to service '{target_service}'"
+                                ),
+                                Some(format!("{}.{}.{}", module.module, export_name, method_name)),
+                            )
+                            .with_suggestion(format!(
+                                "Mark '{}.{}' as \"public_api\": true, or communicate through \
+                                 an event export instead.",
+                                target.module, parts[1]
+                            ))
+                            .with_doc_link(DOC_LINK.to_string()),
+                        );
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Types shared between services must live in a contracts module
+pub struct SharedTypesInContracts;
+
+impl SharedTypesInContracts {
+    /// Module that provides a type referenced from `caller`
+    ///
+    /// Qualified names (`module.Type`) name the module directly. Unqualified
+    /// names resolve to the caller itself, then to any module the caller may
+    /// use freely, and only then to a module in another service.
+    fn provider<'a>(
+        project: &'a Project,
+        caller: &Module,
+        type_name: &str,
+    ) -> Option<(&'a Module, &'a Export, &'a str)> {
+        if let Some((module_name, export_name)) = type_name.split_once('.') {
+            let module = project.modules.iter().find(|m| m.module == module_name)?;
+            let (name, export) = module.exports.get_key_value(export_name)?;
+            return Some((module, export, name.as_str()));
+        }
+
+        let mut providers: Vec<_> = project
+            .modules
+            .iter()
+            .filter_map(|module| {
+                let (name, export) = module.exports.get_key_value(type_name)?;
+                Some((module, export, name.as_str()))
+            })
+            .collect();
+        providers.sort_by_key(|(module, _, _)| {
+            if module.module == caller.module {
+                0
+            } else if foreign_service(caller, module).is_none() {
+                1
+            } else {
+                2
+            }
+        });
+        providers.into_iter().next()
+    }
+}
+
+impl ValidationRule for SharedTypesInContracts {
+    fn id(&self) -> &str {
+        "shared-types-via-contracts"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Types and interfaces used across services must be defined in a contracts module"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if !is_microservices(project) {
+            return issues;
+        }
+
+        let parser = TypeParser::for_dialect(TypeDialect::for_project(project));
+
+        for module in &project.modules {
+            let Some(caller_service) = module.service.as_deref() else {
+                continue;
+            };
+
+            for usage in type_usages(module) {
+                // Unparseable types are reported by all-types-must-exist
+                let Ok(type_ref) = parser.parse(usage.type_str) else {
+                    continue;
+                };
+
+                for type_name in type_ref.referenced_names() {
+                    let Some((provider, export, export_name)) =
+                        Self::provider(project, module, type_name)
+                    else {
+                        continue;
+                    };
+                    let Some(provider_service) = foreign_service(module, provider) else {
+                        continue;
+                    };
+                    if !matches!(export.export_type, ExportType::Type | ExportType::Interface) {
+                        continue;
+                    }
+
+                    issues.push(
+                        ValidationIssue::new(
+                            self.id().to_string(),
+                            self.default_severity(),
+                            format!(
+                                "Service '{caller_service}' uses '{}.{export_name}', which is \
+                                 owned by service '{provider_service}'",
+                                provider.module
+                            ),
+                            Some(usage.location.clone()),
+                        )
+                        .with_suggestion(format!(
+                            "Move '{export_name}' into a module with \"contracts\": true that \
+                             both services depend on."
+                        ))
+                        .with_doc_link(DOC_LINK.to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    const BILLING: &str = r#"{
+        "module": "billing",
+        "version": "1.0.0",
+        "service": "billing",
+        "exports": {
+            "BillingApi": {
+                "type": "class",
+                "public_api": true,
+                "methods": {"charge": {"inputs": [{"name": "amount", "type": "number"}], "returns": {"type": "void"}}}
+            },
+            "Ledger": {
+                "type": "class",
+                "methods": {"record": {"inputs": [], "returns": {"type": "void"}}}
+            },
+            "Invoice": {"type": "interface", "properties": {"id": {"type": "string"}}},
+            "InvoicePaid": {"type": "event", "payload": {"id": {"type": "string"}}}
+        },
+        "dependencies": {}
+    }"#;
+
+    const CONTRACTS: &str = r#"{
+        "module": "billing-contracts",
+        "version": "1.0.0",
+        "service": "billing",
+        "contracts": true,
+        "exports": {
+            "InvoiceDto": {"type": "interface", "properties": {"id": {"type": "string"}}}
+        },
+        "dependencies": {}
+    }"#;
+
+    /// A `pattern` project where the orders service's checkout method makes
+    /// `call` and returns `returns`
+    fn project(pattern: &str, call: &str, returns: &str) -> Project {
+        project_in_service(pattern, "orders", call, returns)
+    }
+
+    fn project_in_service(pattern: &str, service: &str, call: &str, returns: &str) -> Project {
+        TestProject::new("typescript")
+            .pattern(pattern)
+            .module(BILLING)
+            .module(CONTRACTS)
+            .module(format!(
+                r#"{{
+                "module": "orders",
+                "version": "1.0.0",
+                "service": "{service}",
+                "exports": {{
+                    "Checkout": {{
+                        "type": "class",
+                        "methods": {{"run": {{"inputs": [], "returns": {{"type": "{returns}"}}, "calls": ["{call}"]}}}}
+                    }}
+                }},
+                "dependencies": {{"billing": "^1.0.0", "billing-contracts": "^1.0.0"}}
+            }}"#
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_call_to_public_api_allowed() {
+        let project = project(
+            "microservices",
+            "billing.BillingApi.charge",
+            "billing-contracts.InvoiceDto",
+        );
+
+        assert!(NoCrossServiceInternalCalls.validate(&project).is_empty());
+        assert!(SharedTypesInContracts.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_internal_cross_service_call_reported() {
+        let project = project("microservices", "billing.Ledger.record", "void");
+
+        let issues = NoCrossServiceInternalCalls.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("orders.Checkout.run"));
+        assert!(issues[0].message.contains("billing.Ledger.record"));
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_shared_type_outside_contracts_reported() {
+        let project = project(
+            "microservices",
+            "billing.BillingApi.charge",
+            "billing.Invoice",
+        );
+
+        let issues = SharedTypesInContracts.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("orders.Checkout.run (returns)")
+        );
+    }
+
+    #[test]
+    fn test_unqualified_foreign_type_reported() {
+        let project = project("microservices", "billing.BillingApi.charge", "Invoice[]");
+
+        assert_eq!(SharedTypesInContracts.validate(&project).len(), 1);
+    }
+
+    #[test]
+    fn test_same_service_calls_unrestricted() {
+        let project = project_in_service(
+            "microservices",
+            "billing",
+            "billing.Ledger.record",
+            "billing.Invoice",
+        );
+
+        assert!(NoCrossServiceInternalCalls.validate(&project).is_empty());
+        assert!(SharedTypesInContracts.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_rules_inactive_for_other_patterns() {
+        let project = project("layered", "billing.Ledger.record", "billing.Invoice");
+
+        assert!(NoCrossServiceInternalCalls.validate(&project).is_empty());
+        assert!(SharedTypesInContracts.validate(&project).is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+//! Validation rule trait and registry
+//!
+//! Built-in checks and checks registered by library consumers implement the
+//! same [`ValidationRule`] trait, so rules.json can enable, disable and
+//! re-prioritize both by id.
+
+pub mod aliases;
+pub mod builtin;
+pub mod effects;
+pub mod events;
+pub mod hexagonal;
+pub mod implements;
+pub mod metrics;
+pub mod microservices;
+pub mod throws;
+pub mod unused;
+pub mod versions;
+
+use crate::type_system::{TypeDialect, TypeKind, TypeParser, TypeReference};
+use crate::types::{Module, Project, Severity};
+use crate::validator::ValidationIssue;
+
+/// A single architecture check
+pub trait ValidationRule {
+    /// Unique rule id, as referenced from rules.json
+    fn id(&self) -> &str;
+
+    /// Severity used when rules.json doesn't configure one
+    fn default_severity(&self) -> Severity;
+
+    /// Short human-readable description of what the rule checks
+    fn description(&self) -> &str {
+        ""
+    }
+
+    /// Run the check against a project
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue>;
+}
+
+/// Ordered collection of validation rules
+#[derive(Default)]
+pub struct RuleRegistry {
+    rules: Vec<Box<dyn ValidationRule>>,
+}
+
+impl RuleRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry containing all built-in rules
+    pub fn with_builtin_rules() -> Self {
+        let mut registry = Self::new();
+        registry.register(builtin::NoCircularDependencies);
+        registry.register(builtin::RespectLayerBoundaries);
+        registry.register(builtin::AllTypesMustExist);
+        registry.register(aliases::TypeAliasesMustResolve);
+        registry.register(builtin::AllCallsMustExist);
+        registry.register(builtin::UsedDependenciesDeclared);
+        registry.register(builtin::DeclaredDependenciesMustBeUsed);
+        registry.register(versions::ValidVersions);
+        registry.register(versions::DependencyVersionsCompatible);
+        registry.register(builtin::EventStructure);
+        registry.register(events::EventsMustExist);
+        registry.register(events::EmittedEventsMustBeHandled);
+        registry.register(events::SubscribedEventsMustBeEmitted);
+        registry.register(builtin::TraitStructure);
+        registry.register(builtin::ExportStructure);
+        registry.register(implements::ImplementsMustReferenceTrait);
+        registry.register(implements::ImplementationsMustMatch);
+        registry.register(effects::EffectsMustBeDeclared);
+        registry.register(effects::EffectsMustBeProduced);
+        registry.register(effects::LayerEffectsAllowed);
+        registry.register(throws::ThrownErrorsMustBeDeclared);
+        registry.register(throws::ThrownTypesMustExist);
+        registry.register(hexagonal::PortsAreAbstract);
+        registry.register(hexagonal::NoDependencyOnAdapters);
+        registry.register(hexagonal::PortsHaveAdapters);
+        registry.register(microservices::NoCrossServiceInternalCalls);
+        registry.register(microservices::SharedTypesInContracts);
+        registry.register(unused::NoUnusedExports);
+        registry.register(unused::ReachableFromEntryPoints);
+        registry.register(metrics::MetricsWithinThresholds);
+        registry
+    }
+
+    /// Register a rule, replacing any existing rule with the same id
+    pub fn register(&mut self, rule: impl ValidationRule + 'static) {
+        let rule: Box<dyn ValidationRule> = Box::new(rule);
+        match self.rules.iter().position(|r| r.id() == rule.id()) {
+            Some(index) => self.rules[index] = rule,
+            None => self.rules.push(rule),
+        }
+    }
+
+    /// Remove a rule by id, returning whether it was registered
+    pub fn unregister(&mut self, id: &str) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|rule| rule.id() != id);
+        self.rules.len() != before
+    }
+
+    /// Look up a rule by id
+    pub fn get(&self, id: &str) -> Option<&dyn ValidationRule> {
+        self.rules
+            .iter()
+            .find(|rule| rule.id() == id)
+            .map(|rule| rule.as_ref())
+    }
+
+    /// Check whether a rule with the given id is registered
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Iterate over rules in registration order
+    pub fn iter(&self) -> impl Iterator<Item = &dyn ValidationRule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    /// Ids of all registered rules
+    pub fn ids(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.id()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+/// Module owning the export a `module.Export` reference points to, with the
+/// export name
+///
+/// An unqualified name refers to an export of `module` itself, or failing
+/// that to the first module exporting it.
+pub(crate) fn resolve_export<'a>(
+    project: &'a Project,
+    module: &'a Module,
+    reference: &str,
+) -> Option<(&'a Module, String)> {
+    match reference.split_once('.') {
+        Some((module_name, export_name)) => project
+            .modules
+            .iter()
+            .find(|m| m.module == module_name && m.exports.contains_key(export_name))
+            .map(|m| (m, export_name.to_string())),
+        None => std::iter::once(module)
+            .chain(&project.modules)
+            .find(|m| m.exports.contains_key(reference))
+            .map(|m| (m, reference.to_string())),
+    }
+}
+
+/// Module owning the trait or interface an `implements` entry names, with its
+/// name and the type arguments passed to it (`ports.Repository<domain.User>`)
+///
+/// Entries that don't parse as a type don't resolve.
+pub(crate) fn resolve_implemented<'a>(
+    project: &'a Project,
+    module: &'a Module,
+    reference: &str,
+) -> Option<(&'a Module, String, Vec<TypeReference>)> {
+    let type_ref = TypeParser::for_dialect(TypeDialect::for_project(project))
+        .parse(reference)
+        .ok()?;
+    if type_ref.kind != TypeKind::Named || type_ref.nullable {
+        return None;
+    }
+    let (owner, name) = resolve_export(project, module, &type_ref.base_type)?;
+    Some((owner, name, type_ref.type_args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NamedRule(&'static str);
+
+    impl ValidationRule for NamedRule {
+        fn id(&self) -> &str {
+            self.0
+        }
+
+        fn default_severity(&self) -> Severity {
+            Severity::Info
+        }
+
+        fn validate(&self, _project: &Project) -> Vec<ValidationIssue> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_builtin_rules_registered() {
+        let registry = RuleRegistry::with_builtin_rules();
+        assert!(registry.contains("no-circular-dependencies"));
+        assert!(registry.contains("all-calls-must-exist"));
+        assert!(registry.contains("declared-dependencies-must-be-used"));
+        assert_eq!(
+            registry
+                .get("declared-dependencies-must-be-used")
+                .unwrap()
+                .default_severity(),
+            Severity::Warning
+        );
+    }
+
+    #[test]
+    fn test_register_replaces_rule_with_same_id() {
+        let mut registry = RuleRegistry::new();
+        registry.register(NamedRule("a"));
+        registry.register(NamedRule("b"));
+        registry.register(NamedRule("a"));
+        assert_eq!(registry.ids(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_unregister() {
+        let mut registry = RuleRegistry::with_builtin_rules();
+        let count = registry.len();
+        assert!(registry.unregister("event-structure"));
+        assert!(!registry.unregister("event-structure"));
+        assert_eq!(registry.len(), count - 1);
+    }
+}
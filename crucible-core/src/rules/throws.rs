@@ -0,0 +1,400 @@
+//! Checked-exception style validation of `throws`
+//!
+//! An error thrown by a callee escapes into the caller unless the caller lists
+//! it in `handles`. Escaping errors must be re-declared in the caller's
+//! `throws`, so every method's error contract reflects what can reach it.
+
+use super::ValidationRule;
+use crate::calls::CallGraph;
+use crate::type_system::TypeValidator;
+use crate::types::{Method, Module, Project, Severity};
+use crate::validator::ValidationIssue;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+
+const DOC_LINK: &str =
+    "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#error-contracts";
+
+/// Error name without its module qualifier
+fn error_name(error: &str) -> &str {
+    error.rsplit('.').next().unwrap_or(error)
+}
+
+/// An error as listed by a method of `module`: unqualified names the module
+/// exports are qualified with it, other names are kept as written
+fn qualify(module: &Module, error: &str) -> String {
+    if !error.contains('.') && module.exports.contains_key(error) {
+        format!("{}.{}", module.module, error)
+    } else {
+        error.to_string()
+    }
+}
+
+/// Whether two qualified errors are the same; an unqualified error (a builtin
+/// or one that didn't resolve) matches any error with the same name
+fn same_error(a: &str, b: &str) -> bool {
+    if a.contains('.') && b.contains('.') {
+        a == b
+    } else {
+        error_name(a) == error_name(b)
+    }
+}
+
+/// Whether a list of `module`'s errors covers a qualified error
+fn lists_error(module: &Module, errors: &[String], error: &str) -> bool {
+    errors
+        .iter()
+        .any(|listed| same_error(&qualify(module, listed), error))
+}
+
+/// Every method in the project with its module, keyed by `module.Export.method`
+fn collect_methods(project: &Project) -> BTreeMap<String, (&Module, &Method)> {
+    let mut methods = BTreeMap::new();
+    for module in &project.modules {
+        for (export_name, export) in &module.exports {
+            if let Some(export_methods) = &export.methods {
+                for (method_name, method) in export_methods {
+                    methods.insert(
+                        format!("{}.{}.{}", module.module, export_name, method_name),
+                        (module, method),
+                    );
+                }
+            }
+        }
+    }
+    methods
+}
+
+/// Qualified errors reaching each method from its callees, mapped to the
+/// direct callee they come through
+fn propagated_errors(project: &Project) -> BTreeMap<String, BTreeMap<String, String>> {
+    let methods = collect_methods(project);
+
+    let calls = CallGraph::new(project);
+
+    // Errors escaping each method: its own throws plus unhandled propagated
+    // errors. Grows monotonically, so recursion converges.
+    let mut escaping: BTreeMap<String, BTreeSet<String>> = methods
+        .iter()
+        .map(|(key, (module, method))| {
+            let throws = method
+                .throws
+                .iter()
+                .map(|error| qualify(module, error))
+                .collect();
+            (key.clone(), throws)
+        })
+        .collect();
+    let mut propagated: BTreeMap<String, BTreeMap<String, String>> = methods
+        .keys()
+        .map(|key| (key.clone(), BTreeMap::new()))
+        .collect();
+
+    loop {
+        let mut changed = false;
+        for (key, (module, method)) in &methods {
+            for target in calls.callees(key) {
+                if target == key {
+                    continue;
+                }
+                let target_errors: Vec<String> = escaping[target].iter().cloned().collect();
+                for error in target_errors {
+                    if let Entry::Vacant(entry) = propagated
+                        .get_mut(key)
+                        .expect("method was collected")
+                        .entry(error.clone())
+                    {
+                        entry.insert(target.clone());
+                        changed = true;
+                    }
+                    if !lists_error(module, &method.handles, &error) {
+                        changed |= escaping
+                            .get_mut(key)
+                            .expect("method was collected")
+                            .insert(error);
+                    }
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    propagated
+}
+
+/// Errors thrown by callees must be re-declared or handled
+pub struct ThrownErrorsMustBeDeclared;
+
+impl ValidationRule for ThrownErrorsMustBeDeclared {
+    fn id(&self) -> &str {
+        "thrown-errors-must-be-declared"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Errors thrown by called methods must be declared in throws or listed in handles"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let methods = collect_methods(project);
+        let mut issues = Vec::new();
+
+        for (key, errors) in propagated_errors(project) {
+            let (module, method) = methods[&key];
+            for (error, via) in errors {
+                if lists_error(module, &method.throws, &error)
+                    || lists_error(module, &method.handles, &error)
+                {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Method '{key}' can throw '{error}' from '{via}' but neither \
+                             declares nor handles it"
+                        ),
+                        Some(key.clone()),
+                    )
+                    .with_suggestion(format!(
+                        "Add '{error}' to the throws of '{key}', or to its handles if the \
+                         error is caught there."
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// Thrown and handled error types must be exported somewhere
+pub struct ThrownTypesMustExist;
+
+impl ValidationRule for ThrownTypesMustExist {
+    fn id(&self) -> &str {
+        "thrown-types-must-exist"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Error types in throws and handles must be exported types"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let validator = TypeValidator::for_project(project);
+        let mut issues = Vec::new();
+
+        for (key, (_, method)) in collect_methods(project) {
+            let listed = method
+                .throws
+                .iter()
+                .map(|error| ("throws", error))
+                .chain(method.handles.iter().map(|error| ("handles", error)));
+
+            for (list, error) in listed {
+                let Err(reason) = validator.validate_type_string(error, None, &project.modules)
+                else {
+                    continue;
+                };
+
+                issues.push(
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Error type '{error}' in {list} of '{key}' doesn't exist: {reason}"
+                        ),
+                        Some(key.clone()),
+                    )
+                    .with_suggestion(format!(
+                        "Export '{}' from the module that owns the error, or fix the name.",
+                        error_name(error)
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// A project where a controller calls a service that calls a repository
+    ///
+    /// `controller` and `service` are the JSON bodies of the two calling
+    /// methods' error lists (e.g. `"throws": [], "handles": []`).
+    fn project(controller: &str, service: &str) -> Project {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "todos",
+                "version": "1.0.0",
+                "exports": {{
+                    "TodoController": {{
+                        "type": "class",
+                        "methods": {{
+                            "get": {{"inputs": [], "returns": {{"type": "void"}}, "calls": ["todos.TodoService.find"], {controller}}}
+                        }}
+                    }},
+                    "TodoService": {{
+                        "type": "class",
+                        "methods": {{
+                            "find": {{"inputs": [], "returns": {{"type": "void"}}, "calls": ["todos.TodoRepository.load"], {service}}}
+                        }}
+                    }},
+                    "TodoRepository": {{
+                        "type": "class",
+                        "methods": {{
+                            "load": {{"inputs": [], "returns": {{"type": "void"}}, "throws": ["TodoNotFoundError", "DatabaseError"]}}
+                        }}
+                    }},
+                    "TodoNotFoundError": {{"type": "class"}},
+                    "DatabaseError": {{"type": "class"}}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_redeclared_and_handled_errors_valid() {
+        let project = project(
+            r#""throws": ["DatabaseError"], "handles": ["TodoNotFoundError"]"#,
+            r#""throws": ["TodoNotFoundError", "DatabaseError"]"#,
+        );
+
+        assert!(ThrownErrorsMustBeDeclared.validate(&project).is_empty());
+        assert!(ThrownTypesMustExist.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_undeclared_error_reported() {
+        let project = project(
+            r#""throws": ["DatabaseError"], "handles": ["TodoNotFoundError"]"#,
+            r#""throws": ["DatabaseError"]"#,
+        );
+
+        let issues = ThrownErrorsMustBeDeclared.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("todos.TodoService.find")
+        );
+        assert!(issues[0].message.contains("TodoNotFoundError"));
+        assert!(issues[0].message.contains("todos.TodoRepository.load"));
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_undeclared_error_propagates_to_callers() {
+        let project = project(r#""throws": []"#, r#""throws": []"#);
+
+        let locations: Vec<_> = ThrownErrorsMustBeDeclared
+            .validate(&project)
+            .into_iter()
+            .filter_map(|issue| issue.location)
+            .collect();
+        assert_eq!(
+            locations,
+            vec![
+                "todos.TodoController.get",
+                "todos.TodoController.get",
+                "todos.TodoService.find",
+                "todos.TodoService.find",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_handled_error_stops_propagating() {
+        let project = project(
+            r#""throws": []"#,
+            r#""throws": [], "handles": ["TodoNotFoundError", "DatabaseError"]"#,
+        );
+
+        assert!(ThrownErrorsMustBeDeclared.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_thrown_types_must_exist() {
+        let project = project(
+            r#""throws": ["DatabaseError"], "handles": ["TodoMissingError", "TodoNotFoundError"]"#,
+            r#""throws": ["TodoNotFoundError", "DatabaseError", "Error"]"#,
+        );
+
+        let issues = ThrownTypesMustExist.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].location.as_deref(),
+            Some("todos.TodoController.get")
+        );
+        assert!(issues[0].message.contains("TodoMissingError"));
+    }
+
+    /// A project where `api.Handler.handle` calls `db.Db.load`, which throws
+    /// the `db` module's own `AuthError`; `auth` exports another `AuthError`
+    fn qualified_project(handler_throws: &str) -> Project {
+        TestProject::new("typescript")
+            .module(
+                r#"{"module": "auth", "version": "1.0.0", "exports": {"AuthError": {"type": "class"}}, "dependencies": {}}"#,
+            )
+            .module(
+                r#"{
+                "module": "db",
+                "version": "1.0.0",
+                "exports": {
+                    "Db": {"type": "class", "methods": {"load": {"inputs": [], "returns": {"type": "void"}, "throws": ["AuthError"]}}},
+                    "AuthError": {"type": "class"}
+                },
+                "dependencies": {}
+            }"#,
+            )
+            .module(format!(
+                r#"{{
+                "module": "api",
+                "version": "1.0.0",
+                "exports": {{
+                    "Handler": {{"type": "class", "methods": {{"handle": {{"inputs": [], "returns": {{"type": "void"}}, "calls": ["db.Db.load"], "throws": {handler_throws}}}}}}}
+                }},
+                "dependencies": {{"auth": "^1.0.0", "db": "^1.0.0"}}
+            }}"#
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_errors_compared_by_qualified_name() {
+        let issues =
+            ThrownErrorsMustBeDeclared.validate(&qualified_project(r#"["auth.AuthError"]"#));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("'db.AuthError'"));
+
+        let project = qualified_project(r#"["db.AuthError"]"#);
+        assert!(ThrownErrorsMustBeDeclared.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_unqualified_error_matches_by_name() {
+        let project = qualified_project(r#"["AuthError"]"#);
+
+        assert!(ThrownErrorsMustBeDeclared.validate(&project).is_empty());
+    }
+}
@@ -0,0 +1,530 @@
+//! Dead architecture detection
+//!
+//! An export is used when something else refers to it: a `calls` entry, a
+//! type reference in a signature, property or payload, a `throws`/`handles`,
+//! `emits`/`subscribes` or `implements` entry, or a dependency import. Exports
+//! marked `entry_point` are used from outside the architecture and are the
+//! roots for reachability. A class implementing a used trait is used, a call
+//! to a trait method reaches the matching method of every implementation, and
+//! a call to an implementation method uses the trait method it implements.
+
+use super::{resolve_export, resolve_implemented, ValidationRule};
+use crate::calls::{resolve_call, CallGraph};
+use crate::type_system::{type_usages, TypeDialect, TypeParser};
+use crate::types::{Project, Severity};
+use crate::validator::ValidationIssue;
+use crate::versions::DependencySpec;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#entry-points";
+
+/// Classes implementing each trait or interface, keyed by `module.Trait`
+fn implementations(project: &Project) -> HashMap<String, Vec<String>> {
+    let mut implementations: HashMap<String, Vec<String>> = HashMap::new();
+    for module in &project.modules {
+        for (export_name, export) in &module.exports {
+            for implemented in &export.implements {
+                if let Some((trait_module, trait_name, _)) =
+                    resolve_implemented(project, module, implemented)
+                {
+                    implementations
+                        .entry(format!("{}.{}", trait_module.module, trait_name))
+                        .or_default()
+                        .push(format!("{}.{}", module.module, export_name));
+                }
+            }
+        }
+    }
+    implementations
+}
+
+/// Everything referenced somewhere in the project
+#[derive(Default)]
+struct References {
+    /// `module.Export` names referenced from outside the export itself
+    exports: HashSet<String>,
+    /// `module.Export.method` keys called from another method
+    methods: HashSet<String>,
+}
+
+impl References {
+    fn new(project: &Project) -> Self {
+        let mut refs = Self::default();
+        let parser = TypeParser::for_dialect(TypeDialect::for_project(project));
+
+        // Resolve a type name to the exports it may refer to
+        let type_targets = |name: &str| -> Vec<String> {
+            match name.split_once('.') {
+                Some(_) => vec![name.to_string()],
+                None => project
+                    .modules
+                    .iter()
+                    .filter(|module| module.exports.contains_key(name))
+                    .map(|module| format!("{}.{}", module.module, name))
+                    .collect(),
+            }
+        };
+
+        for module in &project.modules {
+            for (dep_name, value) in &module.dependencies {
+                if let DependencySpec::Exports = DependencySpec::parse(value) {
+                    for import in value.split(',') {
+                        refs.exports.insert(format!("{dep_name}.{}", import.trim()));
+                    }
+                }
+            }
+
+            for usage in type_usages(module) {
+                let Ok(type_ref) = parser.parse(usage.type_str) else {
+                    continue;
+                };
+                // Usage locations start with `module.Export`
+                let owner: String = usage
+                    .location
+                    .split(['.', ' '])
+                    .take(2)
+                    .collect::<Vec<_>>()
+                    .join(".");
+                for name in type_ref.referenced_names() {
+                    refs.exports
+                        .extend(type_targets(name).into_iter().filter(|t| *t != owner));
+                }
+            }
+
+            for (export_name, export) in &module.exports {
+                let owner = format!("{}.{}", module.module, export_name);
+
+                for implemented in &export.implements {
+                    if let Some((trait_module, trait_name, _)) =
+                        resolve_implemented(project, module, implemented)
+                    {
+                        refs.exports
+                            .insert(format!("{}.{}", trait_module.module, trait_name));
+                    }
+                }
+
+                for dependency in export.dependencies.iter().flatten() {
+                    for import in &dependency.imports {
+                        refs.exports
+                            .insert(format!("{}.{import}", dependency.module));
+                    }
+                }
+
+                let Some(methods) = &export.methods else {
+                    continue;
+                };
+                for (method_name, method) in methods {
+                    let key = format!("{owner}.{method_name}");
+
+                    for error in method.throws.iter().chain(&method.handles) {
+                        refs.exports
+                            .extend(type_targets(error).into_iter().filter(|t| *t != owner));
+                    }
+
+                    for event in method.emits.iter().chain(&method.subscribes) {
+                        if let Some((event_module, event_name)) =
+                            resolve_export(project, module, event)
+                        {
+                            refs.exports
+                                .insert(format!("{}.{}", event_module.module, event_name));
+                        }
+                    }
+
+                    for call in &method.calls {
+                        match resolve_call(module, export_name, export, call) {
+                            Some(target) => {
+                                if target != key {
+                                    refs.methods.insert(target.clone());
+                                }
+                                let target_export =
+                                    target.rsplit_once('.').map_or(target.as_str(), |(e, _)| e);
+                                if target_export != owner {
+                                    refs.exports.insert(target_export.to_string());
+                                }
+                            }
+                            // Standalone function: "module.function"
+                            None => {
+                                if call.split('.').count() == 2 && *call != owner {
+                                    refs.exports.insert(call.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Classes are used through the traits they implement. Calls through a
+        // trait dispatch to its implementations, and a call to an
+        // implementation uses the trait method it implements.
+        for (trait_key, classes) in &implementations(project) {
+            if refs.exports.contains(trait_key) {
+                refs.exports.extend(classes.iter().cloned());
+            }
+        }
+        let calls = CallGraph::new(project);
+        let mut dispatched = Vec::new();
+        for trait_method in calls.methods() {
+            for implementation in calls.implementations(trait_method) {
+                if refs.methods.contains(trait_method) {
+                    dispatched.push(implementation.clone());
+                }
+                if refs.methods.contains(implementation) {
+                    dispatched.push(trait_method.to_string());
+                }
+            }
+        }
+        refs.methods.extend(dispatched);
+
+        refs
+    }
+}
+
+/// Exports and methods that nothing refers to
+pub struct NoUnusedExports;
+
+impl ValidationRule for NoUnusedExports {
+    fn id(&self) -> &str {
+        "no-unused-exports"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn description(&self) -> &str {
+        "Exports and methods must be referenced somewhere or marked as entry points"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let refs = References::new(project);
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            let mut exports: Vec<_> = module.exports.iter().collect();
+            exports.sort_by_key(|(name, _)| *name);
+
+            for (export_name, export) in exports {
+                if export.entry_point {
+                    continue;
+                }
+
+                let location = format!("{}.{}", module.module, export_name);
+                if !refs.exports.contains(&location) {
+                    issues.push(
+                        ValidationIssue::new(
+                            self.id().to_string(),
+                            self.default_severity(),
+                            format!("Export '{location}' is never referenced"),
+                            Some(location.clone()),
+                        )
+                        .with_suggestion(format!(
+                            "Remove '{export_name}', or mark it \"entry_point\": true if it is \
+                             used from outside the architecture."
+                        ))
+                        .with_doc_link(DOC_LINK.to_string()),
+                    );
+                    continue;
+                }
+
+                let Some(methods) = &export.methods else {
+                    continue;
+                };
+                let mut methods: Vec<_> = methods.keys().collect();
+                methods.sort();
+
+                for method_name in methods {
+                    let key = format!("{location}.{method_name}");
+                    if refs.methods.contains(&key) {
+                        continue;
+                    }
+
+                    issues.push(
+                        ValidationIssue::new(
+                            self.id().to_string(),
+                            self.default_severity(),
+                            format!("Method '{key}' is never called"),
+                            Some(key.clone()),
+                        )
+                        .with_suggestion(format!(
+                            "Remove '{method_name}', or add it to the calls of the methods \
+                             that use it."
+                        ))
+                        .with_doc_link(DOC_LINK.to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// Methods and functions that are called, but only from code no entry point reaches
+pub struct ReachableFromEntryPoints;
+
+impl ValidationRule for ReachableFromEntryPoints {
+    fn id(&self) -> &str {
+        "reachable-from-entry-points"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn description(&self) -> &str {
+        "Called methods must be reachable from an entry point through the call graph"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        // Roots: every method of every entry point
+        let roots: Vec<String> = project
+            .modules
+            .iter()
+            .flat_map(|module| {
+                module
+                    .exports
+                    .iter()
+                    .filter(|(_, export)| export.entry_point)
+                    .map(|(export_name, _)| format!("{}.{}", module.module, export_name))
+            })
+            .collect();
+
+        // Reached standalone functions show up as unresolved calls
+        let calls = CallGraph::new(project);
+        let reached = calls.reachable(roots.iter().map(String::as_str));
+        if reached.methods.is_empty() {
+            return issues;
+        }
+
+        let refs = References::new(project);
+        let mut unreachable = BTreeSet::new();
+        for module in &project.modules {
+            for (export_name, export) in &module.exports {
+                if export.entry_point {
+                    continue;
+                }
+                let location = format!("{}.{}", module.module, export_name);
+
+                match &export.methods {
+                    Some(methods) => {
+                        for method_name in methods.keys() {
+                            let key = format!("{location}.{method_name}");
+                            // Never-called methods are reported by no-unused-exports,
+                            // and a trait method is reached with its implementations
+                            let reached = reached.methods.contains(&key)
+                                || calls
+                                    .implementations(&key)
+                                    .iter()
+                                    .any(|method| reached.methods.contains(method));
+                            if refs.methods.contains(&key) && !reached {
+                                unreachable.insert(key);
+                            }
+                        }
+                    }
+                    None => {
+                        let called_as_function = export.export_type
+                            == crate::types::ExportType::Function
+                            && refs.exports.contains(&location);
+                        if called_as_function && !reached.unresolved.contains(&location) {
+                            unreachable.insert(location);
+                        }
+                    }
+                }
+            }
+        }
+
+        for location in unreachable {
+            issues.push(
+                ValidationIssue::new(
+                    self.id().to_string(),
+                    self.default_severity(),
+                    format!("'{location}' is only called from code that no entry point reaches"),
+                    Some(location.clone()),
+                )
+                .with_suggestion(
+                    "Remove the unreachable call chain, or mark the export that starts it \
+                     \"entry_point\": true."
+                        .to_string(),
+                )
+                .with_doc_link(DOC_LINK.to_string()),
+            );
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// A project with a controller, a service and a repository
+    ///
+    /// `entry_point` is the controller's `entry_point` flag and `extra` adds
+    /// exports to the module (e.g. `, "Legacy": {...}`).
+    fn project(entry_point: bool, extra: &str) -> Project {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "todos",
+                "version": "1.0.0",
+                "exports": {{
+                    "TodoController": {{
+                        "type": "class",
+                        "entry_point": {entry_point},
+                        "methods": {{
+                            "get": {{"inputs": [], "returns": {{"type": "Todo"}}, "calls": ["todos.TodoService.find"]}}
+                        }}
+                    }},
+                    "TodoService": {{
+                        "type": "class",
+                        "methods": {{
+                            "find": {{"inputs": [], "returns": {{"type": "Todo"}}, "calls": ["todos.TodoRepository.load"]}}
+                        }}
+                    }},
+                    "TodoRepository": {{
+                        "type": "class",
+                        "methods": {{
+                            "load": {{"inputs": [], "returns": {{"type": "Todo"}}}}
+                        }}
+                    }},
+                    "Todo": {{"type": "interface", "properties": {{"id": {{"type": "string"}}}}}}{extra}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+            .build()
+    }
+
+    fn locations(issues: Vec<ValidationIssue>) -> Vec<String> {
+        issues
+            .into_iter()
+            .filter_map(|issue| issue.location)
+            .collect()
+    }
+
+    #[test]
+    fn test_referenced_exports_valid() {
+        let project = project(true, "");
+
+        assert!(NoUnusedExports.validate(&project).is_empty());
+        assert!(ReachableFromEntryPoints.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_unreferenced_entry_point_reported_without_flag() {
+        let issues = NoUnusedExports.validate(&project(false, ""));
+
+        assert_eq!(locations(issues), vec!["todos.TodoController"]);
+    }
+
+    #[test]
+    fn test_unused_export_and_method_reported() {
+        let project = project(
+            true,
+            r#",
+            "Legacy": {"type": "class", "methods": {"run": {"inputs": [], "returns": {"type": "void"}}}},
+            "TodoMapper": {"type": "function"}"#,
+        );
+
+        assert_eq!(
+            locations(NoUnusedExports.validate(&project)),
+            vec!["todos.Legacy", "todos.TodoMapper"]
+        );
+    }
+
+    #[test]
+    fn test_uncalled_method_of_used_export_reported() {
+        let project = project(
+            true,
+            r#",
+            "Cleaner": {"type": "class", "methods": {
+                "run": {"inputs": [], "returns": {"type": "void"}, "calls": ["todos.TodoRepository.load"]},
+                "unused": {"inputs": [], "returns": {"type": "void"}}
+            }},
+            "Job": {"type": "interface", "properties": {"cleaner": {"type": "Cleaner"}}}"#,
+        );
+
+        assert_eq!(
+            locations(NoUnusedExports.validate(&project)),
+            vec!["todos.Cleaner.run", "todos.Cleaner.unused", "todos.Job"]
+        );
+    }
+
+    #[test]
+    fn test_call_chain_not_reached_from_entry_points_reported() {
+        let project = project(
+            true,
+            r#",
+            "Scheduler": {"type": "class", "methods": {
+                "tick": {"inputs": [], "returns": {"type": "void"}, "calls": ["todos.Archiver.archive"]}
+            }},
+            "Archiver": {"type": "class", "methods": {
+                "archive": {"inputs": [], "returns": {"type": "void"}, "calls": ["todos.TodoRepository.load"]}
+            }}"#,
+        );
+
+        // Scheduler is unused; the Archiver it calls is dead with it
+        assert_eq!(
+            locations(NoUnusedExports.validate(&project)),
+            vec!["todos.Scheduler"]
+        );
+        assert_eq!(
+            locations(ReachableFromEntryPoints.validate(&project)),
+            vec!["todos.Archiver.archive"]
+        );
+    }
+
+    #[test]
+    fn test_reachability_skipped_without_entry_points() {
+        assert!(ReachableFromEntryPoints
+            .validate(&project(false, ""))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_calls_through_traits_reach_implementations() {
+        let project = project(
+            true,
+            r#",
+            "Cli": {"type": "class", "entry_point": true, "methods": {
+                "run": {"inputs": [], "returns": {"type": "void"}, "calls": ["todos.Store.load"]}
+            }},
+            "Store": {"type": "trait", "methods": {"load": {"inputs": [], "returns": {"type": "void"}}}},
+            "MemoryStore": {"type": "class", "implements": ["Store"], "methods": {
+                "load": {"inputs": [], "returns": {"type": "void"}, "calls": ["todos.TodoRepository.load"]}
+            }}"#,
+        );
+
+        assert!(NoUnusedExports.validate(&project).is_empty());
+        assert!(ReachableFromEntryPoints.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_calls_to_implementations_use_trait_methods() {
+        let project = project(
+            true,
+            r#",
+            "Cli": {"type": "class", "entry_point": true, "methods": {
+                "run": {"inputs": [], "returns": {"type": "void"}, "calls": ["todos.MemoryStore.load"]}
+            }},
+            "Store": {"type": "trait", "methods": {"load": {"inputs": [], "returns": {"type": "void"}}}},
+            "MemoryStore": {"type": "class", "implements": ["Store"], "methods": {
+                "load": {"inputs": [], "returns": {"type": "void"}}
+            }},
+            "FileStore": {"type": "class", "implements": ["Store"], "methods": {
+                "load": {"inputs": [], "returns": {"type": "void"}}
+            }}"#,
+        );
+
+        assert_eq!(
+            locations(NoUnusedExports.validate(&project)),
+            vec!["todos.FileStore.load"]
+        );
+        assert!(ReachableFromEntryPoints.validate(&project).is_empty());
+    }
+}
@@ -0,0 +1,217 @@
+//! Semantic version rules for module versions and dependency constraints
+
+use super::ValidationRule;
+use crate::types::{Project, Severity};
+use crate::validator::ValidationIssue;
+use crate::versions::DependencySpec;
+use semver::Version;
+
+const DOC_LINK: &str = "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#versioning";
+
+/// Module versions and dependency constraints must be valid semver
+pub struct ValidVersions;
+
+impl ValidationRule for ValidVersions {
+    fn id(&self) -> &str {
+        "valid-versions"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Module versions and dependency constraints must be valid semver"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            if let Err(err) = Version::parse(&module.version) {
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Module '{}' has an invalid version '{}': {err}",
+                            module.module, module.version
+                        ),
+                        Some(module.module.clone()),
+                        module.version.clone(),
+                        "MAJOR.MINOR.PATCH (e.g. 1.0.0)".to_string(),
+                    )
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+
+            let mut dependencies: Vec<_> = module.dependencies.iter().collect();
+            dependencies.sort();
+
+            for (dep_name, value) in dependencies {
+                let DependencySpec::Invalid(err) = DependencySpec::parse(value) else {
+                    continue;
+                };
+
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "Dependency '{dep_name}' of '{}' has an invalid version \
+                             constraint '{value}': {err}",
+                            module.module
+                        ),
+                        Some(format!("{} -> {}", module.module, dep_name)),
+                        value.clone(),
+                        "a semver constraint (e.g. ^1.0.0, ~1.2.0, >=1.0.0, <2.0.0)".to_string(),
+                    )
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+/// Dependency constraints must accept the depended-on module's version
+pub struct DependencyVersionsCompatible;
+
+impl ValidationRule for DependencyVersionsCompatible {
+    fn id(&self) -> &str {
+        "dependency-versions-compatible"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn description(&self) -> &str {
+        "Dependency version constraints must be satisfied by the target module's version"
+    }
+
+    fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        for module in &project.modules {
+            let mut dependencies: Vec<_> = module.dependencies.iter().collect();
+            dependencies.sort();
+
+            for (dep_name, value) in dependencies {
+                let DependencySpec::Constraint(req) = DependencySpec::parse(value) else {
+                    continue;
+                };
+                let Some(target) = project.modules.iter().find(|m| &m.module == dep_name) else {
+                    continue;
+                };
+                let Ok(version) = Version::parse(&target.version) else {
+                    continue;
+                };
+                if req.matches(&version) {
+                    continue;
+                }
+
+                issues.push(
+                    ValidationIssue::with_comparison(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!(
+                            "'{}' requires '{dep_name}' {value}, but '{dep_name}' is at \
+                             version {version}",
+                            module.module
+                        ),
+                        Some(format!("{} -> {}", module.module, dep_name)),
+                        version.to_string(),
+                        value.clone(),
+                    )
+                    .with_suggestion(format!(
+                        "Update the constraint to '^{version}' after checking '{dep_name}' \
+                         for breaking changes. Run 'crucible deps outdated' to list all \
+                         lagging constraints."
+                    ))
+                    .with_doc_link(DOC_LINK.to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// A project where `app` depends on `lib` with the given constraint
+    fn project(constraint: &str, lib_version: &str) -> Project {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "app",
+                "version": "1.0.0",
+                "exports": {{
+                    "App": {{"type": "class", "methods": {{"run": {{"inputs": [], "returns": {{"type": "void"}}, "calls": ["lib.Lib.work"]}}}}}}
+                }},
+                "dependencies": {{"lib": "{constraint}"}}
+            }}"#
+            ))
+            .module(format!(
+                r#"{{
+                "module": "lib",
+                "version": "{lib_version}",
+                "exports": {{
+                    "Lib": {{"type": "class", "methods": {{"work": {{"inputs": [], "returns": {{"type": "void"}}}}}}}}
+                }},
+                "dependencies": {{}}
+            }}"#
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_satisfied_constraint_valid() {
+        let project = project("^1.0.0", "1.3.0");
+
+        assert!(DependencyVersionsCompatible.validate(&project).is_empty());
+        assert!(ValidVersions.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_unsatisfied_constraint_reported() {
+        let issues = DependencyVersionsCompatible.validate(&project("^1.0.0", "2.0.0"));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("app -> lib"));
+        assert_eq!(issues[0].found.as_deref(), Some("2.0.0"));
+        assert_eq!(issues[0].expected.as_deref(), Some("^1.0.0"));
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_invalid_constraint_reported() {
+        let issues = ValidVersions.validate(&project("^1.x.y", "1.0.0"));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("app -> lib"));
+    }
+
+    #[test]
+    fn test_invalid_module_version_reported() {
+        let project = project("^1.0.0", "1.0");
+
+        let issues = ValidVersions.validate(&project);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location.as_deref(), Some("lib"));
+        assert!(DependencyVersionsCompatible.validate(&project).is_empty());
+    }
+
+    #[test]
+    fn test_export_list_dependencies_skipped() {
+        let project = project("Lib", "1.0.0");
+
+        assert!(ValidVersions.validate(&project).is_empty());
+        assert!(DependencyVersionsCompatible.validate(&project).is_empty());
+    }
+}
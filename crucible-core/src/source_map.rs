@@ -0,0 +1,425 @@
+//! Source positions for architecture definitions
+//!
+//! serde_json doesn't expose spans, so the parser runs a lightweight scan over
+//! each file and records the line and column of every value, keyed by JSON
+//! pointer. Validation issues carry dotted locations such as
+//! `module.Export.method`; [`SourceMap::resolve`] maps those back to a file
+//! position so editors and CI annotations can jump to the offending line.
+
+use crate::types::Project;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Position of an element in a `.crucible` file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    /// RFC 6901 JSON pointer to the element (e.g. `/exports/User/methods/find`)
+    pub pointer: String,
+    /// 1-based line
+    pub line: usize,
+    /// 1-based column, in characters
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
+/// Positions of all values in one JSON file
+#[derive(Debug, Clone)]
+struct FileSpans {
+    path: PathBuf,
+    positions: HashMap<String, (usize, usize)>,
+}
+
+impl FileSpans {
+    fn new(path: &Path, content: &str) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            positions: scan_positions(content),
+        }
+    }
+
+    fn locate(&self, pointer: &str) -> Option<SourceLocation> {
+        self.positions
+            .get(pointer)
+            .map(|&(line, column)| SourceLocation {
+                file: self.path.clone(),
+                pointer: pointer.to_string(),
+                line,
+                column,
+            })
+    }
+}
+
+/// Positions of the values in a project's manifest, module and rules files
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    manifest: Option<FileSpans>,
+    rules: Option<FileSpans>,
+    modules: HashMap<String, FileSpans>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record positions for manifest.json
+    pub fn add_manifest(&mut self, path: &Path, content: &str) {
+        self.manifest = Some(FileSpans::new(path, content));
+    }
+
+    /// Record positions for rules.json
+    pub fn add_rules(&mut self, path: &Path, content: &str) {
+        self.rules = Some(FileSpans::new(path, content));
+    }
+
+    /// Record positions for a module definition file
+    pub fn add_module(&mut self, name: &str, path: &Path, content: &str) {
+        self.modules
+            .insert(name.to_string(), FileSpans::new(path, content));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.manifest.is_none() && self.rules.is_none() && self.modules.is_empty()
+    }
+
+    /// Position of a JSON pointer in a module file
+    pub fn module_position(&self, module: &str, pointer: &str) -> Option<SourceLocation> {
+        self.modules.get(module)?.locate(pointer)
+    }
+
+    /// Position of a JSON pointer in rules.json
+    pub fn rules_position(&self, pointer: &str) -> Option<SourceLocation> {
+        self.rules.as_ref()?.locate(pointer)
+    }
+
+    /// Position of a JSON pointer in manifest.json
+    pub fn manifest_position(&self, pointer: &str) -> Option<SourceLocation> {
+        self.manifest.as_ref()?.locate(pointer)
+    }
+
+    /// Map a dotted issue location to the most specific known file position
+    ///
+    /// Understands `module`, `module.Export`, `module.Export.member`,
+    /// `module.Export.payload.field`, `from -> to` and the `(returns)` /
+    /// `(parameter: name)` suffixes used by the built-in rules, plus
+    /// `rules.json` locations.
+    pub fn resolve(&self, project: &Project, location: &str) -> Option<SourceLocation> {
+        if let Some(rest) = location.strip_prefix("rules.json") {
+            let section = if rest.contains("custom_rules") {
+                "/custom_rules"
+            } else {
+                "/rules"
+            };
+            return self
+                .rules_position(section)
+                .or_else(|| self.rules_position(""));
+        }
+
+        let (path, detail) = match location.split_once(" (") {
+            Some((path, detail)) => (path, Some(detail.trim_end_matches(')'))),
+            None => (location, None),
+        };
+        let (path, target) = match path.split_once(" -> ") {
+            Some((from, to)) => (from, Some(to)),
+            None => (path, None),
+        };
+
+        let parts: Vec<&str> = path.split('.').collect();
+        let module_name = parts[0];
+        let spans = self.modules.get(module_name)?;
+
+        // Candidate pointers, most specific first
+        let mut candidates = Vec::new();
+
+        if let Some(target) = target {
+            candidates.push(format!("/dependencies/{}", escape_pointer(target)));
+        }
+
+        if let Some(export_name) = parts.get(1) {
+            let export_pointer = format!("/exports/{}", escape_pointer(export_name));
+
+            if let Some(member) = parts.get(2) {
+                let member_escaped = escape_pointer(member);
+
+                if *member == "payload" {
+                    if let Some(field) = parts.get(3) {
+                        candidates.push(format!(
+                            "{export_pointer}/payload/{}",
+                            escape_pointer(field)
+                        ));
+                    }
+                    candidates.push(format!("{export_pointer}/payload"));
+                }
+
+                let method_pointer = format!("{export_pointer}/methods/{member_escaped}");
+                match detail {
+                    Some("returns") => candidates.push(format!("{method_pointer}/returns")),
+                    Some(detail) => {
+                        if let Some(param) = detail.strip_prefix("parameter: ") {
+                            if let Some(index) =
+                                parameter_index(project, module_name, export_name, member, param)
+                            {
+                                candidates.push(format!("{method_pointer}/inputs/{index}"));
+                            }
+                        }
+                    }
+                    None => {}
+                }
+                candidates.push(method_pointer);
+                candidates.push(format!("{export_pointer}/properties/{member_escaped}"));
+                candidates.push(format!("{export_pointer}/payload/{member_escaped}"));
+            }
+
+            candidates.push(export_pointer);
+        }
+
+        candidates.push(String::new());
+
+        candidates.iter().find_map(|pointer| spans.locate(pointer))
+    }
+}
+
+/// Index of a named parameter in a method's inputs
+fn parameter_index(
+    project: &Project,
+    module: &str,
+    export: &str,
+    method: &str,
+    param: &str,
+) -> Option<usize> {
+    project
+        .modules
+        .iter()
+        .find(|m| m.module == module)?
+        .exports
+        .get(export)?
+        .methods
+        .as_ref()?
+        .get(method)?
+        .inputs
+        .iter()
+        .position(|input| input.name == param)
+}
+
+/// Escape a reference token per RFC 6901
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Record the position of every value in a JSON document by JSON pointer
+///
+/// Object members are positioned at their key, array elements at the start of
+/// the element. Scanning stops quietly at malformed input; the document has
+/// already been validated by serde at this point.
+fn scan_positions(content: &str) -> HashMap<String, (usize, usize)> {
+    let mut scanner = Scanner {
+        chars: content.chars().peekable(),
+        line: 1,
+        column: 1,
+        positions: HashMap::new(),
+    };
+
+    scanner.skip_whitespace();
+    let start = (scanner.line, scanner.column);
+    scanner.positions.insert(String::new(), start);
+    scanner.value("");
+    scanner.positions
+}
+
+struct Scanner<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+    positions: HashMap<String, (usize, usize)>,
+}
+
+impl Scanner<'_> {
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    /// Scan one value; returns false on malformed input
+    fn value(&mut self, pointer: &str) -> bool {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('{') => self.object(pointer),
+            Some('[') => self.array(pointer),
+            Some('"') => self.string().is_some(),
+            Some(_) => {
+                while self
+                    .chars
+                    .peek()
+                    .is_some_and(|c| !matches!(c, ',' | '}' | ']') && !c.is_whitespace())
+                {
+                    self.bump();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn object(&mut self, pointer: &str) -> bool {
+        self.bump(); // {
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some('}') => {
+                    self.bump();
+                    return true;
+                }
+                Some(',') => {
+                    self.bump();
+                }
+                Some('"') => {
+                    let position = (self.line, self.column);
+                    let Some(key) = self.string() else {
+                        return false;
+                    };
+                    let child = format!("{pointer}/{}", escape_pointer(&key));
+                    self.positions.insert(child.clone(), position);
+
+                    self.skip_whitespace();
+                    if self.bump() != Some(':') || !self.value(&child) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &str) -> bool {
+        self.bump(); // [
+        let mut index = 0;
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(']') => {
+                    self.bump();
+                    return true;
+                }
+                Some(',') => {
+                    self.bump();
+                }
+                Some(_) => {
+                    let child = format!("{pointer}/{index}");
+                    self.positions
+                        .insert(child.clone(), (self.line, self.column));
+                    if !self.value(&child) {
+                        return false;
+                    }
+                    index += 1;
+                }
+                None => return false,
+            }
+        }
+    }
+
+    /// Scan a string literal, returning its decoded contents
+    fn string(&mut self) -> Option<String> {
+        self.bump(); // opening quote
+        let mut text = String::new();
+        loop {
+            match self.bump()? {
+                '"' => return Some(text),
+                '\\' => match self.bump()? {
+                    'n' => text.push('\n'),
+                    't' => text.push('\t'),
+                    'r' => text.push('\r'),
+                    'b' => text.push('\u{8}'),
+                    'f' => text.push('\u{c}'),
+                    'u' => {
+                        let hex: String = (0..4).filter_map(|_| self.bump()).collect();
+                        let code = u32::from_str_radix(&hex, 16).ok()?;
+                        text.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    other => text.push(other),
+                },
+                c => text.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = r#"{
+  "module": "auth",
+  "exports": {
+    "User": {
+      "type": "class",
+      "methods": {
+        "login": {
+          "inputs": [
+            {"name": "password", "type": "string"}
+          ],
+          "returns": {"type": "void"}
+        }
+      }
+    }
+  },
+  "dependencies": {"db": "^1.0.0"}
+}"#;
+
+    #[test]
+    fn test_scan_positions() {
+        let positions = scan_positions(MODULE);
+        assert_eq!(positions[""], (1, 1));
+        assert_eq!(positions["/module"], (2, 3));
+        assert_eq!(positions["/exports/User"], (4, 5));
+        assert_eq!(positions["/exports/User/methods/login"], (7, 9));
+        assert_eq!(positions["/exports/User/methods/login/inputs/0"], (9, 13));
+        assert_eq!(
+            positions["/exports/User/methods/login/inputs/0/name"],
+            (9, 14)
+        );
+        assert_eq!(positions["/dependencies/db"], (16, 20));
+    }
+
+    #[test]
+    fn test_scan_escaped_keys() {
+        let positions = scan_positions(r#"{"a/b": {"x\"y": 1}}"#);
+        assert_eq!(positions["/a~1b"], (1, 2));
+        assert!(positions.contains_key("/a~1b/x\"y"));
+    }
+
+    #[test]
+    fn test_scan_stops_at_malformed_input() {
+        let positions = scan_positions(r#"{"a": 1, "b": "#);
+        assert!(positions.contains_key("/a"));
+    }
+
+    #[test]
+    fn test_display() {
+        let location = SourceLocation {
+            file: PathBuf::from(".crucible/modules/auth.json"),
+            pointer: "/exports/User".to_string(),
+            line: 4,
+            column: 5,
+        };
+        assert_eq!(location.to_string(), ".crucible/modules/auth.json:4:5");
+    }
+}
@@ -0,0 +1,316 @@
+//! Inline suppression of validation issues
+//!
+//! Modules, exports and methods can accept known issues with a
+//! `suppress: [{rule, reason}]` entry. A suppression covers issues of that
+//! rule located on the element itself or on anything nested inside it.
+
+use crate::types::{Project, Severity, Suppression};
+use crate::validator::{ValidationIssue, ValidationResult};
+
+/// A validation issue accepted by an inline suppression
+#[derive(Debug, Clone)]
+pub struct SuppressedIssue {
+    pub issue: ValidationIssue,
+    /// Justification given by the suppression
+    pub reason: String,
+    /// Module, export or method declaring the suppression (e.g. `auth.AuthService.login`)
+    pub scope: String,
+}
+
+/// A suppression together with the element that declares it
+struct SuppressionSite<'a> {
+    scope: String,
+    suppression: &'a Suppression,
+    used: bool,
+}
+
+impl SuppressionSite<'_> {
+    fn matches(&self, issue: &ValidationIssue) -> bool {
+        issue.rule == self.suppression.rule
+            && issue
+                .location
+                .as_deref()
+                .is_some_and(|location| is_within_scope(location, &self.scope))
+    }
+}
+
+/// Check whether an issue location refers to `scope` or something nested in it
+///
+/// Locations look like `module`, `module.Export.method`,
+/// `module.Export.method (returns)` or `from -> to`.
+fn is_within_scope(location: &str, scope: &str) -> bool {
+    match location.strip_prefix(scope) {
+        Some(rest) => rest.is_empty() || rest.starts_with('.') || rest.starts_with(' '),
+        None => false,
+    }
+}
+
+/// Collect every suppression in the project, in a deterministic order
+fn collect_sites(project: &Project) -> Vec<SuppressionSite<'_>> {
+    let mut sites = Vec::new();
+
+    for module in &project.modules {
+        for suppression in &module.suppress {
+            sites.push(SuppressionSite {
+                scope: module.module.clone(),
+                suppression,
+                used: false,
+            });
+        }
+
+        let mut exports: Vec<_> = module.exports.iter().collect();
+        exports.sort_by_key(|(name, _)| *name);
+
+        for (export_name, export) in exports {
+            let export_scope = format!("{}.{}", module.module, export_name);
+            for suppression in &export.suppress {
+                sites.push(SuppressionSite {
+                    scope: export_scope.clone(),
+                    suppression,
+                    used: false,
+                });
+            }
+
+            let mut methods: Vec<_> = export.methods.iter().flatten().collect();
+            methods.sort_by_key(|(name, _)| *name);
+
+            for (method_name, method) in methods {
+                for suppression in &method.suppress {
+                    sites.push(SuppressionSite {
+                        scope: format!("{export_scope}.{method_name}"),
+                        suppression,
+                        used: false,
+                    });
+                }
+            }
+        }
+    }
+
+    sites
+}
+
+/// Id of the issues reporting suppressions that match nothing, which rules.json
+/// can configure like a rule
+pub(crate) const UNUSED_SUPPRESSION: &str = "unused-suppression";
+
+/// Move suppressed issues out of `result` and return issues for the
+/// suppressions that match nothing, before rules.json configuration
+pub(crate) fn apply_suppressions(
+    project: &Project,
+    result: &mut ValidationResult,
+) -> Vec<ValidationIssue> {
+    let mut sites = collect_sites(project);
+    if sites.is_empty() {
+        return Vec::new();
+    }
+
+    for issues in [&mut result.errors, &mut result.warnings, &mut result.info] {
+        let mut kept = Vec::new();
+
+        for issue in issues.drain(..) {
+            // Every matching suppression counts as used; the most specific one
+            // provides the reason
+            let mut best: Option<usize> = None;
+            for (index, site) in sites.iter_mut().enumerate() {
+                if site.matches(&issue) {
+                    site.used = true;
+                    best = Some(index);
+                }
+            }
+
+            match best {
+                Some(index) => result.suppressed.push(SuppressedIssue {
+                    issue,
+                    reason: sites[index].suppression.reason.clone(),
+                    scope: sites[index].scope.clone(),
+                }),
+                None => kept.push(issue),
+            }
+        }
+
+        *issues = kept;
+    }
+
+    result.valid = result.errors.is_empty();
+
+    sites
+        .iter()
+        .filter(|site| !site.used)
+        .map(|site| {
+            ValidationIssue::new(
+                UNUSED_SUPPRESSION.to_string(),
+                Severity::Warning,
+                format!(
+                    "Suppression of '{}' on '{}' does not match any issue",
+                    site.suppression.rule, site.scope
+                ),
+                Some(site.scope.clone()),
+            )
+            .with_suggestion("Remove the suppression if the issue has been fixed".to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    #[test]
+    fn test_is_within_scope() {
+        assert!(is_within_scope("auth", "auth"));
+        assert!(is_within_scope("auth.User", "auth"));
+        assert!(is_within_scope("auth -> db", "auth"));
+        assert!(is_within_scope(
+            "auth.User.login (returns)",
+            "auth.User.login"
+        ));
+        assert!(!is_within_scope("authz", "auth"));
+        assert!(!is_within_scope("auth.UserStore", "auth.User"));
+        assert!(!is_within_scope("db", "auth"));
+    }
+
+    /// Module `a` calls a method that doesn't exist and declares an unused
+    /// dependency on `b`
+    fn project_with_suppressions(module_suppress: &str, method_suppress: &str) -> TestProject {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{
+                "module": "a",
+                "version": "1.0.0",
+                "exports": {{
+                    "Service": {{
+                        "type": "class",
+                        "methods": {{
+                            "run": {{
+                                "inputs": [],
+                                "returns": {{"type": "void"}},
+                                "calls": ["a.Service.missing"],
+                                "suppress": {method_suppress}
+                            }}
+                        }}
+                    }}
+                }},
+                "dependencies": {{"b": "^1.0.0"}},
+                "suppress": {module_suppress}
+            }}"#
+            ))
+            .module(r#"{"module": "b", "version": "1.0.0", "exports": {}, "dependencies": {}}"#)
+    }
+
+    fn validate_with_suppressions(
+        module_suppress: &str,
+        method_suppress: &str,
+    ) -> ValidationResult {
+        project_with_suppressions(module_suppress, method_suppress).validate()
+    }
+
+    #[test]
+    fn test_issues_reported_without_suppressions() {
+        let result = validate_with_suppressions("[]", "[]");
+
+        assert!(!result.valid);
+        assert!(result
+            .errors
+            .iter()
+            .any(|e| e.rule == "all-calls-must-exist"));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.rule == "declared-dependencies-must-be-used"));
+        assert!(result.suppressed.is_empty());
+    }
+
+    #[test]
+    fn test_method_suppression_drops_error() {
+        let result = validate_with_suppressions(
+            "[]",
+            r#"[{"rule": "all-calls-must-exist", "reason": "Implemented by a generated mixin"}]"#,
+        );
+
+        assert!(result.valid);
+        assert!(!result
+            .errors
+            .iter()
+            .any(|e| e.rule == "all-calls-must-exist"));
+
+        let suppressed = result
+            .suppressed
+            .iter()
+            .find(|s| s.issue.rule == "all-calls-must-exist")
+            .expect("expected suppressed issue");
+        assert_eq!(suppressed.scope, "a.Service.run");
+        assert_eq!(suppressed.reason, "Implemented by a generated mixin");
+    }
+
+    #[test]
+    fn test_module_suppression_covers_nested_issues() {
+        let result = validate_with_suppressions(
+            r#"[
+                {"rule": "all-calls-must-exist", "reason": "Legacy module"},
+                {"rule": "declared-dependencies-must-be-used", "reason": "Loaded at runtime"}
+            ]"#,
+            "[]",
+        );
+
+        assert!(result.valid);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.suppressed.len(), 2);
+        assert!(result.suppressed.iter().all(|s| s.scope == "a"));
+    }
+
+    #[test]
+    fn test_suppression_only_applies_to_its_rule() {
+        let result = validate_with_suppressions(
+            "[]",
+            r#"[{"rule": "declared-dependencies-must-be-used", "reason": "Wrong scope"}]"#,
+        );
+
+        assert!(!result.valid);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.rule == "declared-dependencies-must-be-used"));
+    }
+
+    #[test]
+    fn test_stale_suppression_is_reported() {
+        let result = validate_with_suppressions(
+            r#"[{"rule": "no-circular-dependencies", "reason": "Fixed long ago"}]"#,
+            "[]",
+        );
+
+        let issue = result
+            .warnings
+            .iter()
+            .find(|w| w.rule == "unused-suppression")
+            .expect("expected unused-suppression warning");
+        assert!(issue.message.contains("no-circular-dependencies"));
+        assert_eq!(issue.location.as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn test_unused_suppression_configured_in_rules() {
+        let stale = r#"[{"rule": "no-circular-dependencies", "reason": "Fixed long ago"}]"#;
+
+        let disabled = project_with_suppressions(stale, "[]")
+            .rules(Some(
+                r#"{"rules": [{"id": "unused-suppression", "enabled": false, "severity": "warning"}]}"#,
+            ))
+            .validate();
+        assert!(!disabled
+            .warnings
+            .iter()
+            .any(|w| w.rule == UNUSED_SUPPRESSION || w.rule == "unknown-rule"));
+
+        let escalated = project_with_suppressions(stale, "[]")
+            .rules(Some(
+                r#"{"rules": [{"id": "unused-suppression", "enabled": true, "severity": "error"}]}"#,
+            ))
+            .validate();
+        assert!(escalated
+            .errors
+            .iter()
+            .any(|e| e.rule == UNUSED_SUPPRESSION));
+    }
+}
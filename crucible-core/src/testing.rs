@@ -0,0 +1,80 @@
+//! Projects assembled in memory for unit tests
+
+use crate::types::Project;
+use crate::validator::{ValidationResult, Validator};
+use serde_json::{json, Value};
+
+/// A project built from JSON module definitions, listed in the manifest in
+/// the order they're added
+pub(crate) struct TestProject {
+    manifest: Value,
+    modules: Vec<String>,
+    rules: Option<String>,
+}
+
+impl TestProject {
+    /// An empty project in the given language
+    pub fn new(language: &str) -> Self {
+        Self {
+            manifest: json!({
+                "version": "0.1.0",
+                "project": {"name": "test", "language": language},
+                "modules": []
+            }),
+            modules: Vec::new(),
+            rules: None,
+        }
+    }
+
+    /// Set the manifest's architecture pattern
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.manifest["project"]["architecture_pattern"] = json!(pattern);
+        self
+    }
+
+    /// Set a top-level manifest field to the given JSON
+    pub fn manifest_field(mut self, key: &str, value: &str) -> Self {
+        self.manifest[key] = serde_json::from_str(value).unwrap();
+        self
+    }
+
+    /// Add a module definition and list it in the manifest under its `module` name
+    pub fn module(mut self, json: impl Into<String>) -> Self {
+        let json = json.into();
+        let definition: Value = serde_json::from_str(&json).unwrap();
+        self.manifest["modules"]
+            .as_array_mut()
+            .unwrap()
+            .push(definition["module"].clone());
+        self.modules.push(json);
+        self
+    }
+
+    /// Use the given rules.json, if any
+    pub fn rules(mut self, rules: Option<&str>) -> Self {
+        self.rules = rules.map(String::from);
+        self
+    }
+
+    /// The project as the parser would produce it, without source positions
+    pub fn build(&self) -> Project {
+        Project {
+            manifest: serde_json::from_value(self.manifest.clone()).unwrap(),
+            modules: self
+                .modules
+                .iter()
+                .map(|module| serde_json::from_str(module).unwrap())
+                .collect(),
+            rules: self
+                .rules
+                .as_ref()
+                .map(|rules| serde_json::from_str(rules).unwrap()),
+            source_map: Default::default(),
+        }
+    }
+
+    /// Run every registered rule over the project
+    pub fn validate(&self) -> ValidationResult {
+        Validator::new(self.build()).validate()
+    }
+}
@@ -5,14 +5,21 @@
 //! - Nullable types (Type | null)
 //! - Array syntax (Type[] and array with items)
 //! - Generic types (`Partial<T>`, `Omit<T, K>`, etc.)
+//!
+//! Built-in types and syntax follow the project language's [`TypeDialect`];
+//! the language-neutral types of the spec (`string`, `number`, `Array<T>`,
+//! ...) are available in every dialect.
 
-use crate::types::Module;
-use std::collections::HashSet;
+use crate::graph::{cyclic_components_of, shortest_cycle};
+use crate::types::{Export, ExportType, Language, Module, Project, Property, TypeParam};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Unified type reference structure
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeReference {
-    /// Base type name (e.g., "Patient", "array", "Partial")
+    /// Base type name (e.g., "Patient", "array", "Partial"), or the kind of a
+    /// composite type ("union", "intersection", "literal", "tuple", "function",
+    /// "keyof", "typeof", "indexed")
     pub base_type: String,
 
     /// Whether this type can be null
@@ -23,6 +30,39 @@ pub struct TypeReference {
 
     /// For generic types - type arguments
     pub type_args: Vec<TypeReference>,
+
+    /// Structure of composite types
+    pub kind: TypeKind,
+}
+
+/// Structure of a type expression beyond a (possibly generic) name
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TypeKind {
+    /// `Patient`, `Map<K, V>`, or an `array` with items
+    #[default]
+    Named,
+    /// `A | B`; `null` members are folded into `nullable` instead
+    Union(Vec<TypeReference>),
+    /// `A & B`
+    Intersection(Vec<TypeReference>),
+    /// String, number or boolean literal, as written: `'active'`, `42`, `true`
+    Literal(String),
+    /// `[string, number]`, or `(A, B)` in Rust
+    Tuple(Vec<TypeReference>),
+    /// `(id: string) => Patient`; parameter names aren't kept
+    Function {
+        params: Vec<TypeReference>,
+        returns: Box<TypeReference>,
+    },
+    /// `keyof Patient`
+    KeyOf(Box<TypeReference>),
+    /// `typeof value`; the value isn't a type and isn't checked
+    TypeOf(String),
+    /// `Patient['id']`
+    IndexedAccess {
+        object: Box<TypeReference>,
+        index: Box<TypeReference>,
+    },
 }
 
 impl TypeReference {
@@ -33,92 +73,371 @@ impl TypeReference {
             nullable: false,
             items: None,
             type_args: vec![],
+            kind: TypeKind::Named,
         }
     }
 
     /// Create a nullable type reference
     pub fn nullable(base_type: impl Into<String>) -> Self {
         Self {
-            base_type: base_type.into(),
             nullable: true,
-            items: None,
-            type_args: vec![],
+            ..Self::simple(base_type)
         }
     }
 
     /// Create an array type reference
     pub fn array(items: TypeReference) -> Self {
         Self {
-            base_type: "array".to_string(),
-            nullable: false,
             items: Some(Box::new(items)),
-            type_args: vec![],
+            ..Self::simple("array")
         }
     }
 
     /// Create a generic type reference
     pub fn generic(base_type: impl Into<String>, type_args: Vec<TypeReference>) -> Self {
         Self {
-            base_type: base_type.into(),
-            nullable: false,
-            items: None,
             type_args,
+            ..Self::simple(base_type)
+        }
+    }
+
+    /// Create a composite type reference, named after its kind
+    pub fn composite(kind: TypeKind) -> Self {
+        let base_type = match &kind {
+            TypeKind::Named => "named",
+            TypeKind::Union(_) => "union",
+            TypeKind::Intersection(_) => "intersection",
+            TypeKind::Literal(_) => "literal",
+            TypeKind::Tuple(_) => "tuple",
+            TypeKind::Function { .. } => "function",
+            TypeKind::KeyOf(_) => "keyof",
+            TypeKind::TypeOf(_) => "typeof",
+            TypeKind::IndexedAccess { .. } => "indexed",
+        };
+        Self {
+            kind,
+            ..Self::simple(base_type)
+        }
+    }
+
+    /// Names of all types this reference mentions, including array items,
+    /// type arguments and members of composite types (the `array` wrapper and
+    /// composite kinds themselves are not included)
+    pub fn referenced_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        match &self.kind {
+            TypeKind::Named => {
+                if self.base_type != "array" {
+                    names.push(self.base_type.as_str());
+                }
+                if let Some(items) = &self.items {
+                    names.extend(items.referenced_names());
+                }
+                for arg in &self.type_args {
+                    names.extend(arg.referenced_names());
+                }
+            }
+            TypeKind::Union(members)
+            | TypeKind::Intersection(members)
+            | TypeKind::Tuple(members) => {
+                for member in members {
+                    names.extend(member.referenced_names());
+                }
+            }
+            TypeKind::Literal(_) | TypeKind::TypeOf(_) => {}
+            TypeKind::Function { params, returns } => {
+                for param in params {
+                    names.extend(param.referenced_names());
+                }
+                names.extend(returns.referenced_names());
+            }
+            TypeKind::KeyOf(operand) => names.extend(operand.referenced_names()),
+            TypeKind::IndexedAccess { object, index } => {
+                names.extend(object.referenced_names());
+                names.extend(index.referenced_names());
+            }
+        }
+        names
+    }
+}
+
+/// A type string used somewhere in a module definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeUsage<'a> {
+    /// Issue location of the usage (e.g. `module.Export.method (returns)`)
+    pub location: String,
+    pub type_str: &'a str,
+}
+
+/// Every type string a module uses in its aliases, method signatures,
+/// properties and event payloads, in a stable order
+pub fn type_usages(module: &Module) -> Vec<TypeUsage<'_>> {
+    let mut usages = Vec::new();
+
+    let mut exports: Vec<_> = module.exports.iter().collect();
+    exports.sort_by_key(|(name, _)| *name);
+
+    for (export_name, export) in exports {
+        let export_location = format!("{}.{}", module.module, export_name);
+
+        if let Some(alias) = &export.alias {
+            usages.push(TypeUsage {
+                location: format!("{export_location} (alias)"),
+                type_str: alias,
+            });
+        }
+
+        if let Some(methods) = &export.methods {
+            let mut methods: Vec<_> = methods.iter().collect();
+            methods.sort_by_key(|(name, _)| *name);
+
+            for (method_name, method) in methods {
+                for param in &method.inputs {
+                    usages.push(TypeUsage {
+                        location: format!(
+                            "{export_location}.{method_name} (parameter: {})",
+                            param.name
+                        ),
+                        type_str: &param.param_type,
+                    });
+                }
+
+                let returns_location = format!("{export_location}.{method_name} (returns)");
+                usages.push(TypeUsage {
+                    location: returns_location.clone(),
+                    type_str: &method.returns.return_type,
+                });
+                if let Some(inner) = &method.returns.inner {
+                    usages.push(TypeUsage {
+                        location: returns_location,
+                        type_str: inner,
+                    });
+                }
+            }
+        }
+
+        if let Some(properties) = &export.properties {
+            let mut properties: Vec<_> = properties.iter().collect();
+            properties.sort_by_key(|(name, _)| *name);
+
+            for (prop_name, prop) in properties {
+                usages.push(TypeUsage {
+                    location: format!("{export_location}.{prop_name}"),
+                    type_str: &prop.prop_type,
+                });
+            }
+        }
+
+        if let Some(payload) = &export.payload {
+            let mut fields: Vec<_> = payload.iter().collect();
+            fields.sort_by_key(|(name, _)| *name);
+
+            for (field_name, field) in fields {
+                usages.push(TypeUsage {
+                    location: format!("{export_location}.payload.{field_name}"),
+                    type_str: &field.prop_type,
+                });
+            }
+        }
+    }
+
+    usages
+}
+
+/// Type syntax and built-in types of a project language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TypeDialect {
+    #[default]
+    TypeScript,
+    /// `String`, `Vec<T>`, `Option<T>`, `&str`, `chrono::DateTime`
+    Rust,
+    /// `str`, `list[str]`, `Optional[int]`, `int | None`
+    Python,
+}
+
+impl TypeDialect {
+    /// Dialect of the project's manifest language
+    pub fn for_project(project: &Project) -> Self {
+        Self::from(&project.manifest.project.language)
+    }
+}
+
+impl From<&Language> for TypeDialect {
+    fn from(language: &Language) -> Self {
+        match language {
+            Language::Rust => TypeDialect::Rust,
+            Language::Python => TypeDialect::Python,
+            // Go and Java keep the TypeScript names until they get a dialect
+            Language::TypeScript | Language::Go | Language::Java => TypeDialect::TypeScript,
         }
     }
 }
 
-/// Built-in TypeScript type registry
+/// Language-neutral types of the spec, built in for every dialect
+const NEUTRAL_TYPES: &[&str] = &[
+    "string", "number", "boolean", "void", "null", "object", "any", "unknown", "never", "Date",
+    "Error",
+];
+
+const TYPESCRIPT_TYPES: &[&str] = &[
+    // Primitives
+    "undefined",
+    "symbol",
+    "bigint",
+    // Objects
+    "Buffer",
+    "RegExp",
+    "Map",
+    "Set",
+    // Database/Connection types
+    "Connection",
+    "Transaction",
+    "QueryResult",
+    // Common Node.js
+    "Promise",
+    "Array",
+];
+
+const RUST_TYPES: &[&str] = &[
+    "String",
+    "str",
+    "bool",
+    "char",
+    "()",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "f32",
+    "f64",
+    "Path",
+    "PathBuf",
+    "Duration",
+    "SystemTime",
+    "Instant",
+];
+
+const PYTHON_TYPES: &[&str] = &[
+    "str",
+    "int",
+    "float",
+    "complex",
+    "bool",
+    "bytes",
+    "bytearray",
+    "None",
+    "Any",
+    "datetime",
+    "date",
+    "time",
+    "timedelta",
+    "Decimal",
+    "UUID",
+    "Path",
+    "Exception",
+];
+
+/// Language-neutral generics of the spec, available in every dialect
+const NEUTRAL_GENERICS: &[&str] = &["Array", "Map", "Set", "Promise", "Result", "Record"];
+
+const TYPESCRIPT_GENERICS: &[&str] = &[
+    // TypeScript utility types
+    "Partial",
+    "Required",
+    "Readonly",
+    "Pick",
+    "Omit",
+    "Exclude",
+    "Extract",
+    "NonNullable",
+    "ReturnType",
+    "InstanceType",
+    "Parameters",
+    "ConstructorParameters",
+    // Rust-style names, accepted before dialects existed
+    "Vec",
+    "HashMap",
+    "HashSet",
+    "Option",
+];
+
+const RUST_GENERICS: &[&str] = &[
+    "Vec", "VecDeque", "Option", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "Box", "Rc", "Arc",
+    "RefCell", "Cell", "Mutex", "RwLock", "Cow", "Pin",
+];
+
+const PYTHON_GENERICS: &[&str] = &[
+    "list",
+    "dict",
+    "set",
+    "frozenset",
+    "tuple",
+    "type",
+    "Optional",
+    "Union",
+    "List",
+    "Dict",
+    "Set",
+    "FrozenSet",
+    "Tuple",
+    "Type",
+    "Sequence",
+    "Mapping",
+    "Iterable",
+    "Iterator",
+    "Callable",
+    "Awaitable",
+];
+
+/// Built-in type registry
 pub struct BuiltInTypeRegistry {
-    types: HashSet<&'static str>,
+    types: HashSet<String>,
 }
 
 impl BuiltInTypeRegistry {
-    /// Create a new built-in type registry with default types
+    /// Create a new built-in type registry with the TypeScript types
     pub fn new() -> Self {
-        let mut types = HashSet::new();
-
-        // Primitives
-        types.insert("string");
-        types.insert("number");
-        types.insert("boolean");
-        types.insert("void");
-        types.insert("null");
-        types.insert("undefined");
-
-        // Objects
-        types.insert("Date");
-        types.insert("Buffer");
-        types.insert("Error");
-        types.insert("RegExp");
-        types.insert("Map");
-        types.insert("Set");
-
-        // Database/Connection types
-        types.insert("Connection");
-        types.insert("Transaction");
-        types.insert("QueryResult");
-
-        // Special
-        types.insert("object");
-        types.insert("any");
-        types.insert("unknown");
-        types.insert("never");
-
-        // Common Node.js
-        types.insert("Promise");
-        types.insert("Array");
+        Self::for_dialect(TypeDialect::TypeScript)
+    }
+
+    /// Create a registry with the built-in types of a dialect
+    pub fn for_dialect(dialect: TypeDialect) -> Self {
+        let dialect_types = match dialect {
+            TypeDialect::TypeScript => TYPESCRIPT_TYPES,
+            TypeDialect::Rust => RUST_TYPES,
+            TypeDialect::Python => PYTHON_TYPES,
+        };
+        let types = NEUTRAL_TYPES
+            .iter()
+            .chain(dialect_types)
+            .map(|name| name.to_string())
+            .collect();
 
         Self { types }
     }
 
+    /// Register a type that's defined outside the architecture, such as
+    /// `chrono::DateTime` or `express.Request`
+    pub fn register(&mut self, type_name: impl Into<String>) {
+        self.types.insert(type_name.into());
+    }
+
     /// Check if a type name is a built-in type
     pub fn is_builtin(&self, type_name: &str) -> bool {
         self.types.contains(type_name)
     }
 
     /// Get all built-in type names
-    pub fn get_builtins(&self) -> Vec<&'static str> {
-        self.types.iter().copied().collect()
+    pub fn get_builtins(&self) -> Vec<&str> {
+        self.types.iter().map(String::as_str).collect()
     }
 }
 
@@ -128,7 +447,7 @@ impl Default for BuiltInTypeRegistry {
     }
 }
 
-/// Generic utility type registry
+/// Generic type registry
 pub struct GenericTypeRegistry {
     generics: HashSet<&'static str>,
 }
@@ -136,33 +455,21 @@ pub struct GenericTypeRegistry {
 impl GenericTypeRegistry {
     /// Create a new generic type registry with common TypeScript generics
     pub fn new() -> Self {
-        let mut generics = HashSet::new();
-
-        // TypeScript utility types
-        generics.insert("Partial");
-        generics.insert("Required");
-        generics.insert("Readonly");
-        generics.insert("Pick");
-        generics.insert("Omit");
-        generics.insert("Exclude");
-        generics.insert("Extract");
-        generics.insert("NonNullable");
-        generics.insert("ReturnType");
-        generics.insert("InstanceType");
-        generics.insert("Parameters");
-        generics.insert("ConstructorParameters");
-
-        // Common generics
-        generics.insert("Record");
-        generics.insert("Promise");
-        generics.insert("Array");
-        generics.insert("Vec"); // Rust-style array
-        generics.insert("Map");
-        generics.insert("HashMap");
-        generics.insert("Set");
-        generics.insert("HashSet");
-        generics.insert("Option");
-        generics.insert("Result");
+        Self::for_dialect(TypeDialect::TypeScript)
+    }
+
+    /// Create a registry with the generic types of a dialect
+    pub fn for_dialect(dialect: TypeDialect) -> Self {
+        let dialect_generics = match dialect {
+            TypeDialect::TypeScript => TYPESCRIPT_GENERICS,
+            TypeDialect::Rust => RUST_GENERICS,
+            TypeDialect::Python => PYTHON_GENERICS,
+        };
+        let generics = NEUTRAL_GENERICS
+            .iter()
+            .chain(dialect_generics)
+            .copied()
+            .collect();
 
         Self { generics }
     }
@@ -172,10 +479,12 @@ impl GenericTypeRegistry {
         self.generics.contains(type_name)
     }
 
-    /// Validate a generic type reference
+    /// Validate a generic type reference, with the type parameters of the
+    /// enclosing export in scope
     pub fn validate_generic(
         &self,
         type_ref: &TypeReference,
+        type_params: &[TypeParam],
         modules: &[Module],
         validator: &TypeValidator,
     ) -> Result<(), String> {
@@ -185,7 +494,7 @@ impl GenericTypeRegistry {
 
         // Validate all type arguments
         for arg in &type_ref.type_args {
-            validator.validate_type_exists(arg, modules)?;
+            validator.validate_type_in_scope(arg, type_params, modules)?;
         }
 
         Ok(())
@@ -198,110 +507,84 @@ impl Default for GenericTypeRegistry {
     }
 }
 
-/// Type parser - parses type strings into TypeReference
-pub struct TypeParser {}
+/// Error from parsing a type expression, pointing at the offending column
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParseError {
+    pub message: String,
+    /// 1-based column of the offending character in `input`
+    pub column: usize,
+    pub input: String,
+}
 
-impl TypeParser {
-    /// Create a new type parser
-    pub fn new() -> Self {
-        Self {}
+impl std::fmt::Display for TypeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at column {} of '{}'",
+            self.message, self.column, self.input
+        )
     }
+}
 
-    /// Parse a type string into a TypeReference
-    pub fn parse(&self, type_str: &str) -> Result<TypeReference, String> {
-        // Handle array shorthand syntax (Type[])
-        if type_str.ends_with("[]") {
-            return self.parse_array_syntax(type_str);
-        }
-
-        // Handle angle bracket generic syntax (Promise<T>, Map<K,V>)
-        if type_str.contains('<') && type_str.contains('>') {
-            return self.parse_angle_bracket_generic(type_str);
-        }
-
-        // Handle union types (Type | null, Type | undefined)
-        if type_str.contains(" | ") {
-            return self.parse_union_type(type_str);
-        }
+impl std::error::Error for TypeParseError {}
 
-        // Simple type
-        Ok(TypeReference::simple(type_str))
+impl From<TypeParseError> for String {
+    fn from(error: TypeParseError) -> Self {
+        error.to_string()
     }
+}
 
-    /// Parse union type syntax (Type | null, Type | undefined)
-    /// For now, only handle nullable unions (Type | null, Type | undefined)
-    fn parse_union_type(&self, type_str: &str) -> Result<TypeReference, String> {
-        let parts: Vec<&str> = type_str.split(" | ").map(|s| s.trim()).collect();
-
-        // Check if this is a nullable union (Type | null or Type | undefined)
-        if parts.len() == 2 {
-            if parts[1] == "null" || parts[1] == "undefined" {
-                // Parse the base type and mark as nullable
-                let mut type_ref = self.parse(parts[0])?;
-                type_ref.nullable = true;
-                return Ok(type_ref);
-            }
-            if parts[0] == "null" || parts[0] == "undefined" {
-                // null | Type (reversed order)
-                let mut type_ref = self.parse(parts[1])?;
-                type_ref.nullable = true;
-                return Ok(type_ref);
-            }
-        }
+/// Type parser - parses type strings into TypeReference
+///
+/// Grammar (TypeScript, with the dialect's differences noted):
+///
+/// ```text
+/// type         := ['|'] intersection ('|' intersection)*
+/// intersection := postfix ('&' postfix)*            (not in Rust)
+/// postfix      := primary ('[' ']' | '[' type ']')*   (indexed access only in TypeScript)
+/// primary      := name [type-args] | literal | tuple | function | '(' type ')'
+///               | operator postfix | 'typeof' name   (TypeScript)
+/// operator     := 'keyof' | 'readonly' | 'unique'
+/// type-args    := '<' type (',' type)* '>'          ('[' ... ']' in Python)
+/// tuple        := '[' [type (',' type)*] ']'        ('(' ... ')' in Rust)
+/// function     := '(' [param (',' param)*] ')' '=>' type
+/// param        := ['...'] name ['?'] ':' type
+/// ```
+///
+/// TypeScript's `readonly` and `unique` modifiers are skipped, and template
+/// literal types are kept as literals without checking their placeholders.
+/// Rust references, lifetimes and `dyn`/`impl` (`&'a mut dyn Error`) are
+/// skipped, and `[T]` / `[T; N]` are arrays. Python's `Optional[T]` and
+/// `Union[A, B]` are read as `T | None` and `A | B`.
+pub struct TypeParser {
+    dialect: TypeDialect,
+}
 
-        // Other union types not yet supported
-        Err(format!(
-            "Union type '{type_str}' not supported yet (only Type | null is supported)"
-        ))
+impl TypeParser {
+    /// Create a new type parser for TypeScript syntax
+    pub fn new() -> Self {
+        Self::for_dialect(TypeDialect::TypeScript)
     }
 
-    /// Parse angle bracket generic syntax (Promise<T>, Vec<string>, Map<K, V>)
-    fn parse_angle_bracket_generic(&self, type_str: &str) -> Result<TypeReference, String> {
-        // Find the base type and type arguments
-        let open_bracket = type_str.find('<').ok_or("Missing opening bracket")?;
-        let close_bracket = type_str.rfind('>').ok_or("Missing closing bracket")?;
-
-        let base_type = &type_str[..open_bracket];
-        let args_str = &type_str[open_bracket + 1..close_bracket];
-
-        // Parse type arguments (split by comma, but handle nested generics)
-        let mut type_args = Vec::new();
-        let mut current_arg = String::new();
-        let mut depth = 0;
-
-        for ch in args_str.chars() {
-            match ch {
-                '<' => {
-                    depth += 1;
-                    current_arg.push(ch);
-                }
-                '>' => {
-                    depth -= 1;
-                    current_arg.push(ch);
-                }
-                ',' if depth == 0 => {
-                    let arg = current_arg.trim();
-                    if !arg.is_empty() {
-                        type_args.push(self.parse(arg)?);
-                    }
-                    current_arg.clear();
-                }
-                _ => current_arg.push(ch),
-            }
-        }
+    /// Create a type parser for the syntax of a dialect
+    pub fn for_dialect(dialect: TypeDialect) -> Self {
+        Self { dialect }
+    }
 
-        // Don't forget the last argument
-        let arg = current_arg.trim();
-        if !arg.is_empty() {
-            type_args.push(self.parse(arg)?);
+    /// Parse a type string into a TypeReference
+    pub fn parse(&self, type_str: &str) -> Result<TypeReference, TypeParseError> {
+        let tokens = tokenize(type_str, self.dialect)?;
+        let mut parser = TypeExprParser {
+            input: type_str,
+            tokens,
+            pos: 0,
+            dialect: self.dialect,
+        };
+        let type_ref = parser.parse_type()?;
+        if parser.peek() != &Token::End {
+            return Err(parser.unexpected("end of type"));
         }
-
-        Ok(TypeReference {
-            base_type: base_type.to_string(),
-            nullable: false,
-            items: None,
-            type_args,
-        })
+        Ok(type_ref)
     }
 
     /// Parse array shorthand syntax (Type[])
@@ -310,13 +593,7 @@ impl TypeParser {
             return Err(format!("Expected array syntax, got: {type_str}"));
         }
 
-        // Remove [] suffix
-        let base = &type_str[..type_str.len() - 2];
-
-        // Recursively parse the base type (handles nested arrays)
-        let items = self.parse(base)?;
-
-        Ok(TypeReference::array(items))
+        Ok(self.parse(type_str)?)
     }
 
     /// Parse a type reference from JSON-like structure
@@ -327,12 +604,8 @@ impl TypeParser {
         items: Option<&str>,
         type_args: Option<&[String]>,
     ) -> Result<TypeReference, String> {
-        let mut type_ref = TypeReference {
-            base_type: base_type.to_string(),
-            nullable: nullable.unwrap_or(false),
-            items: None,
-            type_args: vec![],
-        };
+        let mut type_ref = TypeReference::simple(base_type);
+        type_ref.nullable = nullable.unwrap_or(false);
 
         // Handle array items
         if let Some(items_str) = items {
@@ -357,6 +630,507 @@ impl Default for TypeParser {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    /// String literal, including its quotes
+    Str(String),
+    Number(String),
+    /// Rust lifetime (`'a`)
+    Lifetime,
+    Punct(&'static str),
+    End,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Ident(text) | Token::Str(text) | Token::Number(text) => format!("'{text}'"),
+            Token::Lifetime => "lifetime".to_string(),
+            Token::Punct(punct) => format!("'{punct}'"),
+            Token::End => "end of input".to_string(),
+        }
+    }
+}
+
+const PUNCTUATION: &[&str] = &[
+    "=>", "->", "...", "<", ">", "[", "]", "(", ")", "{", "}", ",", "|", "&", ":", "?", ";",
+];
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Split a type string into tokens with their 1-based columns
+fn tokenize(input: &str, dialect: TypeDialect) -> Result<Vec<(Token, usize)>, TypeParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let error = |message: String, index: usize| TypeParseError {
+        message,
+        column: index + 1,
+        input: input.to_string(),
+    };
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if is_ident_start(c) {
+            // Names may be qualified (`user.User`, `chrono::DateTime`) and
+            // module names may contain dashes (`user-service.User`)
+            while i < chars.len() {
+                let next = chars.get(i + 1).copied();
+                if is_ident_char(chars[i]) {
+                    i += 1;
+                } else if (matches!(chars[i], '.' | '-') && next.is_some_and(is_ident_start))
+                    || (chars[i] == ':' && next == Some(':'))
+                {
+                    i += 2;
+                } else {
+                    break;
+                }
+            }
+            tokens.push((Token::Ident(chars[start..i].iter().collect()), start));
+            continue;
+        }
+
+        if c.is_ascii_digit() || (c == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit)) {
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || matches!(chars[i], '.' | '_')) {
+                i += 1;
+            }
+            tokens.push((Token::Number(chars[start..i].iter().collect()), start));
+            continue;
+        }
+
+        if c == '\'' && dialect == TypeDialect::Rust {
+            // Lifetimes (`'a`) have no closing quote
+            i += 1;
+            while i < chars.len() && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            tokens.push((Token::Lifetime, start));
+            continue;
+        }
+
+        if c == '`' && dialect == TypeDialect::TypeScript {
+            // Template literal types; placeholders may nest braces and strings
+            i += 1;
+            let mut depth = 0;
+            while i < chars.len() && (depth > 0 || chars[i] != '`') {
+                match chars[i] {
+                    '$' if chars.get(i + 1) == Some(&'{') => {
+                        depth += 1;
+                        i += 1;
+                    }
+                    '}' if depth > 0 => depth -= 1,
+                    _ => {}
+                }
+                i += 1;
+            }
+            if i == chars.len() {
+                return Err(error("Unterminated template literal".to_string(), start));
+            }
+            i += 1;
+            tokens.push((Token::Str(chars[start..i].iter().collect()), start));
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += 1;
+            }
+            if i == chars.len() {
+                return Err(error("Unterminated string literal".to_string(), start));
+            }
+            i += 1;
+            tokens.push((Token::Str(chars[start..i].iter().collect()), start));
+            continue;
+        }
+
+        let rest: String = chars[i..chars.len().min(i + 3)].iter().collect();
+        let Some(punct) = PUNCTUATION.iter().find(|punct| rest.starts_with(**punct)) else {
+            return Err(error(format!("Unexpected character '{c}'"), start));
+        };
+        i += punct.chars().count();
+        tokens.push((Token::Punct(punct), start));
+    }
+
+    tokens.push((Token::End, chars.len()));
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over the tokens of one type string
+struct TypeExprParser<'a> {
+    input: &'a str,
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    dialect: TypeDialect,
+}
+
+impl TypeExprParser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn peek_at(&self, offset: usize) -> &Token {
+        let index = (self.pos + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].0
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].0.clone();
+        if token != Token::End {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn at(&self, punct: &str) -> bool {
+        matches!(self.peek(), Token::Punct(p) if *p == punct)
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        let found = self.at(punct);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn error(&self, message: String) -> TypeParseError {
+        TypeParseError {
+            message,
+            column: self.tokens[self.pos].1 + 1,
+            input: self.input.to_string(),
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> TypeParseError {
+        self.error(format!(
+            "Expected {expected} but found {}",
+            self.peek().describe()
+        ))
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<(), TypeParseError> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("'{punct}'")))
+        }
+    }
+
+    fn parse_type(&mut self) -> Result<TypeReference, TypeParseError> {
+        self.eat("|");
+        let mut members = vec![self.parse_intersection()?];
+        while self.eat("|") {
+            members.push(self.parse_intersection()?);
+        }
+        Ok(self.union(members))
+    }
+
+    /// Fold union members, turning `null` members into `nullable`
+    fn union(&self, members: Vec<TypeReference>) -> TypeReference {
+        let (nulls, mut rest): (Vec<_>, Vec<_>) =
+            members.into_iter().partition(|member| self.is_null(member));
+        match (rest.len(), nulls.is_empty()) {
+            (0, _) => nulls.into_iter().next().expect("union has a member"),
+            (1, no_nulls) => {
+                let mut member = rest.remove(0);
+                member.nullable |= !no_nulls;
+                member
+            }
+            (_, no_nulls) => {
+                let mut union = TypeReference::composite(TypeKind::Union(rest));
+                union.nullable = !no_nulls;
+                union
+            }
+        }
+    }
+
+    fn is_null(&self, type_ref: &TypeReference) -> bool {
+        type_ref.kind == TypeKind::Named
+            && type_ref.type_args.is_empty()
+            && match self.dialect {
+                TypeDialect::Python => type_ref.base_type == "None",
+                TypeDialect::TypeScript | TypeDialect::Rust => {
+                    type_ref.base_type == "null" || type_ref.base_type == "undefined"
+                }
+            }
+    }
+
+    fn parse_intersection(&mut self) -> Result<TypeReference, TypeParseError> {
+        let first = self.parse_postfix()?;
+        // `&` starts a reference in Rust
+        if self.dialect == TypeDialect::Rust || !self.at("&") {
+            return Ok(first);
+        }
+        let mut members = vec![first];
+        while self.eat("&") {
+            members.push(self.parse_postfix()?);
+        }
+        Ok(TypeReference::composite(TypeKind::Intersection(members)))
+    }
+
+    fn parse_postfix(&mut self) -> Result<TypeReference, TypeParseError> {
+        let mut type_ref = self.parse_primary()?;
+        while self.at("[") {
+            if self.peek_at(1) == &Token::Punct("]") {
+                self.pos += 2;
+                type_ref = TypeReference::array(type_ref);
+            } else if self.dialect == TypeDialect::TypeScript {
+                // User['id']
+                self.next();
+                let index = self.parse_type()?;
+                self.expect("]")?;
+                type_ref = TypeReference::composite(TypeKind::IndexedAccess {
+                    object: Box::new(type_ref),
+                    index: Box::new(index),
+                });
+            } else {
+                break;
+            }
+        }
+        Ok(type_ref)
+    }
+
+    /// `keyof T`, `typeof value`, `readonly T[]` and `unique symbol`
+    fn parse_type_operator(&mut self) -> Option<Result<TypeReference, TypeParseError>> {
+        let Token::Ident(operator) = self.peek() else {
+            return None;
+        };
+        // A name followed by something that can't start a type is the type itself
+        let operand_follows = matches!(
+            self.peek_at(1),
+            Token::Ident(_) | Token::Punct("[") | Token::Punct("(")
+        );
+        if !operand_follows {
+            return None;
+        }
+        match operator.as_str() {
+            "keyof" => {
+                self.next();
+                Some(
+                    self.parse_postfix().map(|operand| {
+                        TypeReference::composite(TypeKind::KeyOf(Box::new(operand)))
+                    }),
+                )
+            }
+            "typeof" => {
+                self.next();
+                match self.next() {
+                    Token::Ident(value) => {
+                        Some(Ok(TypeReference::composite(TypeKind::TypeOf(value))))
+                    }
+                    _ => {
+                        self.pos -= 1;
+                        Some(Err(self.unexpected("a value name")))
+                    }
+                }
+            }
+            "readonly" | "unique" => {
+                self.next();
+                Some(self.parse_postfix())
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<TypeReference, TypeParseError> {
+        if self.dialect == TypeDialect::Rust {
+            if self.eat("&") {
+                // &'a mut T
+                if self.peek() == &Token::Lifetime {
+                    self.next();
+                }
+                if self.peek() == &Token::Ident("mut".to_string()) {
+                    self.next();
+                }
+                return self.parse_postfix();
+            }
+            if let Token::Ident(keyword) = self.peek() {
+                if matches!(keyword.as_str(), "dyn" | "impl")
+                    && matches!(self.peek_at(1), Token::Ident(_))
+                {
+                    self.next();
+                    return self.parse_postfix();
+                }
+            }
+        }
+
+        if self.dialect == TypeDialect::TypeScript {
+            if let Some(type_ref) = self.parse_type_operator() {
+                return type_ref;
+            }
+        }
+
+        match self.peek().clone() {
+            Token::Ident(name) => {
+                self.next();
+                if name == "true" || name == "false" {
+                    return Ok(TypeReference::composite(TypeKind::Literal(name)));
+                }
+                self.parse_named(name)
+            }
+            Token::Str(text) | Token::Number(text) => {
+                self.next();
+                Ok(TypeReference::composite(TypeKind::Literal(text)))
+            }
+            Token::Punct("...") if self.dialect == TypeDialect::Python => {
+                // tuple[int, ...]
+                self.next();
+                Ok(TypeReference::composite(TypeKind::Literal(
+                    "...".to_string(),
+                )))
+            }
+            Token::Punct("[") => self.parse_bracketed(),
+            Token::Punct("(") => self.parse_parenthesized(),
+            Token::Punct("{") => Err(self.error(
+                "Object literal types aren't supported; export an interface instead".to_string(),
+            )),
+            _ => Err(self.unexpected("a type")),
+        }
+    }
+
+    /// A name with optional type arguments
+    fn parse_named(&mut self, name: String) -> Result<TypeReference, TypeParseError> {
+        let close = match self.dialect {
+            TypeDialect::Python if self.at("[") && self.peek_at(1) != &Token::Punct("]") => "]",
+            TypeDialect::Python => return Ok(TypeReference::simple(name)),
+            _ if self.at("<") => ">",
+            _ => return Ok(TypeReference::simple(name)),
+        };
+        self.next();
+        if self.at(close) {
+            return Err(self.error(format!("Type '{name}' has an empty type argument list")));
+        }
+        let args = self.parse_list(close)?;
+
+        if self.dialect == TypeDialect::Python {
+            match name.as_str() {
+                "Optional" if args.len() == 1 => {
+                    let mut arg = args.into_iter().next().expect("one argument");
+                    arg.nullable = true;
+                    return Ok(arg);
+                }
+                "Union" => return Ok(self.union(args)),
+                _ => {}
+            }
+        }
+        Ok(TypeReference::generic(name, args))
+    }
+
+    /// Comma-separated types up to the closing `close`, which is consumed
+    fn parse_list(&mut self, close: &str) -> Result<Vec<TypeReference>, TypeParseError> {
+        let mut types = Vec::new();
+        while !self.eat(close) {
+            types.push(self.parse_type()?);
+            if !self.eat(",") {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Ok(types)
+    }
+
+    /// `[A, B]` tuples, or `[T]` / `[T; N]` arrays in Rust
+    fn parse_bracketed(&mut self) -> Result<TypeReference, TypeParseError> {
+        self.expect("[")?;
+        if self.dialect == TypeDialect::Rust {
+            let items = self.parse_type()?;
+            if self.eat(";") && matches!(self.peek(), Token::Number(_) | Token::Ident(_)) {
+                self.next();
+            }
+            self.expect("]")?;
+            return Ok(TypeReference::array(items));
+        }
+        let members = self.parse_list("]")?;
+        Ok(TypeReference::composite(TypeKind::Tuple(members)))
+    }
+
+    /// Parenthesized types, function types, and Rust tuples and `()`
+    fn parse_parenthesized(&mut self) -> Result<TypeReference, TypeParseError> {
+        if self.dialect == TypeDialect::Rust {
+            self.expect("(")?;
+            if self.eat(")") {
+                return Ok(TypeReference::simple("()"));
+            }
+            let first = self.parse_type()?;
+            if self.eat(")") {
+                return Ok(first);
+            }
+            self.expect(",")?;
+            let mut members = vec![first];
+            members.extend(self.parse_list(")")?);
+            return Ok(TypeReference::composite(TypeKind::Tuple(members)));
+        }
+
+        if !self.is_function_ahead() {
+            self.expect("(")?;
+            let inner = self.parse_type()?;
+            self.expect(")")?;
+            return Ok(inner);
+        }
+
+        self.expect("(")?;
+        let mut params = Vec::new();
+        while !self.eat(")") {
+            self.eat("...");
+            match self.next() {
+                Token::Ident(_) => {}
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.unexpected("a parameter name"));
+                }
+            }
+            self.eat("?");
+            self.expect(":")?;
+            params.push(self.parse_type()?);
+            if !self.eat(",") {
+                self.expect(")")?;
+                break;
+            }
+        }
+        self.expect("=>")?;
+        let returns = Box::new(self.parse_type()?);
+        Ok(TypeReference::composite(TypeKind::Function {
+            params,
+            returns,
+        }))
+    }
+
+    /// Whether the `(` at the current token opens a function's parameters
+    fn is_function_ahead(&self) -> bool {
+        let mut depth = 0;
+        for (offset, (token, _)) in self.tokens[self.pos..].iter().enumerate() {
+            match token {
+                Token::Punct("(") => depth += 1,
+                Token::Punct(")") => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return self.peek_at(offset + 1) == &Token::Punct("=>");
+                    }
+                }
+                Token::End => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
 /// Type validator - validates types against available modules
 pub struct TypeValidator {
     builtin_registry: BuiltInTypeRegistry,
@@ -365,13 +1139,43 @@ pub struct TypeValidator {
 }
 
 impl TypeValidator {
-    /// Create a new type validator
+    /// Create a new type validator for TypeScript types
     pub fn new() -> Self {
+        Self::for_dialect(TypeDialect::TypeScript)
+    }
+
+    /// Create a type validator for the types and syntax of a dialect
+    pub fn for_dialect(dialect: TypeDialect) -> Self {
         Self {
-            builtin_registry: BuiltInTypeRegistry::new(),
-            generic_registry: GenericTypeRegistry::new(),
-            parser: TypeParser::new(),
+            builtin_registry: BuiltInTypeRegistry::for_dialect(dialect),
+            generic_registry: GenericTypeRegistry::for_dialect(dialect),
+            parser: TypeParser::for_dialect(dialect),
+        }
+    }
+
+    /// Create a type validator for the dialect of the project's language,
+    /// accepting the external types declared in its manifest
+    pub fn for_project(project: &Project) -> Self {
+        Self::for_dialect(TypeDialect::for_project(project)).with_external_types(
+            project
+                .manifest
+                .external_types
+                .iter()
+                .map(|external| external.name.clone()),
+        )
+    }
+
+    /// Accept types defined outside the architecture (`chrono::DateTime`,
+    /// `express.Request`) as opaque built-ins
+    pub fn with_external_types<I, S>(mut self, type_names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for type_name in type_names {
+            self.builtin_registry.register(type_name);
         }
+        self
     }
 
     /// Validate that a type exists in the available modules or is built-in
@@ -380,8 +1184,52 @@ impl TypeValidator {
         type_ref: &TypeReference,
         modules: &[Module],
     ) -> Result<(), String> {
-        // Check if it's a built-in type
-        if self.builtin_registry.is_builtin(&type_ref.base_type) {
+        self.validate_type_in_scope(type_ref, &[], modules)
+    }
+
+    /// Validate a type inside a generic export, where its type parameters
+    /// (`T` in `Repository<T>`) are in scope
+    pub fn validate_type_in_scope(
+        &self,
+        type_ref: &TypeReference,
+        type_params: &[TypeParam],
+        modules: &[Module],
+    ) -> Result<(), String> {
+        // Check the members of composite types
+        match &type_ref.kind {
+            TypeKind::Named => {}
+            TypeKind::Union(members)
+            | TypeKind::Intersection(members)
+            | TypeKind::Tuple(members) => {
+                for member in members {
+                    self.validate_type_in_scope(member, type_params, modules)?;
+                }
+                return Ok(());
+            }
+            TypeKind::Literal(_) | TypeKind::TypeOf(_) => return Ok(()),
+            TypeKind::Function { params, returns } => {
+                for param in params {
+                    self.validate_type_in_scope(param, type_params, modules)?;
+                }
+                return self.validate_type_in_scope(returns, type_params, modules);
+            }
+            TypeKind::KeyOf(operand) => {
+                return self.validate_type_in_scope(operand, type_params, modules);
+            }
+            TypeKind::IndexedAccess { object, index } => {
+                self.validate_type_in_scope(object, type_params, modules)?;
+                return self.validate_type_in_scope(index, type_params, modules);
+            }
+        }
+
+        // Check if it's a type parameter in scope
+        if type_params.iter().any(|p| p.name == type_ref.base_type) {
+            if !type_ref.type_args.is_empty() {
+                return Err(format!(
+                    "Type parameter '{}' can't take type arguments",
+                    type_ref.base_type
+                ));
+            }
             return Ok(());
         }
 
@@ -389,19 +1237,54 @@ impl TypeValidator {
         if self.generic_registry.is_generic(&type_ref.base_type) {
             return self
                 .generic_registry
-                .validate_generic(type_ref, modules, self);
+                .validate_generic(type_ref, type_params, modules, self);
+        }
+
+        // Check if it's a built-in type
+        if self.builtin_registry.is_builtin(&type_ref.base_type) {
+            return Ok(());
         }
 
         // Handle array types
         if type_ref.base_type == "array" {
             if let Some(items) = &type_ref.items {
-                return self.validate_type_exists(items, modules);
+                return self.validate_type_in_scope(items, type_params, modules);
             }
             return Err("Array type must specify items".to_string());
         }
 
         // Check module exports
-        self.validate_module_type(&type_ref.base_type, modules)
+        self.validate_module_type(&type_ref.base_type, modules)?;
+        let Some(export) = find_export(&type_ref.base_type, modules) else {
+            return Ok(());
+        };
+
+        // Check type arguments against the export's type parameters
+        if type_ref.type_args.len() != export.type_params.len() {
+            if export.type_params.is_empty() {
+                return Err(format!(
+                    "Type '{}' is not generic but got {} type argument(s)",
+                    type_ref.base_type,
+                    type_ref.type_args.len()
+                ));
+            }
+            let names: Vec<&str> = export.type_params.iter().map(|p| p.name.as_str()).collect();
+            return Err(format!(
+                "Type '{}<{}>' expects {} type argument(s), got {}",
+                type_ref.base_type,
+                names.join(", "),
+                names.len(),
+                type_ref.type_args.len()
+            ));
+        }
+        for (arg, param) in type_ref.type_args.iter().zip(&export.type_params) {
+            self.validate_type_in_scope(arg, type_params, modules)?;
+            if let Some(bound) = &param.bound {
+                check_bound(arg, param, bound, type_params, modules)?;
+            }
+        }
+
+        Ok(())
     }
 
     /// Validate a type string (helper method)
@@ -418,6 +1301,17 @@ impl TypeValidator {
         self.validate_type_exists(&type_ref, modules)
     }
 
+    /// Validate a type string with the given type parameters in scope
+    pub fn validate_type_string_in_scope(
+        &self,
+        type_str: &str,
+        type_params: &[TypeParam],
+        modules: &[Module],
+    ) -> Result<(), String> {
+        let type_ref = self.parser.parse(type_str)?;
+        self.validate_type_in_scope(&type_ref, type_params, modules)
+    }
+
     /// Validate that a type exists in module exports
     fn validate_module_type(&self, type_name: &str, modules: &[Module]) -> Result<(), String> {
         // Handle module-qualified types (module.Type)
@@ -486,6 +1380,383 @@ impl Default for TypeValidator {
     }
 }
 
+/// Expands the `type` exports that alias another type expression
+///
+/// `type UserId = string` and `type Paged<T> = Page<T> | null` are replaced by
+/// what they stand for, with the alias's type parameters substituted by the
+/// arguments it's used with. An alias is expanded at most once per path, so a
+/// recursive alias (`type Tree = Tree[]`) keeps referring to itself.
+pub struct AliasResolver<'a> {
+    modules: &'a [Module],
+    parser: TypeParser,
+}
+
+impl<'a> AliasResolver<'a> {
+    /// Create a resolver for aliases written in a dialect's syntax
+    pub fn new(modules: &'a [Module], dialect: TypeDialect) -> Self {
+        Self {
+            modules,
+            parser: TypeParser::for_dialect(dialect),
+        }
+    }
+
+    /// Create a resolver for the modules and language of a project
+    pub fn for_project(project: &'a Project) -> Self {
+        Self::new(&project.modules, TypeDialect::for_project(project))
+    }
+
+    /// Expand the aliases in a type reference; names an alias brings in are
+    /// qualified by the module exporting them
+    pub fn resolve(&self, type_ref: &TypeReference) -> TypeReference {
+        self.expand(type_ref, &mut Vec::new())
+    }
+
+    /// Parse a type string and expand the aliases in it
+    pub fn resolve_str(&self, type_str: &str) -> Result<TypeReference, TypeParseError> {
+        Ok(self.resolve(&self.parser.parse(type_str)?))
+    }
+
+    /// Expand the aliases in a type reference written in `module`, with every
+    /// exported name qualified by the module exporting it
+    pub fn resolve_in(&self, module: &Module, type_ref: &TypeReference) -> TypeReference {
+        self.resolve(&self.qualify(&module.module, type_ref))
+    }
+
+    /// Whether two type strings stand for the same type once aliases are
+    /// expanded, ignoring module qualifiers
+    pub fn equivalent(&self, a: &str, b: &str) -> bool {
+        match (self.resolve_str(a), self.resolve_str(b)) {
+            (Ok(a), Ok(b)) => without_qualifiers(&a) == without_qualifiers(&b),
+            _ => false,
+        }
+    }
+
+    /// Properties of the object type a reference resolves to: an interface,
+    /// class or `type` with properties, or an intersection of them
+    pub fn properties(&self, type_ref: &TypeReference) -> Option<HashMap<&'a str, &'a Property>> {
+        self.shape_properties(&self.resolve(type_ref))
+    }
+
+    /// Cycles of `type` exports that can't be expanded to a finite type
+    ///
+    /// An alias may only refer back to itself through array items, type
+    /// arguments, tuples or function types; a `type` with properties only
+    /// through properties that are optional, nullable or unions. Aliases that
+    /// refer to each other are reported together: one shortest cycle per group,
+    /// as qualified names starting at the smallest one.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut edges: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for module in self.modules {
+            for (export_name, export) in &module.exports {
+                if export.export_type != ExportType::Type {
+                    continue;
+                }
+
+                let mut names = Vec::new();
+                let mut types = Vec::new();
+                match &export.alias {
+                    Some(alias) => types.extend(self.parser.parse(alias).ok()),
+                    None => types.extend(
+                        export
+                            .properties
+                            .iter()
+                            .flat_map(|properties| properties.values())
+                            .filter(|prop| prop.required)
+                            .filter_map(|prop| self.parser.parse(&prop.prop_type).ok()),
+                    ),
+                }
+                for type_ref in &types {
+                    required_references(type_ref, export.alias.is_some(), &mut names);
+                }
+
+                let mut targets: Vec<String> = names
+                    .into_iter()
+                    .filter_map(|name| find_qualified_export(name, self.modules))
+                    .filter(|(_, target)| target.export_type == ExportType::Type)
+                    .map(|(key, _)| key)
+                    .collect();
+                targets.sort();
+                targets.dedup();
+                edges.insert(format!("{}.{export_name}", module.module), targets);
+            }
+        }
+
+        cyclic_components_of(&edges)
+            .iter()
+            .filter_map(|component| shortest_cycle(component, &edges))
+            .collect()
+    }
+
+    /// Qualified name, export and parsed target of the alias a name refers to
+    fn alias(&self, type_name: &str) -> Option<(String, &'a Export, TypeReference)> {
+        let (key, export) = find_qualified_export(type_name, self.modules)?;
+        if export.export_type != ExportType::Type {
+            return None;
+        }
+        let target = self.parser.parse(export.alias.as_deref()?).ok()?;
+        Some((key, export, target))
+    }
+
+    fn expand(&self, type_ref: &TypeReference, expanding: &mut Vec<String>) -> TypeReference {
+        if type_ref.kind == TypeKind::Named {
+            if let Some((key, export, target)) = self.alias(&type_ref.base_type) {
+                if !expanding.contains(&key) {
+                    let args: HashMap<&str, &TypeReference> = export
+                        .type_params
+                        .iter()
+                        .map(|param| param.name.as_str())
+                        .zip(&type_ref.type_args)
+                        .collect();
+
+                    // Names in the alias are relative to the module declaring it
+                    let alias_module = key.split_once('.').map_or("", |(module, _)| module);
+                    let target = self.qualify(alias_module, &substitute(&target, &args));
+
+                    expanding.push(key);
+                    let mut resolved = self.expand(&target, expanding);
+                    expanding.pop();
+
+                    resolved.nullable |= type_ref.nullable;
+                    return resolved;
+                }
+            }
+        }
+
+        flatten(map_children(type_ref, |child| {
+            self.expand(child, expanding)
+        }))
+    }
+
+    /// Type reference with each unqualified name that a module exports
+    /// qualified by it, preferring the exports of `module_name`
+    fn qualify(&self, module_name: &str, type_ref: &TypeReference) -> TypeReference {
+        let mut qualified = map_children(type_ref, |child| self.qualify(module_name, child));
+        if qualified.kind == TypeKind::Named && !qualified.base_type.contains('.') {
+            let exporting = self
+                .modules
+                .iter()
+                .find(|m| m.module == module_name && m.exports.contains_key(&qualified.base_type))
+                .or_else(|| {
+                    self.modules
+                        .iter()
+                        .find(|m| m.exports.contains_key(&qualified.base_type))
+                });
+            if let Some(exporting) = exporting {
+                qualified.base_type = format!("{}.{}", exporting.module, qualified.base_type);
+            }
+        }
+        qualified
+    }
+
+    fn shape_properties(&self, type_ref: &TypeReference) -> Option<HashMap<&'a str, &'a Property>> {
+        match &type_ref.kind {
+            TypeKind::Named => {
+                let (_, export) = find_qualified_export(&type_ref.base_type, self.modules)?;
+                if export.alias.is_some() {
+                    // A recursive alias that wasn't expanded any further
+                    return None;
+                }
+                let properties = export.properties.as_ref()?;
+                Some(
+                    properties
+                        .iter()
+                        .map(|(name, prop)| (name.as_str(), prop))
+                        .collect(),
+                )
+            }
+            TypeKind::Intersection(members) => {
+                let mut properties = HashMap::new();
+                for member in members {
+                    properties.extend(self.shape_properties(member)?);
+                }
+                Some(properties)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Copy of a type reference with `f` applied to each of its direct children
+fn map_children(
+    type_ref: &TypeReference,
+    mut f: impl FnMut(&TypeReference) -> TypeReference,
+) -> TypeReference {
+    let kind = match &type_ref.kind {
+        TypeKind::Named | TypeKind::Literal(_) | TypeKind::TypeOf(_) => type_ref.kind.clone(),
+        TypeKind::Union(members) => TypeKind::Union(members.iter().map(&mut f).collect()),
+        TypeKind::Intersection(members) => {
+            TypeKind::Intersection(members.iter().map(&mut f).collect())
+        }
+        TypeKind::Tuple(members) => TypeKind::Tuple(members.iter().map(&mut f).collect()),
+        TypeKind::Function { params, returns } => TypeKind::Function {
+            params: params.iter().map(&mut f).collect(),
+            returns: Box::new(f(returns)),
+        },
+        TypeKind::KeyOf(operand) => TypeKind::KeyOf(Box::new(f(operand))),
+        TypeKind::IndexedAccess { object, index } => TypeKind::IndexedAccess {
+            object: Box::new(f(object)),
+            index: Box::new(f(index)),
+        },
+    };
+    let items = type_ref.items.as_deref().map(|items| Box::new(f(items)));
+    let type_args = type_ref.type_args.iter().map(&mut f).collect();
+
+    TypeReference {
+        base_type: type_ref.base_type.clone(),
+        nullable: type_ref.nullable,
+        items,
+        type_args,
+        kind,
+    }
+}
+
+/// Merge unions nested in a union, and intersections nested in an
+/// intersection, as left behind by expanding aliases
+fn flatten(mut type_ref: TypeReference) -> TypeReference {
+    match &mut type_ref.kind {
+        TypeKind::Union(members) => {
+            let mut flat = Vec::new();
+            for member in members.drain(..) {
+                match member.kind {
+                    TypeKind::Union(nested) => {
+                        type_ref.nullable |= member.nullable;
+                        flat.extend(nested);
+                    }
+                    _ => flat.push(member),
+                }
+            }
+            type_ref.kind = TypeKind::Union(flat);
+        }
+        TypeKind::Intersection(members) => {
+            let mut flat = Vec::new();
+            for member in members.drain(..) {
+                match member.kind {
+                    TypeKind::Intersection(nested) if !member.nullable => flat.extend(nested),
+                    _ => flat.push(member),
+                }
+            }
+            type_ref.kind = TypeKind::Intersection(flat);
+        }
+        _ => {}
+    }
+    type_ref
+}
+
+/// Replace the type parameters of an alias or trait by their arguments
+pub(crate) fn substitute(
+    type_ref: &TypeReference,
+    args: &HashMap<&str, &TypeReference>,
+) -> TypeReference {
+    if type_ref.kind == TypeKind::Named && type_ref.type_args.is_empty() {
+        if let Some(arg) = args.get(type_ref.base_type.as_str()) {
+            let mut arg = (*arg).clone();
+            arg.nullable |= type_ref.nullable;
+            return arg;
+        }
+    }
+    map_children(type_ref, |child| substitute(child, args))
+}
+
+/// Type reference with module qualifiers dropped from every name
+fn without_qualifiers(type_ref: &TypeReference) -> TypeReference {
+    let mut unqualified_ref = map_children(type_ref, without_qualifiers);
+    if unqualified_ref.kind == TypeKind::Named {
+        unqualified_ref.base_type = unqualified(&type_ref.base_type).to_string();
+    }
+    unqualified_ref
+}
+
+/// Names a value of the type can't be built without
+///
+/// Array items, type arguments, tuples and functions never count. Nullable
+/// types and union members only count in an alias (`type A = B | C` is
+/// circular if `B` is `A`), not in a property, which can end with another
+/// member.
+fn required_references<'t>(type_ref: &'t TypeReference, in_alias: bool, names: &mut Vec<&'t str>) {
+    if type_ref.nullable && !in_alias {
+        return;
+    }
+    match &type_ref.kind {
+        TypeKind::Named if type_ref.base_type != "array" => names.push(&type_ref.base_type),
+        TypeKind::Union(members) if in_alias => {
+            for member in members {
+                required_references(member, in_alias, names);
+            }
+        }
+        TypeKind::Intersection(members) => {
+            for member in members {
+                required_references(member, in_alias, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Export a type name refers to: `module.Type`, or the first module exporting
+/// an unqualified `Type`
+fn find_export<'a>(type_name: &str, modules: &'a [Module]) -> Option<&'a Export> {
+    find_qualified_export(type_name, modules).map(|(_, export)| export)
+}
+
+/// [`find_export`], along with the export's qualified `module.Type` name
+fn find_qualified_export<'a>(
+    type_name: &str,
+    modules: &'a [Module],
+) -> Option<(String, &'a Export)> {
+    match type_name.split_once('.') {
+        Some((module_name, export_name)) => {
+            let module = modules.iter().find(|m| m.module == module_name)?;
+            let export = module.exports.get(export_name)?;
+            Some((type_name.to_string(), export))
+        }
+        None => modules.iter().find_map(|m| {
+            let export = m.exports.get(type_name)?;
+            Some((format!("{}.{type_name}", m.module), export))
+        }),
+    }
+}
+
+/// Name without its module qualifier
+fn unqualified(type_name: &str) -> &str {
+    type_name.rsplit('.').next().unwrap_or(type_name)
+}
+
+/// Check that a type argument is, or implements, the bound of its parameter
+fn check_bound(
+    arg: &TypeReference,
+    param: &TypeParam,
+    bound: &str,
+    type_params: &[TypeParam],
+    modules: &[Module],
+) -> Result<(), String> {
+    let satisfied = match type_params.iter().find(|p| p.name == arg.base_type) {
+        // A type parameter satisfies the bound through its own bound
+        Some(scoped) => scoped
+            .bound
+            .as_deref()
+            .is_some_and(|own| unqualified(own) == unqualified(bound)),
+        None => {
+            unqualified(&arg.base_type) == unqualified(bound)
+                || find_export(&arg.base_type, modules).is_some_and(|export| {
+                    export.implements.iter().any(|implemented| {
+                        // `implements` may pass type arguments to a generic trait
+                        let name = implemented.split(['<', '[']).next().unwrap_or(implemented);
+                        unqualified(name.trim()) == unqualified(bound)
+                    })
+                })
+        }
+    };
+
+    if satisfied {
+        Ok(())
+    } else {
+        Err(format!(
+            "Type argument '{}' doesn't satisfy the bound '{bound}' of type parameter '{}'",
+            arg.base_type, param.name
+        ))
+    }
+}
+
 // Convenience functions for testing
 
 pub fn is_builtin_type(type_name: &str) -> bool {
@@ -496,7 +1767,7 @@ pub fn is_generic_type(type_name: &str) -> bool {
     GenericTypeRegistry::new().is_generic(type_name)
 }
 
-pub fn parse_type_string(type_str: &str) -> Result<TypeReference, String> {
+pub fn parse_type_string(type_str: &str) -> Result<TypeReference, TypeParseError> {
     TypeParser::new().parse(type_str)
 }
 
@@ -552,6 +1823,34 @@ mod tests {
         assert_eq!(items.base_type, "Patient");
     }
 
+    #[test]
+    fn test_referenced_names() {
+        let parser = TypeParser::new();
+        let result = parser.parse("Map<string, billing.Invoice[]>").unwrap();
+        assert_eq!(
+            result.referenced_names(),
+            vec!["Map", "string", "billing.Invoice"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_qualified_names() {
+        let tokens = tokenize("user-service.User<chrono::DateTime>[]", TypeDialect::Rust).unwrap();
+        let tokens: Vec<_> = tokens.into_iter().map(|(token, _)| token).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Ident("user-service.User".to_string()),
+                Token::Punct("<"),
+                Token::Ident("chrono::DateTime".to_string()),
+                Token::Punct(">"),
+                Token::Punct("["),
+                Token::Punct("]"),
+                Token::End,
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_nested_array() {
         let parser = TypeParser::new();
@@ -562,4 +1861,29 @@ mod tests {
         let nested = items.items.unwrap();
         assert_eq!(nested.base_type, "Patient");
     }
+
+    #[test]
+    fn test_generic_alias_resolved() {
+        let project = crate::testing::TestProject::new("typescript")
+            .module(
+                r#"{"module": "domain", "version": "1.0.0", "dependencies": {}, "exports": {
+                    "Page": {"type": "interface", "properties": {"total": {"type": "number"}}},
+                    "Paged": {"type": "type", "type_params": [{"name": "T"}], "alias": "T[] | Page"},
+                    "Users": {"type": "type", "alias": "Paged<string> | null"}
+                }}"#,
+            )
+            .build();
+
+        let resolved = AliasResolver::for_project(&project)
+            .resolve_str("Users")
+            .unwrap();
+
+        assert!(resolved.nullable);
+        let TypeKind::Union(members) = &resolved.kind else {
+            panic!("expected a union, got {resolved:?}");
+        };
+        assert_eq!(members[0].base_type, "array");
+        assert_eq!(members[0].items.as_ref().unwrap().base_type, "string");
+        assert_eq!(members[1].base_type, "domain.Page");
+    }
 }
@@ -1,5 +1,6 @@
 //! Core type definitions matching the Crucible specification
 
+use crate::source_map::SourceMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -12,6 +13,20 @@ pub struct Manifest {
     pub strict_validation: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
+    /// Types defined outside the architecture (third-party libraries)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_types: Vec<ExternalType>,
+}
+
+/// Opaque type defined outside the architecture, such as `express.Request` or
+/// `chrono::DateTime`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExternalType {
+    /// Name as it's used in type strings
+    pub name: String,
+    /// Package the type is imported from; types without one are ambient
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,7 +47,7 @@ pub enum Language {
     Java,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ArchitecturePattern {
     Layered,
@@ -47,11 +62,37 @@ pub struct Module {
     pub version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub layer: Option<String>,
+    /// Role of the module in a hexagonal (ports & adapters) architecture
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<ModuleRole>,
+    /// Service the module is deployed in (microservices architecture)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Whether the module holds contracts shared between services
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub contracts: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub exports: HashMap<String, Export>,
     #[serde(default)]
     pub dependencies: HashMap<String, String>,
+    /// Accepted validation issues for this module and everything in it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppress: Vec<Suppression>,
+}
+
+/// Role of a module in a hexagonal (ports & adapters) architecture
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModuleRole {
+    /// Core business logic, independent of infrastructure
+    Domain,
+    /// Interfaces through which the domain talks to the outside world
+    Port,
+    /// Infrastructure implementing ports (databases, HTTP, messaging)
+    Adapter,
+    /// Use cases orchestrating the domain through ports
+    Application,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +110,34 @@ pub struct Export {
     /// Payload for event types - defines the data carried by the event
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payload: Option<HashMap<String, Property>>,
+    /// Whether other services may call this export (microservices architecture)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub public_api: bool,
+    /// Whether the export is an entry point into the system (controller, CLI
+    /// handler, ...) that is used from outside the architecture
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub entry_point: bool,
+    /// Traits and interfaces this class implements (`module.Trait`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<String>,
+    /// Generic type parameters, e.g. `T` in `Repository<T>`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<TypeParam>,
+    /// Type expression a `type` export stands for (`type UserId = string`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// Accepted validation issues for this export and its members
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppress: Vec<Suppression>,
+}
+
+/// Generic type parameter of an export
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TypeParam {
+    pub name: String,
+    /// Type every argument must be or implement (`T extends Entity`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bound: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -91,16 +160,37 @@ pub struct Method {
     pub returns: ReturnType,
     #[serde(default)]
     pub throws: Vec<String>,
+    /// Error types thrown by callees that this method handles instead of re-throwing
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub handles: Vec<String>,
     #[serde(default)]
     pub calls: Vec<String>,
     #[serde(default)]
     pub effects: Vec<String>,
+    /// Events the method publishes (`module.Event`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emits: Vec<String>,
+    /// Events the method consumes (`module.Event`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subscribes: Vec<String>,
     /// Whether the method is async (for Rust traits, TypeScript async functions, etc.)
     #[serde(default, rename = "async")]
     pub is_async: bool,
     /// Compliance and metadata annotations (e.g., @requires-auth, @audit-log, @rate-limited)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub annotations: Vec<String>,
+    /// Accepted validation issues for this method
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppress: Vec<Suppression>,
+}
+
+/// Accepts a known validation issue instead of disabling its rule project-wide
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Suppression {
+    /// Id of the suppressed rule
+    pub rule: String,
+    /// Why the issue is accepted
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -157,6 +247,8 @@ pub struct Project {
     pub manifest: Manifest,
     pub modules: Vec<Module>,
     pub rules: Option<Rules>,
+    /// Positions of definitions in the source files, filled in by the parser
+    pub source_map: SourceMap,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -166,6 +258,38 @@ pub struct Rules {
     pub rules: Vec<Rule>,
     #[serde(default)]
     pub custom_rules: Vec<CustomRule>,
+    /// Effect vocabulary allowed in each layer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effects: Option<EffectPolicy>,
+    /// Limits on module metrics reported by metrics-within-thresholds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<MetricThresholds>,
+}
+
+/// Which effects methods in each layer may perform
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EffectPolicy {
+    /// Allowed effects by layer name; `category.*` allows a whole category.
+    /// Layers not listed here are unrestricted.
+    #[serde(default)]
+    pub layers: HashMap<String, Vec<String>>,
+}
+
+/// Upper limits on module metrics; unset limits aren't checked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricThresholds {
+    /// Most modules that may depend on one module
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_afferent_coupling: Option<usize>,
+    /// Most modules one module may depend on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_efferent_coupling: Option<usize>,
+    /// Highest instability, from 0 to 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_instability: Option<f64>,
+    /// Highest distance from the main sequence, from 0 to 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -206,6 +330,11 @@ pub struct CustomRule {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
     pub severity: Severity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Rule-type specific settings (e.g., `{"max": 5}` for `max-dependencies`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub config: HashMap<String, serde_json::Value>,
 }
 
 // ============================================================================
@@ -322,6 +451,7 @@ mod tests {
                 repository: Some("https://github.com/test/repo".to_string()),
                 created: Some("2025-01-01T00:00:00Z".to_string()),
             }),
+            external_types: Vec::new(),
         };
 
         let json = serde_json::to_string(&manifest).unwrap();
@@ -391,6 +521,12 @@ mod tests {
                 values: None,
                 dependencies: None,
                 payload: None,
+                suppress: Vec::new(),
+                public_api: false,
+                entry_point: false,
+                implements: Vec::new(),
+                type_params: Vec::new(),
+                alias: None,
             },
         );
 
@@ -404,11 +540,18 @@ mod tests {
             description: Some("Test module".to_string()),
             exports,
             dependencies,
+            suppress: Vec::new(),
+            role: None,
+            service: None,
+            contracts: false,
         };
 
         let json = serde_json::to_string(&module).unwrap();
         let deserialized: Module = serde_json::from_str(&json).unwrap();
 
+        assert!(!json.contains("contracts"));
+        assert!(!json.contains("public_api"));
+        assert!(!json.contains("entry_point"));
         assert_eq!(module.module, deserialized.module);
         assert_eq!(module.version, deserialized.version);
         assert_eq!(module.layer, deserialized.layer);
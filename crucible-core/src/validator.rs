@@ -1,8 +1,10 @@
 //! Architecture validation engine
 
-use crate::types::{ExportType, Project, ReturnType, Severity};
-use petgraph::algo::is_cyclic_directed;
-use petgraph::graph::DiGraph;
+use crate::custom_rules::CustomRuleEngine;
+use crate::rules::{RuleRegistry, ValidationRule};
+use crate::source_map::SourceLocation;
+use crate::suppression::{apply_suppressions, SuppressedIssue, UNUSED_SUPPRESSION};
+use crate::types::{Project, Rule, Severity};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
@@ -14,10 +16,33 @@ pub struct ValidationResult {
     pub errors: Vec<ValidationIssue>,
     pub warnings: Vec<ValidationIssue>,
     pub info: Vec<ValidationIssue>,
+    /// Issues accepted by inline `suppress` entries
+    pub suppressed: Vec<SuppressedIssue>,
     /// Modules that were actually validated (for incremental validation)
     pub validated_modules: Vec<String>,
 }
 
+impl ValidationResult {
+    /// Record an issue, routing it by severity
+    pub fn add_issue(&mut self, issue: ValidationIssue) {
+        match issue.severity {
+            Severity::Error => {
+                self.valid = false;
+                self.errors.push(issue);
+            }
+            Severity::Warning => self.warnings.push(issue),
+            Severity::Info => self.info.push(issue),
+        }
+    }
+
+    /// Record several issues, routing each by severity
+    pub fn add_issues(&mut self, issues: impl IntoIterator<Item = ValidationIssue>) {
+        for issue in issues {
+            self.add_issue(issue);
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ValidationIssue {
     pub rule: String,
@@ -32,6 +57,8 @@ pub struct ValidationIssue {
     pub suggestion: Option<String>,
     /// Link to relevant documentation
     pub doc_link: Option<String>,
+    /// File position of `location`, resolved by the validator
+    pub source: Option<SourceLocation>,
 }
 
 impl ValidationIssue {
@@ -51,6 +78,7 @@ impl ValidationIssue {
             expected: None,
             suggestion: None,
             doc_link: None,
+            source: None,
         }
     }
 
@@ -72,6 +100,7 @@ impl ValidationIssue {
             expected: Some(expected),
             suggestion: None,
             doc_link: None,
+            source: None,
         }
     }
 
@@ -86,6 +115,12 @@ impl ValidationIssue {
         self.doc_link = Some(doc_link);
         self
     }
+
+    /// Set the file position explicitly instead of resolving it from `location`
+    pub fn with_source(mut self, source: SourceLocation) -> Self {
+        self.source = Some(source);
+        self
+    }
 }
 
 /// Tracks module changes for incremental validation
@@ -193,6 +228,8 @@ pub struct Validator {
     project: Project,
     /// Optional change tracker for incremental validation
     change_tracker: Option<ChangeTracker>,
+    /// Rules run by `validate`, starting with the built-in rules
+    registry: RuleRegistry,
 }
 
 impl Validator {
@@ -200,6 +237,7 @@ impl Validator {
         Self {
             project,
             change_tracker: None,
+            registry: RuleRegistry::with_builtin_rules(),
         }
     }
 
@@ -211,9 +249,25 @@ impl Validator {
         Self {
             project,
             change_tracker: Some(change_tracker),
+            registry: RuleRegistry::with_builtin_rules(),
         }
     }
 
+    /// Register an additional rule, replacing any rule with the same id
+    pub fn register_rule(&mut self, rule: impl ValidationRule + 'static) {
+        self.registry.register(rule);
+    }
+
+    /// Rules run by this validator
+    pub fn registry(&self) -> &RuleRegistry {
+        &self.registry
+    }
+
+    /// Mutable access to the rule registry (e.g., to remove built-in rules)
+    pub fn registry_mut(&mut self) -> &mut RuleRegistry {
+        &mut self.registry
+    }
+
     /// Perform incremental validation - only validate changed modules and their dependents
     pub fn incremental_validate(&mut self, root_path: &Path) -> ValidationResult {
         // Extract data from tracker to avoid borrow issues
@@ -233,6 +287,7 @@ impl Validator {
                         "No modules changed since last validation".to_string(),
                         None,
                     )],
+                    suppressed: Vec::new(),
                     validated_modules: Vec::new(),
                 };
             }
@@ -248,7 +303,7 @@ impl Validator {
         let filtered_project = self.filter_project_modules(&affected_modules);
 
         // Run validation on filtered project
-        let mut result = self.validate_filtered(&filtered_project);
+        let mut result = self.validate_project(&filtered_project);
 
         // Add info about what was validated
         result.info.insert(
@@ -289,888 +344,373 @@ impl Validator {
             manifest: self.project.manifest.clone(),
             modules: filtered_modules,
             rules: self.project.rules.clone(),
+            source_map: self.project.source_map.clone(),
         }
     }
 
-    /// Validate a filtered project (internal use for incremental validation)
-    fn validate_filtered(&self, project: &Project) -> ValidationResult {
-        let temp_validator = Validator::new(project.clone());
-        temp_validator.validate()
+    /// Run all registered rules
+    pub fn validate(&self) -> ValidationResult {
+        self.validate_project(&self.project)
     }
 
-    /// Run all validation rules
-    pub fn validate(&self) -> ValidationResult {
+    /// Run all registered rules against the given project
+    fn validate_project(&self, project: &Project) -> ValidationResult {
         let mut result = ValidationResult {
             valid: true,
             errors: Vec::new(),
             warnings: Vec::new(),
             info: Vec::new(),
-            validated_modules: self
-                .project
-                .modules
-                .iter()
-                .map(|m| m.module.clone())
-                .collect(),
+            suppressed: Vec::new(),
+            validated_modules: project.modules.iter().map(|m| m.module.clone()).collect(),
         };
 
-        // Check for circular dependencies
-        if let Some(issues) = self.check_circular_dependencies() {
-            for issue in issues {
-                match issue.severity {
-                    Severity::Error => {
-                        result.valid = false;
-                        result.errors.push(issue);
-                    }
-                    Severity::Warning => result.warnings.push(issue),
-                    Severity::Info => result.info.push(issue),
-                }
-            }
-        }
-
-        // Check layer boundaries if architecture is defined
-        if let Some(rules) = &self.project.rules {
-            if let Some(arch) = &rules.architecture {
-                if let Some(issues) = self.check_layer_boundaries(arch) {
-                    for issue in issues {
-                        match issue.severity {
-                            Severity::Error => {
-                                result.valid = false;
-                                result.errors.push(issue);
-                            }
-                            Severity::Warning => result.warnings.push(issue),
-                            Severity::Info => result.info.push(issue),
-                        }
-                    }
-                }
-            }
-        }
-
-        // Check that all types exist
-        if let Some(issues) = self.check_type_existence() {
-            for issue in issues {
-                match issue.severity {
-                    Severity::Error => {
-                        result.valid = false;
-                        result.errors.push(issue);
-                    }
-                    Severity::Warning => result.warnings.push(issue),
-                    Severity::Info => result.info.push(issue),
-                }
+        for rule in self.registry.iter() {
+            if rule_config(project, rule.id()).is_some_and(|config| !config.enabled) {
+                continue;
             }
+            result.add_issues(apply_rule_config(project, rule.validate(project)));
         }
 
-        // Check that all function calls reference existing exports
-        if let Some(issues) = self.check_call_targets() {
-            for issue in issues {
-                match issue.severity {
-                    Severity::Error => {
-                        result.valid = false;
-                        result.errors.push(issue);
-                    }
-                    Severity::Warning => result.warnings.push(issue),
-                    Severity::Info => result.info.push(issue),
-                }
-            }
-        }
+        // Report rule ids in rules.json that don't match any rule
+        result.add_issues(self.check_rule_ids(project));
 
-        // Check that all used dependencies are declared
-        if let Some(issues) = self.check_used_dependencies() {
-            for issue in issues {
-                match issue.severity {
-                    Severity::Error => {
-                        result.valid = false;
-                        result.errors.push(issue);
-                    }
-                    Severity::Warning => result.warnings.push(issue),
-                    Severity::Info => result.info.push(issue),
-                }
-            }
+        // Evaluate custom rules from rules.json
+        if let Some(rules) = &project.rules {
+            let issues = CustomRuleEngine::new(project).evaluate_all(&rules.custom_rules);
+            result.add_issues(apply_rule_config(project, issues));
         }
 
-        // Check that all declared dependencies are used (warning only)
-        if let Some(issues) = self.check_declared_dependencies() {
-            for issue in issues {
-                match issue.severity {
-                    Severity::Error => {
-                        result.valid = false;
-                        result.errors.push(issue);
-                    }
-                    Severity::Warning => result.warnings.push(issue),
-                    Severity::Info => result.info.push(issue),
-                }
-            }
-        }
+        attach_sources(project, &mut result);
 
-        // Check event and trait structure
-        if let Some(issues) = self.check_event_trait_structure() {
-            for issue in issues {
-                match issue.severity {
-                    Severity::Error => {
-                        result.valid = false;
-                        result.errors.push(issue);
-                    }
-                    Severity::Warning => result.warnings.push(issue),
-                    Severity::Info => result.info.push(issue),
-                }
-            }
-        }
+        // Move issues accepted by inline suppressions out of the report, and
+        // report unused suppressions as configured in rules.json
+        let unused = apply_suppressions(project, &mut result);
+        result.add_issues(apply_rule_config(project, unused));
 
         result
     }
 
-    /// Check for circular dependencies between modules
-    fn check_circular_dependencies(&self) -> Option<Vec<ValidationIssue>> {
-        let mut graph = DiGraph::new();
-        let mut node_map = HashMap::new();
+    /// Check that every rule configured in rules.json matches a known rule
+    fn check_rule_ids(&self, project: &Project) -> Vec<ValidationIssue> {
         let mut issues = Vec::new();
+        let Some(rules) = &project.rules else {
+            return issues;
+        };
+        let known_ids = self.registry.ids();
+
+        for (index, rule) in rules.rules.iter().enumerate() {
+            let is_known = known_ids.contains(&rule.id.as_str())
+                || rule.id == UNUSED_SUPPRESSION
+                || rules.custom_rules.iter().any(|custom| custom.id == rule.id);
+
+            if !is_known {
+                let similar: Vec<_> = known_ids
+                    .iter()
+                    .filter(|id| levenshtein_distance(id, &rule.id) <= 3)
+                    .copied()
+                    .collect();
+
+                let suggestion = if similar.is_empty() {
+                    format!("Known rules: {}", known_ids.join(", "))
+                } else {
+                    format!("Did you mean: {}?", similar.join(", "))
+                };
 
-        // Add nodes for each module
-        for module in &self.project.modules {
-            let node = graph.add_node(module.module.clone());
-            node_map.insert(module.module.clone(), node);
-        }
-
-        // Add edges for dependencies
-        for module in &self.project.modules {
-            if let Some(from_node) = node_map.get(&module.module) {
-                for dep_name in module.dependencies.keys() {
-                    if let Some(to_node) = node_map.get(dep_name) {
-                        graph.add_edge(*from_node, *to_node, ());
-                    }
-                }
-            }
-        }
-
-        // Check for cycles
-        if is_cyclic_directed(&graph) {
-            issues.push(
-                ValidationIssue::new(
-                    "no-circular-dependencies".to_string(),
-                    Severity::Error,
-                    "Circular dependency detected in module graph".to_string(),
-                    None,
-                )
-                .with_suggestion(
-                    "Remove one of the dependencies creating the cycle. \
-                     Use 'crucible graph' to visualize the dependency structure."
-                        .to_string(),
+                let mut issue = ValidationIssue::new(
+                    "unknown-rule".to_string(),
+                    Severity::Warning,
+                    format!(
+                        "Rule '{}' in rules.json does not match any known check",
+                        rule.id
+                    ),
+                    Some("rules.json".to_string()),
                 )
+                .with_suggestion(suggestion)
                 .with_doc_link(
-                    "https://github.com/anvanster/crucible#circular-dependencies".to_string(),
-                ),
-            );
-        }
-
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
-        }
-    }
-
-    /// Check that modules respect layer boundaries
-    fn check_layer_boundaries(
-        &self,
-        architecture: &crate::types::Architecture,
-    ) -> Option<Vec<ValidationIssue>> {
-        let mut issues = Vec::new();
-
-        // Create a map of module to layer
-        let mut module_layers = HashMap::new();
-        for module in &self.project.modules {
-            if let Some(layer) = &module.layer {
-                module_layers.insert(module.module.clone(), layer.clone());
-            }
-        }
-
-        // Check each module's dependencies
-        for module in &self.project.modules {
-            if let Some(from_layer) = module.layer.as_ref() {
-                // Find the layer definition
-                let layer_def = architecture.layers.iter().find(|l| &l.name == from_layer);
-
-                if let Some(layer) = layer_def {
-                    // Check each dependency
-                    for dep_name in module.dependencies.keys() {
-                        if let Some(to_layer) = module_layers.get(dep_name) {
-                            // Check if this dependency is allowed
-                            if !layer.can_depend_on.contains(to_layer) {
-                                let allowed_layers = layer.can_depend_on.join(", ");
-                                issues.push(
-                                    ValidationIssue::with_comparison(
-                                        "respect-layer-boundaries".to_string(),
-                                        Severity::Error,
-                                        format!(
-                                            "Layer boundary violation: '{from_layer}' cannot depend on '{to_layer}'"
-                                        ),
-                                        Some(format!("{} -> {}", module.module, dep_name)),
-                                        format!("dependency on '{to_layer}' layer"),
-                                        format!("dependency on one of: {allowed_layers}"),
-                                    )
-                                    .with_suggestion(format!(
-                                        "Remove the dependency on '{dep_name}' from module '{}', \
-                                         or restructure your architecture to allow '{from_layer}' → '{to_layer}' dependencies.",
-                                        module.module
-                                    ))
-                                    .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/common-mistakes.md#layer-dependency-issues".to_string()),
-                                );
-                            }
-                        }
-                    }
+                    "https://github.com/anvanster/crucible/blob/main/spec/SPEC.md#built-in-validation-rules"
+                        .to_string(),
+                );
+                if let Some(source) = project
+                    .source_map
+                    .rules_position(&format!("/rules/{index}/id"))
+                {
+                    issue = issue.with_source(source);
                 }
+                issues.push(issue);
             }
         }
 
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
-        }
+        issues
     }
+}
 
-    /// Check that all referenced types exist
-    fn check_type_existence(&self) -> Option<Vec<ValidationIssue>> {
-        let mut issues = Vec::new();
+/// Look up the rules.json configuration for a rule id
+fn rule_config<'a>(project: &'a Project, rule_id: &str) -> Option<&'a Rule> {
+    project
+        .rules
+        .as_ref()?
+        .rules
+        .iter()
+        .find(|rule| rule.id == rule_id)
+}
 
-        // Collect all available types
-        let mut available_types = HashMap::new();
-        for module in &self.project.modules {
-            for export_name in module.exports.keys() {
-                available_types.insert(format!("{}.{}", module.module, export_name), true);
-                available_types.insert(export_name.clone(), true);
+/// Drop issues of disabled rules and apply configured severities
+fn apply_rule_config(project: &Project, issues: Vec<ValidationIssue>) -> Vec<ValidationIssue> {
+    issues
+        .into_iter()
+        .filter_map(|mut issue| match rule_config(project, &issue.rule) {
+            Some(rule) if !rule.enabled => None,
+            Some(rule) => {
+                issue.severity = rule.severity.clone();
+                Some(issue)
             }
-        }
-
-        // Add primitive types
-        for primitive in &["string", "number", "boolean", "void", "null", "Date"] {
-            available_types.insert(primitive.to_string(), true);
-        }
+            None => Some(issue),
+        })
+        .collect()
+}
 
-        // Check all type references
-        for module in &self.project.modules {
-            for (export_name, export) in &module.exports {
-                // Check method parameter and return types
-                if let Some(methods) = &export.methods {
-                    for (method_name, method) in methods {
-                        // Check input types
-                        for param in &method.inputs {
-                            if !self.is_type_available(&param.param_type, &available_types) {
-                                // Try to find similar type names for suggestion
-                                let similar_types =
-                                    self.find_similar_types(&param.param_type, &available_types);
-                                let mut issue = ValidationIssue::new(
-                                    "all-types-must-exist".to_string(),
-                                    Severity::Error,
-                                    format!("Type '{}' not found in parameter '{}'", param.param_type, param.name),
-                                    Some(format!(
-                                        "{}.{}.{} (parameter: {})",
-                                        module.module, export_name, method_name, param.name
-                                    )),
-                                )
-                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/type-system.md".to_string());
-
-                                if !similar_types.is_empty() {
-                                    issue = issue.with_suggestion(format!(
-                                        "Did you mean one of: {}? If using a type from another module, \
-                                         ensure it's listed in the dependencies field.",
-                                        similar_types.join(", ")
-                                    ));
-                                } else {
-                                    issue = issue.with_suggestion(
-                                        "Ensure the type is exported from a module listed in dependencies, \
-                                         or use a built-in type (string, number, boolean, void, Date)."
-                                            .to_string(),
-                                    );
-                                }
-
-                                issues.push(issue);
-                            }
-                        }
+/// Resolve file positions for issues that don't carry one yet
+fn attach_sources(project: &Project, result: &mut ValidationResult) {
+    if project.source_map.is_empty() {
+        return;
+    }
 
-                        // Check return type (including array items if present)
-                        if !self.is_return_type_available(&method.returns, &available_types) {
-                            let type_desc = if method.returns.return_type == "array"
-                                && method.returns.inner.is_some()
-                            {
-                                format!("array<{}>", method.returns.inner.as_ref().unwrap())
-                            } else {
-                                method.returns.return_type.clone()
-                            };
-
-                            let similar_types =
-                                self.find_similar_types(&type_desc, &available_types);
-                            let mut issue = ValidationIssue::new(
-                                "all-types-must-exist".to_string(),
-                                Severity::Error,
-                                format!("Return type '{type_desc}' not found"),
-                                Some(format!(
-                                    "{}.{}.{} (returns)",
-                                    module.module, export_name, method_name
-                                )),
-                            )
-                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/type-system.md".to_string());
-
-                            if !similar_types.is_empty() {
-                                issue = issue.with_suggestion(format!(
-                                    "Did you mean one of: {}? If using a type from another module, \
-                                     ensure it's listed in the dependencies field.",
-                                    similar_types.join(", ")
-                                ));
-                            } else {
-                                issue = issue.with_suggestion(
-                                    "Ensure the type is exported from a module listed in dependencies, \
-                                     or use a built-in type (string, number, boolean, void, Date, Promise<T>)."
-                                        .to_string(),
-                                );
-                            }
-
-                            issues.push(issue);
-                        }
-                    }
-                }
-            }
-        }
-
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
+    for issue in result
+        .errors
+        .iter_mut()
+        .chain(&mut result.warnings)
+        .chain(&mut result.info)
+    {
+        if issue.source.is_none() {
+            issue.source = issue
+                .location
+                .as_deref()
+                .and_then(|location| project.source_map.resolve(project, location));
         }
     }
+}
 
-    /// Check if a type is available (handles generics)
-    fn is_type_available(&self, type_name: &str, _available_types: &HashMap<String, bool>) -> bool {
-        // Use the new TypeValidator for enhanced type checking
-        use crate::type_system::TypeValidator;
+/// Calculate Levenshtein distance between two strings
+pub(crate) fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+    let len1 = s1.chars().count();
+    let len2 = s2.chars().count();
+    let mut matrix = vec![vec![0; len2 + 1]; len1 + 1];
 
-        let validator = TypeValidator::new();
-        validator
-            .validate_type_string(type_name, None, &self.project.modules)
-            .is_ok()
+    for (i, row) in matrix.iter_mut().enumerate().take(len1 + 1) {
+        row[0] = i;
     }
-
-    /// Check if a return type is available (handles array items)
-    fn is_return_type_available(
-        &self,
-        return_type: &ReturnType,
-        _available_types: &HashMap<String, bool>,
-    ) -> bool {
-        use crate::type_system::{TypeParser, TypeValidator};
-
-        let parser = TypeParser::new();
-        let validator = TypeValidator::new();
-
-        // Parse the return type with items if present
-        let type_ref = if return_type.return_type == "array" && return_type.inner.is_some() {
-            // Array with items
-            parser
-                .parse_from_json("array", None, return_type.inner.as_deref(), None)
-                .ok()
-        } else {
-            // Regular type
-            parser.parse(&return_type.return_type).ok()
-        };
-
-        if let Some(type_ref) = type_ref {
-            validator
-                .validate_type_exists(&type_ref, &self.project.modules)
-                .is_ok()
-        } else {
-            false
-        }
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
     }
 
-    /// Check that all function calls reference existing exports
-    /// Call format: "module.Export.method" or "module.function"
-    fn check_call_targets(&self) -> Option<Vec<ValidationIssue>> {
-        let mut issues = Vec::new();
-
-        // Build a map of all available exports and their methods
-        let mut available_exports: HashMap<String, HashMap<String, bool>> = HashMap::new();
-
-        for module in &self.project.modules {
-            for (export_name, export) in &module.exports {
-                let full_export_name = format!("{}.{}", module.module, export_name);
-                let mut methods = HashMap::new();
-
-                // Add methods if they exist
-                if let Some(export_methods) = &export.methods {
-                    for method_name in export_methods.keys() {
-                        methods.insert(method_name.clone(), true);
-                    }
-                }
+    let s1_chars: Vec<char> = s1.chars().collect();
+    let s2_chars: Vec<char> = s2.chars().collect();
 
-                available_exports.insert(full_export_name, methods);
-            }
+    for i in 1..=len1 {
+        for j in 1..=len2 {
+            let cost = usize::from(s1_chars[i - 1] != s2_chars[j - 1]);
+            matrix[i][j] = std::cmp::min(
+                std::cmp::min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1),
+                matrix[i - 1][j - 1] + cost,
+            );
         }
+    }
 
-        // Check all calls in the project
-        for module in &self.project.modules {
-            for (export_name, export) in &module.exports {
-                if let Some(methods) = &export.methods {
-                    for (method_name, method) in methods {
-                        for call in &method.calls {
-                            // Parse call: "module.Export.method" or "module.function"
-                            let parts: Vec<&str> = call.split('.').collect();
-
-                            if parts.len() < 2 {
-                                issues.push(
-                                    ValidationIssue::with_comparison(
-                                        "all-calls-must-exist".to_string(),
-                                        Severity::Error,
-                                        format!("Invalid call format: '{call}'"),
-                                        Some(format!(
-                                            "{}.{}.{}",
-                                            module.module, export_name, method_name
-                                        )),
-                                        format!("'{call}'"),
-                                        "'module.Export.method' or 'module.function'".to_string(),
-                                    )
-                                    .with_suggestion(
-                                        "Use format 'module.function' for function calls or \
-                                         'module.Export.method' for method calls."
-                                            .to_string(),
-                                    )
-                                    .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#method-calls".to_string()),
-                                );
-                                continue;
-                            }
-
-                            let target_module = parts[0];
-                            let target_export = parts[1];
-
-                            // Check if this is a self-call (calling own export's methods)
-                            // Format: "module.method" where module == current && method is on same export
-                            if target_module == module.module && parts.len() == 2 {
-                                // Check if this is calling a method on the SAME export (self-call)
-                                if let Some(self_methods) = &export.methods {
-                                    if self_methods.contains_key(target_export) {
-                                        // This is a self-call - validate it exists (already checked above)
-                                        continue;
-                                    }
-                                }
-                                // Not a self-call, fall through to normal validation
-                            }
-
-                            // Check if calling own export's method with full format
-                            if target_module == module.module
-                                && parts.len() == 3
-                                && target_export == export_name
-                            {
-                                // Full format: "module.Export.method" calling same export
-                                let target_method = parts[2];
-                                if let Some(self_methods) = &export.methods {
-                                    if !self_methods.contains_key(target_method) {
-                                        issues.push(
-                                            ValidationIssue::new(
-                                                "all-calls-must-exist".to_string(),
-                                                Severity::Error,
-                                                format!(
-                                                    "Method '{target_method}' not found on '{export_name}'"
-                                                ),
-                                                Some(format!(
-                                                    "{}.{}.{}",
-                                                    module.module, export_name, method_name
-                                                )),
-                                            )
-                                            .with_suggestion(format!(
-                                                "Ensure '{target_method}' is defined as a method in the '{export_name}' export.",
-                                            ))
-                                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#method-calls".to_string()),
-                                        );
-                                    }
-                                }
-                                continue;
-                            }
-
-                            // Check if it's a function call (2 parts) or method call (3 parts)
-                            if parts.len() == 2 {
-                                // Function call: module.function
-                                let full_name = format!("{target_module}.{target_export}");
-                                if !available_exports.contains_key(&full_name) {
-                                    issues.push(
-                                        ValidationIssue::new(
-                                            "all-calls-must-exist".to_string(),
-                                            Severity::Error,
-                                            format!("Call target '{call}' not found"),
-                                            Some(format!(
-                                                "{}.{}.{}",
-                                                module.module, export_name, method_name
-                                            )),
-                                        )
-                                        .with_suggestion(format!(
-                                            "Ensure '{target_export}' is exported from module '{target_module}' \
-                                             and '{target_module}' is listed in dependencies."
-                                        ))
-                                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
-                                    );
-                                }
-                            } else if parts.len() == 3 {
-                                // Method call: module.Export.method
-                                let target_method = parts[2];
-                                let full_export = format!("{target_module}.{target_export}");
-
-                                if let Some(export_methods) = available_exports.get(&full_export) {
-                                    if !export_methods.contains_key(target_method) {
-                                        issues.push(
-                                            ValidationIssue::new(
-                                                "all-calls-must-exist".to_string(),
-                                                Severity::Error,
-                                                format!(
-                                                    "Method '{target_method}' not found on '{target_module}.{target_export}'"
-                                                ),
-                                                Some(format!(
-                                                    "{}.{}.{}",
-                                                    module.module, export_name, method_name
-                                                )),
-                                            )
-                                            .with_suggestion(format!(
-                                                "Ensure '{target_method}' is defined as a method in export '{target_export}' of module '{target_module}'.",
-                                            ))
-                                            .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#methods".to_string()),
-                                        );
-                                    }
-                                } else {
-                                    issues.push(
-                                        ValidationIssue::new(
-                                            "all-calls-must-exist".to_string(),
-                                            Severity::Error,
-                                            format!(
-                                                "Export '{target_module}.{target_export}' not found"
-                                            ),
-                                            Some(format!(
-                                                "{}.{}.{}",
-                                                module.module, export_name, method_name
-                                            )),
-                                        )
-                                        .with_suggestion(format!(
-                                            "Ensure '{target_export}' is exported from module '{target_module}' \
-                                             and '{target_module}' is listed in dependencies."
-                                        ))
-                                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
-                                    );
-                                }
-                            } else {
-                                issues.push(
-                                    ValidationIssue::with_comparison(
-                                        "all-calls-must-exist".to_string(),
-                                        Severity::Error,
-                                        format!("Invalid call format: '{call}'"),
-                                        Some(format!(
-                                            "{}.{}.{}",
-                                            module.module, export_name, method_name
-                                        )),
-                                        format!("'{call}'"),
-                                        "'module.function' or 'module.Export.method'".to_string(),
-                                    )
-                                    .with_suggestion(
-                                        "Use format 'module.function' for function calls or \
-                                         'module.Export.method' for method calls."
-                                            .to_string(),
-                                    )
-                                    .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#method-calls".to_string()),
-                                );
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    matrix[len1][len2]
+}
 
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    /// Module `a` depends on `b` without using it, and `b` depends back on `a`
+    fn validate_with_rules(rules: &str) -> ValidationResult {
+        TestProject::new("rust")
+            .module(r#"{"module": "a", "version": "1.0.0", "exports": {}, "dependencies": {"b": "^1.0.0"}}"#)
+            .module(r#"{"module": "b", "version": "1.0.0", "exports": {}, "dependencies": {"a": "^1.0.0"}}"#)
+            .rules(Some(rules))
+            .validate()
     }
 
-    /// Check that all modules referenced in calls are declared as dependencies
-    fn check_used_dependencies(&self) -> Option<Vec<ValidationIssue>> {
-        let mut issues = Vec::new();
+    #[test]
+    fn test_defaults_apply_without_rule_entries() {
+        let result = validate_with_rules(r#"{"rules": []}"#);
 
-        for module in &self.project.modules {
-            let mut used_modules = std::collections::HashSet::new();
-
-            // Collect all modules referenced in calls
-            for export in module.exports.values() {
-                if let Some(methods) = &export.methods {
-                    for method in methods.values() {
-                        for call in &method.calls {
-                            // Extract module name from call (first part before '.')
-                            if let Some(target_module) = call.split('.').next() {
-                                // Skip if calling own module
-                                if target_module != module.module {
-                                    used_modules.insert(target_module.to_string());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        assert!(!result.valid);
+        assert!(result
+            .errors
+            .iter()
+            .any(|e| e.rule == "no-circular-dependencies"));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.rule == "declared-dependencies-must-be-used"));
+    }
 
-            // Check that all used modules are in dependencies
-            for used_module in used_modules {
-                if !module.dependencies.contains_key(&used_module) {
-                    issues.push(
-                        ValidationIssue::new(
-                            "used-dependencies-declared".to_string(),
-                            Severity::Error,
-                            format!(
-                                "Module '{used_module}' is used but not declared in dependencies"
-                            ),
-                            Some(module.module.clone()),
-                        )
-                        .with_suggestion(format!(
-                            "Add '{used_module}' to the dependencies field in module '{}'.\n\
-                             Example: \"dependencies\": {{\"{}user\": \"ExportName\", ... }}",
-                            module.module, used_module
-                        ))
-                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
-                    );
-                }
-            }
-        }
+    #[test]
+    fn test_disabled_rule_is_skipped() {
+        let result = validate_with_rules(
+            r#"{"rules": [
+                {"id": "declared-dependencies-must-be-used", "enabled": false, "severity": "warning"}
+            ]}"#,
+        );
 
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
-        }
+        assert!(!result
+            .warnings
+            .iter()
+            .any(|w| w.rule == "declared-dependencies-must-be-used"));
     }
 
-    /// Check that all declared dependencies are actually used (warning only)
-    fn check_declared_dependencies(&self) -> Option<Vec<ValidationIssue>> {
-        let mut issues = Vec::new();
+    #[test]
+    fn test_rule_promoted_to_error() {
+        let result = validate_with_rules(
+            r#"{"rules": [
+                {"id": "no-circular-dependencies", "enabled": false, "severity": "error"},
+                {"id": "declared-dependencies-must-be-used", "enabled": true, "severity": "error"}
+            ]}"#,
+        );
 
-        for module in &self.project.modules {
-            let mut used_modules = std::collections::HashSet::new();
-
-            // Collect all modules referenced in calls
-            for export in module.exports.values() {
-                if let Some(methods) = &export.methods {
-                    for method in methods.values() {
-                        for call in &method.calls {
-                            if let Some(target_module) = call.split('.').next() {
-                                used_modules.insert(target_module.to_string());
-                            }
-                        }
-                    }
-                }
-            }
+        assert!(!result.valid);
+        assert!(result
+            .errors
+            .iter()
+            .all(|e| e.rule == "declared-dependencies-must-be-used"));
+        assert_eq!(result.errors.len(), 2);
+    }
 
-            // Check for unused dependencies
-            for dep_name in module.dependencies.keys() {
-                if !used_modules.contains(dep_name) {
-                    issues.push(
-                        ValidationIssue::new(
-                            "declared-dependencies-must-be-used".to_string(),
-                            Severity::Warning,
-                            format!("Dependency '{dep_name}' is declared but not used"),
-                            Some(module.module.clone()),
-                        )
-                        .with_suggestion(format!(
-                            "Remove '{dep_name}' from the dependencies field in module '{}', \
-                             or add a method call that uses it.",
-                            module.module
-                        ))
-                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/docs/schema-reference.md#dependencies".to_string()),
-                    );
-                }
-            }
-        }
+    #[test]
+    fn test_rule_demoted_to_warning_keeps_project_valid() {
+        let result = validate_with_rules(
+            r#"{"rules": [
+                {"id": "no-circular-dependencies", "enabled": true, "severity": "warning"}
+            ]}"#,
+        );
 
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
-        }
+        assert!(result.valid);
+        assert!(result.errors.is_empty());
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.rule == "no-circular-dependencies"));
     }
 
-    /// Validate event and trait export types have correct structure
-    fn check_event_trait_structure(&self) -> Option<Vec<ValidationIssue>> {
-        let mut issues = Vec::new();
+    #[test]
+    fn test_unknown_rule_id_is_reported() {
+        let result = validate_with_rules(
+            r#"{"rules": [
+                {"id": "no-circular-dependency", "enabled": true, "severity": "error"}
+            ]}"#,
+        );
 
-        for module in &self.project.modules {
-            for (export_name, export) in &module.exports {
-                match export.export_type {
-                    ExportType::Event => {
-                        // Events should have payload, not methods
-                        if export.methods.is_some() && !export.methods.as_ref().unwrap().is_empty()
-                        {
-                            issues.push(
-                                ValidationIssue::new(
-                                    "event-structure".to_string(),
-                                    Severity::Warning,
-                                    format!("Event '{export_name}' has methods defined. Events should define payload, not methods."),
-                                    Some(format!("{}.{}", module.module, export_name)),
-                                )
-                                .with_suggestion(
-                                    "Remove methods from the event and define the event data in the 'payload' field instead.".to_string(),
-                                )
-                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#events".to_string()),
-                            );
-                        }
+        let issue = result
+            .warnings
+            .iter()
+            .find(|w| w.rule == "unknown-rule")
+            .expect("expected unknown-rule warning");
+        assert!(issue.message.contains("no-circular-dependency"));
+        assert!(issue
+            .suggestion
+            .as_ref()
+            .unwrap()
+            .contains("no-circular-dependencies"));
+    }
 
-                        // Validate payload types exist
-                        if let Some(payload) = &export.payload {
-                            for (field_name, prop) in payload {
-                                if !self.is_type_available(&prop.prop_type, &HashMap::new()) {
-                                    issues.push(
-                                        ValidationIssue::new(
-                                            "all-types-must-exist".to_string(),
-                                            Severity::Error,
-                                            format!(
-                                                "Type '{}' not found in event payload field '{}'",
-                                                prop.prop_type, field_name
-                                            ),
-                                            Some(format!("{}.{}.payload.{}", module.module, export_name, field_name)),
-                                        )
-                                        .with_suggestion(
-                                            "Ensure the type is exported from a module listed in dependencies, \
-                                             or use a built-in type (string, number, boolean, Date)."
-                                                .to_string(),
-                                        )
-                                        .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/type-system.md".to_string()),
-                                    );
-                                }
-                            }
-                        }
-                    }
-                    ExportType::Trait => {
-                        // Traits should have methods, not properties
-                        if export.properties.is_some()
-                            && !export.properties.as_ref().unwrap().is_empty()
-                        {
-                            issues.push(
-                                ValidationIssue::new(
-                                    "trait-structure".to_string(),
-                                    Severity::Warning,
-                                    format!("Trait '{export_name}' has properties defined. Traits should only define methods."),
-                                    Some(format!("{}.{}", module.module, export_name)),
-                                )
-                                .with_suggestion(
-                                    "Remove properties from the trait. Traits should only contain method signatures. \
-                                     Use 'interface' if you need properties.".to_string(),
-                                )
-                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#traits".to_string()),
-                            );
-                        }
+    /// Organisation-specific rule: every module must have a description
+    struct ModulesMustBeDescribed;
 
-                        // Traits should have at least one method
-                        if export.methods.is_none() || export.methods.as_ref().unwrap().is_empty() {
-                            issues.push(
-                                ValidationIssue::new(
-                                    "trait-structure".to_string(),
-                                    Severity::Warning,
-                                    format!("Trait '{export_name}' has no methods defined. Traits should define at least one method."),
-                                    Some(format!("{}.{}", module.module, export_name)),
-                                )
-                                .with_suggestion(
-                                    "Add at least one method to the trait.".to_string(),
-                                )
-                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#traits".to_string()),
-                            );
-                        }
+    impl ValidationRule for ModulesMustBeDescribed {
+        fn id(&self) -> &str {
+            "modules-must-be-described"
+        }
 
-                        // Traits should not have payload
-                        if export.payload.is_some() {
-                            issues.push(
-                                ValidationIssue::new(
-                                    "trait-structure".to_string(),
-                                    Severity::Error,
-                                    format!("Trait '{export_name}' has payload defined. Payload is only valid for events."),
-                                    Some(format!("{}.{}", module.module, export_name)),
-                                )
-                                .with_suggestion(
-                                    "Remove the 'payload' field from the trait. Use 'event' type if you need a payload.".to_string(),
-                                )
-                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#traits".to_string()),
-                            );
-                        }
-                    }
-                    _ => {
-                        // For non-event types, payload should not be used
-                        if export.payload.is_some() {
-                            issues.push(
-                                ValidationIssue::new(
-                                    "export-structure".to_string(),
-                                    Severity::Error,
-                                    format!("Export '{export_name}' has payload defined but is not an event type."),
-                                    Some(format!("{}.{}", module.module, export_name)),
-                                )
-                                .with_suggestion(
-                                    "Remove the 'payload' field or change the type to 'event'.".to_string(),
-                                )
-                                .with_doc_link("https://github.com/anvanster/crucible/blob/main/crucible-cli/docs/schema-reference.md#events".to_string()),
-                            );
-                        }
-                    }
-                }
-            }
+        fn default_severity(&self) -> Severity {
+            Severity::Warning
         }
 
-        if issues.is_empty() {
-            None
-        } else {
-            Some(issues)
+        fn validate(&self, project: &Project) -> Vec<ValidationIssue> {
+            project
+                .modules
+                .iter()
+                .filter(|m| m.description.is_none())
+                .map(|m| {
+                    ValidationIssue::new(
+                        self.id().to_string(),
+                        self.default_severity(),
+                        format!("Module '{}' has no description", m.module),
+                        Some(m.module.clone()),
+                    )
+                })
+                .collect()
         }
     }
 
-    /// Find type names similar to the given type (for suggestions)
-    /// Uses simple Levenshtein distance for fuzzy matching
-    fn find_similar_types(
-        &self,
-        target_type: &str,
-        available_types: &HashMap<String, bool>,
-    ) -> Vec<String> {
-        let mut candidates: Vec<(String, usize)> = available_types
-            .keys()
-            .filter_map(|type_name| {
-                let distance = levenshtein_distance(target_type, type_name);
-                // Only suggest if distance is small relative to type name length
-                if distance <= 3 && distance < target_type.len() / 2 {
-                    Some((type_name.clone(), distance))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        // Sort by distance (closest first) and take top 3
-        candidates.sort_by_key(|(_, dist)| *dist);
-        candidates
-            .into_iter()
-            .take(3)
-            .map(|(name, _)| name)
-            .collect()
+    fn undescribed_project(rules: Option<&str>) -> Project {
+        TestProject::new("rust")
+            .module(r#"{"module": "a", "version": "1.0.0", "exports": {}, "dependencies": {}}"#)
+            .rules(rules)
+            .build()
     }
-}
 
-/// Calculate Levenshtein distance between two strings
-fn levenshtein_distance(s1: &str, s2: &str) -> usize {
-    let len1 = s1.chars().count();
-    let len2 = s2.chars().count();
-    let mut matrix = vec![vec![0; len2 + 1]; len1 + 1];
+    #[test]
+    fn test_registered_rule_runs() {
+        let mut validator = Validator::new(undescribed_project(None));
+        validator.register_rule(ModulesMustBeDescribed);
 
-    for (i, row) in matrix.iter_mut().enumerate().take(len1 + 1) {
-        row[0] = i;
+        let result = validator.validate();
+        assert!(result.valid);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.rule == "modules-must-be-described" && w.location.as_deref() == Some("a")));
     }
-    for j in 0..=len2 {
-        matrix[0][j] = j;
+
+    #[test]
+    fn test_registered_rule_honors_rules_json() {
+        let rules = r#"{"rules": [
+            {"id": "modules-must-be-described", "enabled": true, "severity": "error"}
+        ]}"#;
+        let mut validator = Validator::new(undescribed_project(Some(rules)));
+        validator.register_rule(ModulesMustBeDescribed);
+
+        let result = validator.validate();
+        assert!(!result.valid);
+        assert!(result
+            .errors
+            .iter()
+            .any(|e| e.rule == "modules-must-be-described"));
+        assert!(!result.warnings.iter().any(|w| w.rule == "unknown-rule"));
     }
 
-    let s1_chars: Vec<char> = s1.chars().collect();
-    let s2_chars: Vec<char> = s2.chars().collect();
+    #[test]
+    fn test_registered_rule_can_be_disabled() {
+        let rules = r#"{"rules": [
+            {"id": "modules-must-be-described", "enabled": false, "severity": "error"}
+        ]}"#;
+        let mut validator = Validator::new(undescribed_project(Some(rules)));
+        validator.register_rule(ModulesMustBeDescribed);
+
+        let result = validator.validate();
+        assert!(result.valid);
+        assert!(result.warnings.is_empty());
+    }
 
-    for i in 1..=len1 {
-        for j in 1..=len2 {
-            let cost = usize::from(s1_chars[i - 1] != s2_chars[j - 1]);
-            matrix[i][j] = std::cmp::min(
-                std::cmp::min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1),
-                matrix[i - 1][j - 1] + cost,
-            );
-        }
+    #[test]
+    fn test_unregistered_rule_id_is_unknown() {
+        let rules = r#"{"rules": [
+            {"id": "modules-must-be-described", "enabled": true, "severity": "error"}
+        ]}"#;
+        let result = Validator::new(undescribed_project(Some(rules))).validate();
+
+        assert!(result.warnings.iter().any(|w| w.rule == "unknown-rule"));
     }
 
-    matrix[len1][len2]
+    #[test]
+    fn test_builtin_rule_can_be_removed() {
+        let mut validator = Validator::new(undescribed_project(None));
+        assert!(validator
+            .registry_mut()
+            .unregister("no-circular-dependencies"));
+        assert!(!validator.registry().contains("no-circular-dependencies"));
+    }
 }
@@ -0,0 +1,228 @@
+//! Semantic version checks for module dependencies
+//!
+//! `Module.dependencies` maps module names to semver constraints such as
+//! `^1.0.0`. As in the spec, a bare version (`1.2.0`) pins that exact version
+//! and space-separated comparators (`>=1.0.0 <2.0.0`) form a range. Older
+//! definitions list imported export names instead (`"User,Admin"`); those
+//! values carry no version information and are skipped.
+
+use crate::types::Project;
+use semver::{Op, Version, VersionReq};
+
+/// Parsed value of a `dependencies` entry
+#[derive(Debug, Clone)]
+pub enum DependencySpec {
+    /// A semver constraint
+    Constraint(VersionReq),
+    /// A comma-separated list of imported export names
+    Exports,
+    /// A constraint that doesn't parse, with the parser's message
+    Invalid(String),
+}
+
+impl DependencySpec {
+    pub fn parse(value: &str) -> Self {
+        if value.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return Self::Exports;
+        }
+        match VersionReq::parse(&normalize_constraint(value)) {
+            Ok(req) => Self::Constraint(req),
+            Err(err) => Self::Invalid(err.to_string()),
+        }
+    }
+}
+
+/// Rewrite spec constraint syntax into the comma-separated form `semver` parses
+fn normalize_constraint(value: &str) -> String {
+    let value = value.trim();
+    if Version::parse(value).is_ok() {
+        return format!("={value}");
+    }
+    if value.contains(',') {
+        return value.to_string();
+    }
+
+    // ">=1.0.0 <2.0.0" -> ">=1.0.0, <2.0.0"; operators may be followed by a
+    // space (">= 1.0.0"), so only split where a new operator starts
+    let mut normalized = String::new();
+    for token in value.split_whitespace() {
+        if !normalized.is_empty() {
+            let after_operator = normalized.ends_with(['<', '>', '=', '~', '^']);
+            let starts_operator = token.starts_with(['<', '>', '=', '~', '^']);
+            normalized.push_str(if starts_operator && !after_operator {
+                ", "
+            } else {
+                " "
+            });
+        }
+        normalized.push_str(token);
+    }
+    normalized
+}
+
+/// Lowest version a constraint asks for, e.g. `1.2.0` for `^1.2` or `>=1.2.0, <2`
+pub fn minimum_version(req: &VersionReq) -> Option<Version> {
+    req.comparators
+        .iter()
+        .filter(|comparator| {
+            matches!(
+                comparator.op,
+                Op::Exact | Op::Greater | Op::GreaterEq | Op::Tilde | Op::Caret | Op::Wildcard
+            )
+        })
+        .map(|comparator| {
+            Version::new(
+                comparator.major,
+                comparator.minor.unwrap_or(0),
+                comparator.patch.unwrap_or(0),
+            )
+        })
+        .max()
+}
+
+/// A dependency constraint that asks for an older version than the target module has
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutdatedDependency {
+    /// Module declaring the dependency
+    pub module: String,
+    /// Module depended on
+    pub dependency: String,
+    pub constraint: String,
+    /// Current version of the depended-on module
+    pub current: Version,
+    /// Whether the constraint still accepts the current version
+    pub satisfied: bool,
+}
+
+impl OutdatedDependency {
+    /// Constraint that tracks the current version
+    pub fn suggested_constraint(&self) -> String {
+        format!("^{}", self.current)
+    }
+}
+
+/// Dependency constraints lagging behind the current version of their target,
+/// ordered by module and dependency name
+///
+/// Unparseable versions and constraints are skipped; the `valid-versions` rule
+/// reports them.
+pub fn outdated_dependencies(project: &Project) -> Vec<OutdatedDependency> {
+    let mut outdated = Vec::new();
+
+    for module in &project.modules {
+        for (dep_name, value) in &module.dependencies {
+            let DependencySpec::Constraint(req) = DependencySpec::parse(value) else {
+                continue;
+            };
+            let Some(target) = project.modules.iter().find(|m| &m.module == dep_name) else {
+                continue;
+            };
+            let Ok(current) = Version::parse(&target.version) else {
+                continue;
+            };
+            let Some(minimum) = minimum_version(&req) else {
+                continue;
+            };
+
+            if minimum < current {
+                outdated.push(OutdatedDependency {
+                    module: module.module.clone(),
+                    dependency: dep_name.clone(),
+                    constraint: value.clone(),
+                    satisfied: req.matches(&current),
+                    current,
+                });
+            }
+        }
+    }
+
+    outdated.sort_by(|a, b| {
+        a.module
+            .cmp(&b.module)
+            .then_with(|| a.dependency.cmp(&b.dependency))
+    });
+    outdated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestProject;
+
+    #[test]
+    fn test_parse_dependency_spec() {
+        assert!(matches!(
+            DependencySpec::parse("^1.0.0"),
+            DependencySpec::Constraint(_)
+        ));
+        assert!(matches!(
+            DependencySpec::parse(">=1.0.0, <2.0.0"),
+            DependencySpec::Constraint(_)
+        ));
+        assert!(matches!(
+            DependencySpec::parse(">=1.0.0 <2.0.0"),
+            DependencySpec::Constraint(_)
+        ));
+        assert!(matches!(
+            DependencySpec::parse("User,Admin"),
+            DependencySpec::Exports
+        ));
+        assert!(matches!(
+            DependencySpec::parse("^1.x.y"),
+            DependencySpec::Invalid(_)
+        ));
+        assert!(matches!(
+            DependencySpec::parse("latest"),
+            DependencySpec::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn test_spec_constraint_syntax() {
+        let matches = |constraint: &str, version: &str| match DependencySpec::parse(constraint) {
+            DependencySpec::Constraint(req) => req.matches(&Version::parse(version).unwrap()),
+            other => panic!("expected a constraint, got {other:?}"),
+        };
+        assert!(matches("1.0.0", "1.0.0"));
+        assert!(!matches("1.0.0", "1.1.0"));
+        assert!(matches(">=1.0.0 <2.0.0", "1.5.0"));
+        assert!(!matches(">=1.0.0 <2.0.0", "2.0.0"));
+        assert!(matches(">= 1.0.0", "1.5.0"));
+    }
+
+    #[test]
+    fn test_minimum_version() {
+        let minimum = |req: &str| minimum_version(&VersionReq::parse(req).unwrap());
+        assert_eq!(minimum("^1.2"), Some(Version::new(1, 2, 0)));
+        assert_eq!(minimum(">=1.0.0, <2.0.0"), Some(Version::new(1, 0, 0)));
+        assert_eq!(minimum("~0.3.1"), Some(Version::new(0, 3, 1)));
+        assert_eq!(minimum("<2.0.0"), None);
+    }
+
+    /// `app` depends on `lib` with the given constraint
+    fn project(constraint: &str, lib_version: &str) -> Project {
+        TestProject::new("typescript")
+            .module(format!(
+                r#"{{"module": "app", "version": "1.0.0", "exports": {{}}, "dependencies": {{"lib": "{constraint}"}}}}"#
+            ))
+            .module(format!(
+                r#"{{"module": "lib", "version": "{lib_version}", "exports": {{}}, "dependencies": {{}}}}"#
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_outdated_dependencies() {
+        let outdated = outdated_dependencies(&project("^1.0.0", "1.4.0"));
+        assert_eq!(outdated.len(), 1);
+        assert_eq!(outdated[0].module, "app");
+        assert_eq!(outdated[0].dependency, "lib");
+        assert!(outdated[0].satisfied);
+        assert_eq!(outdated[0].suggested_constraint(), "^1.4.0");
+
+        let outdated = outdated_dependencies(&project("~1.0.0", "1.4.0"));
+        assert!(!outdated[0].satisfied);
+
+        assert!(outdated_dependencies(&project("^1.4.0", "1.4.0")).is_empty());
+    }
+}
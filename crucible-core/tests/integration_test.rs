@@ -590,3 +590,80 @@ fn test_non_event_with_payload_errors() {
     );
     assert!(result.errors.iter().any(|e| e.rule == "export-structure"));
 }
+
+/// Write a project whose module `a` has a parameter of a missing type and an
+/// unused dependency, with a rules.json naming a rule that doesn't exist
+fn write_project_with_issues(dir: &std::path::Path) {
+    let manifest = r#"{"version": "0.1.0", "project": {"name": "test", "language": "typescript"}, "modules": ["a", "b"]}"#;
+    fs::write(dir.join("manifest.json"), manifest).unwrap();
+    fs::write(
+        dir.join("rules.json"),
+        "{\n  \"rules\": [\n    {\"id\": \"no-such-rule\", \"enabled\": true, \"severity\": \"error\"}\n  ]\n}",
+    )
+    .unwrap();
+    fs::create_dir(dir.join("modules")).unwrap();
+
+    let module_a = r#"{
+  "module": "a",
+  "version": "1.0.0",
+  "exports": {
+    "Service": {
+      "type": "class",
+      "methods": {
+        "run": {
+          "inputs": [
+            {"name": "id", "type": "string"},
+            {"name": "order", "type": "Missing"}
+          ],
+          "returns": {"type": "void"}
+        }
+      }
+    }
+  },
+  "dependencies": {"b": "^1.0.0"}
+}"#;
+    fs::write(dir.join("modules/a.json"), module_a).unwrap();
+    let module_b = r#"{"module": "b", "version": "1.0.0", "exports": {}, "dependencies": {}}"#;
+    fs::write(dir.join("modules/b.json"), module_b).unwrap();
+}
+
+#[test]
+fn test_issues_point_at_source_positions() {
+    let dir = tempdir().unwrap();
+    write_project_with_issues(dir.path());
+
+    let project = Parser::new(dir.path()).parse_project().unwrap();
+    let result = Validator::new(project).validate();
+
+    // A parameter issue points at the input
+    let issue = result
+        .errors
+        .iter()
+        .find(|e| e.rule == "all-types-must-exist")
+        .expect("expected missing type error");
+    let source = issue.source.as_ref().expect("expected source position");
+    assert_eq!(source.file, dir.path().join("modules/a.json"));
+    assert_eq!(source.pointer, "/exports/Service/methods/run/inputs/1");
+    assert_eq!((source.line, source.column), (11, 13));
+
+    // A module issue points at the start of the module file
+    let issue = result
+        .warnings
+        .iter()
+        .find(|w| w.rule == "declared-dependencies-must-be-used")
+        .expect("expected unused dependency warning");
+    let source = issue.source.as_ref().expect("expected source position");
+    assert_eq!(source.pointer, "");
+    assert_eq!((source.line, source.column), (1, 1));
+
+    // An unknown rule id points into rules.json
+    let issue = result
+        .warnings
+        .iter()
+        .find(|w| w.rule == "unknown-rule")
+        .expect("expected unknown-rule warning");
+    let source = issue.source.as_ref().expect("expected source position");
+    assert_eq!(source.file, dir.path().join("rules.json"));
+    assert_eq!(source.pointer, "/rules/0/id");
+    assert_eq!((source.line, source.column), (3, 6));
+}
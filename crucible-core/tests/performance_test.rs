@@ -203,6 +203,10 @@ fn test_change_tracker_dependencies() {
         description: Some("Module A".to_string()),
         exports: HashMap::new(),
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     });
 
     // Module B depends on A
@@ -215,6 +219,10 @@ fn test_change_tracker_dependencies() {
         description: Some("Module B".to_string()),
         exports: HashMap::new(),
         dependencies: b_deps,
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     });
 
     // Module C depends on B
@@ -227,6 +235,10 @@ fn test_change_tracker_dependencies() {
         description: Some("Module C".to_string()),
         exports: HashMap::new(),
         dependencies: c_deps,
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     });
 
     // Module D depends on A
@@ -239,6 +251,10 @@ fn test_change_tracker_dependencies() {
         description: Some("Module D".to_string()),
         exports: HashMap::new(),
         dependencies: d_deps,
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     });
 
     let project = Project {
@@ -257,9 +273,11 @@ fn test_change_tracker_dependencies() {
             ],
             strict_validation: false,
             metadata: None,
+            external_types: Vec::new(),
         },
         modules,
         rules: None,
+        source_map: Default::default(),
     };
 
     let mut tracker = ChangeTracker::new();
@@ -4,7 +4,7 @@
 //! They should FAIL initially, then PASS after implementation.
 
 use crucible_core::type_system::*;
-use crucible_core::types::Module;
+use crucible_core::types::{Language, Module};
 
 // =============================================================================
 // Phase 1: Built-in Type Support
@@ -229,7 +229,9 @@ fn test_validate_partial_type() {
             nullable: false,
             items: None,
             type_args: vec![],
+            kind: TypeKind::Named,
         }],
+        kind: TypeKind::Named,
     };
 
     let result = validate_type_reference(&type_ref, &modules);
@@ -256,7 +258,9 @@ fn test_validate_promise_type() {
             nullable: false,
             items: None,
             type_args: vec![],
+            kind: TypeKind::Named,
         }],
+        kind: TypeKind::Named,
     };
 
     let result = validate_type_reference(&type_ref, &modules);
@@ -268,6 +272,355 @@ fn test_validate_promise_type() {
     );
 }
 
+// =============================================================================
+// Phase 5: Language Dialects
+// =============================================================================
+
+#[test]
+fn test_rust_dialect_types_validate() {
+    // Given: A validator for Rust projects
+    let validator = TypeValidator::for_dialect(TypeDialect::Rust);
+    let modules = vec![create_patient_module()];
+
+    // When/Then: Rust built-ins, references and slices should validate
+    let cases = vec![
+        "String",
+        "u64",
+        "Vec<patient.Patient>",
+        "Option<&str>",
+        "HashMap<String, Vec<u8>>",
+        "&'a mut [u8]",
+        "Box<dyn Error>",
+        "string",
+    ];
+    for type_str in cases {
+        let result = validator.validate_type_string(type_str, None, &modules);
+        assert!(result.is_ok(), "{type_str} should validate: {result:?}");
+    }
+
+    // And: TypeScript-only types are not built in
+    assert!(validator
+        .validate_type_string("Buffer", None, &modules)
+        .is_err());
+}
+
+#[test]
+fn test_python_dialect_parses_subscripted_generics() {
+    // Given: A parser for Python projects
+    let parser = TypeParser::for_dialect(TypeDialect::Python);
+
+    // When: Parsing subscripted generics and optionals
+    let dict = parser.parse("dict[str, list[patient.Patient]]").unwrap();
+    let optional = parser.parse("Optional[int]").unwrap();
+    let union = parser.parse("patient.Patient | None").unwrap();
+
+    // Then: Arguments and nullability should be extracted
+    assert_eq!(dict.base_type, "dict");
+    assert_eq!(
+        dict.referenced_names(),
+        vec!["dict", "str", "list", "patient.Patient"]
+    );
+    assert_eq!(optional.base_type, "int");
+    assert!(optional.nullable);
+    assert_eq!(union.base_type, "patient.Patient");
+    assert!(union.nullable);
+}
+
+#[test]
+fn test_python_dialect_types_validate() {
+    let validator = TypeValidator::for_dialect(TypeDialect::Python);
+    let modules = vec![create_patient_module()];
+
+    for type_str in [
+        "str",
+        "list[patient.Patient]",
+        "Optional[int]",
+        "int | None",
+    ] {
+        let result = validator.validate_type_string(type_str, None, &modules);
+        assert!(result.is_ok(), "{type_str} should validate: {result:?}");
+    }
+}
+
+#[test]
+fn test_dialect_from_language() {
+    assert_eq!(TypeDialect::from(&Language::Rust), TypeDialect::Rust);
+    assert_eq!(TypeDialect::from(&Language::Python), TypeDialect::Python);
+    assert_eq!(
+        TypeDialect::from(&Language::TypeScript),
+        TypeDialect::TypeScript
+    );
+}
+
+#[test]
+fn test_external_types_validate() {
+    // Given: A Rust validator that knows about chrono
+    let modules = vec![create_patient_module()];
+    let validator = TypeValidator::for_dialect(TypeDialect::Rust);
+    assert!(validator
+        .validate_type_string("chrono::DateTime<Utc>", None, &modules)
+        .is_err());
+
+    // When: Registering the external type
+    let validator = validator.with_external_types(["chrono::DateTime"]);
+
+    // Then: It should validate as an opaque type
+    let result = validator.validate_type_string("Option<chrono::DateTime<Utc>>", None, &modules);
+    assert!(result.is_ok(), "{result:?}");
+}
+
+// =============================================================================
+// Phase 6: Type Expression Grammar
+// =============================================================================
+
+#[test]
+fn test_parse_nested_union_in_generic() {
+    // Given: A union with null nested in generic arguments
+    let parser = TypeParser::new();
+
+    // When: Parsing it
+    let type_ref = parser
+        .parse("Promise<Map<string, User | null>> | null")
+        .unwrap();
+
+    // Then: Nullability applies at each level
+    assert_eq!(type_ref.base_type, "Promise");
+    assert!(type_ref.nullable);
+    let map = &type_ref.type_args[0];
+    assert_eq!(map.base_type, "Map");
+    assert_eq!(map.type_args[1].base_type, "User");
+    assert!(map.type_args[1].nullable);
+}
+
+#[test]
+fn test_parse_unions_and_intersections() {
+    let parser = TypeParser::new();
+
+    let union = parser.parse("string | number | undefined").unwrap();
+    assert!(union.nullable);
+    match &union.kind {
+        TypeKind::Union(members) => {
+            let names: Vec<_> = members.iter().map(|m| m.base_type.as_str()).collect();
+            assert_eq!(names, vec!["string", "number"]);
+        }
+        other => panic!("expected a union, got {other:?}"),
+    }
+
+    let intersection = parser.parse("Patient & Auditable").unwrap();
+    assert_eq!(
+        intersection.kind,
+        TypeKind::Intersection(vec![
+            TypeReference::simple("Patient"),
+            TypeReference::simple("Auditable"),
+        ])
+    );
+}
+
+#[test]
+fn test_parse_literals_tuples_and_functions() {
+    let parser = TypeParser::new();
+
+    // String literal union
+    let status = parser.parse("'active' | \"archived\"").unwrap();
+    assert_eq!(
+        status.kind,
+        TypeKind::Union(vec![
+            TypeReference::composite(TypeKind::Literal("'active'".to_string())),
+            TypeReference::composite(TypeKind::Literal("\"archived\"".to_string())),
+        ])
+    );
+
+    // Tuple
+    let pair = parser.parse("[string, patient.Patient[]]").unwrap();
+    assert_eq!(pair.base_type, "tuple");
+    assert_eq!(pair.referenced_names(), vec!["string", "patient.Patient"]);
+
+    // Function type
+    let callback = parser
+        .parse("(id: string, options?: Options) => Promise<void>")
+        .unwrap();
+    match &callback.kind {
+        TypeKind::Function { params, returns } => {
+            assert_eq!(params.len(), 2);
+            assert_eq!(returns.base_type, "Promise");
+        }
+        other => panic!("expected a function, got {other:?}"),
+    }
+    assert_eq!(
+        parser.parse("() => void").unwrap().referenced_names(),
+        vec!["void"]
+    );
+
+    // Parenthesized array of unions
+    let values = parser.parse("(string | number)[]").unwrap();
+    assert_eq!(values.base_type, "array");
+    assert!(matches!(values.items.unwrap().kind, TypeKind::Union(_)));
+}
+
+#[test]
+fn test_parse_record_of_arrays() {
+    let type_ref = TypeParser::new()
+        .parse("Record<string, patient.Patient[]>")
+        .unwrap();
+
+    assert_eq!(type_ref.base_type, "Record");
+    assert_eq!(type_ref.type_args[1].base_type, "array");
+    assert_eq!(
+        type_ref.referenced_names(),
+        vec!["Record", "string", "patient.Patient"]
+    );
+}
+
+#[test]
+fn test_parse_errors_point_to_column() {
+    let parser = TypeParser::new();
+
+    let cases = vec![
+        (
+            "Map<string, User",
+            17,
+            "Expected '>' but found end of input",
+        ),
+        ("Promise<>", 9, "empty type argument list"),
+        ("User | | null", 8, "Expected a type but found '|'"),
+        ("'active", 1, "Unterminated string literal"),
+        ("`user-${string}", 1, "Unterminated template literal"),
+        (
+            "User Patient",
+            6,
+            "Expected end of type but found 'Patient'",
+        ),
+        ("{ id: string }", 1, "Object literal types aren't supported"),
+    ];
+
+    for (type_str, column, message) in cases {
+        let error = parser.parse(type_str).unwrap_err();
+        assert_eq!(error.column, column, "{type_str}: {error}");
+        assert!(error.message.contains(message), "{type_str}: {error}");
+        assert!(error
+            .to_string()
+            .contains(&format!("at column {column} of '{type_str}'")));
+    }
+}
+
+#[test]
+fn test_composite_types_validate_members() {
+    let modules = vec![create_patient_module()];
+
+    for type_str in [
+        "patient.Patient | string",
+        "[patient.Patient, number]",
+        "(patient: patient.Patient) => void",
+        "'active' | 'archived'",
+    ] {
+        let result = validate_type_string(type_str, None, &modules);
+        assert!(result.is_ok(), "{type_str} should validate: {result:?}");
+    }
+
+    let result = validate_type_string("patient.Patient | Missing", None, &modules);
+    assert!(result.unwrap_err().contains("'Missing'"));
+}
+
+#[test]
+fn test_dialect_specific_composites() {
+    let rust = TypeParser::for_dialect(TypeDialect::Rust);
+    let tuple = rust.parse("(String, Vec<u64>)").unwrap();
+    assert_eq!(tuple.base_type, "tuple");
+    assert_eq!(rust.parse("()").unwrap().base_type, "()");
+    assert_eq!(rust.parse("[u8; 32]").unwrap().base_type, "array");
+
+    let python = TypeParser::for_dialect(TypeDialect::Python);
+    let union = python.parse("Union[int, str, None]").unwrap();
+    assert!(union.nullable);
+    assert!(matches!(union.kind, TypeKind::Union(ref members) if members.len() == 2));
+}
+
+#[test]
+fn test_parse_readonly_arrays() {
+    let type_ref = TypeParser::new().parse("readonly string[]").unwrap();
+
+    assert_eq!(
+        type_ref,
+        TypeReference::array(TypeReference::simple("string"))
+    );
+}
+
+#[test]
+fn test_parse_keyof() {
+    let type_ref = TypeParser::new().parse("keyof patient.Patient").unwrap();
+
+    assert_eq!(type_ref.base_type, "keyof");
+    assert_eq!(type_ref.referenced_names(), vec!["patient.Patient"]);
+}
+
+#[test]
+fn test_parse_typeof() {
+    let type_ref = TypeParser::new().parse("typeof defaultConfig").unwrap();
+
+    assert_eq!(type_ref.kind, TypeKind::TypeOf("defaultConfig".to_string()));
+    assert!(type_ref.referenced_names().is_empty());
+}
+
+#[test]
+fn test_parse_indexed_access() {
+    let type_ref = TypeParser::new().parse("patient.Patient['id']").unwrap();
+
+    assert_eq!(type_ref.base_type, "indexed");
+    assert_eq!(type_ref.referenced_names(), vec!["patient.Patient"]);
+    assert_eq!(
+        TypeParser::new()
+            .parse("patient.Patient[]")
+            .unwrap()
+            .base_type,
+        "array"
+    );
+}
+
+#[test]
+fn test_parse_unique_symbol() {
+    let type_ref = TypeParser::new().parse("unique symbol").unwrap();
+
+    assert_eq!(type_ref, TypeReference::simple("symbol"));
+}
+
+#[test]
+fn test_parse_template_literals() {
+    let type_ref = TypeParser::new()
+        .parse("`user-${string}` | `${number}px`")
+        .unwrap();
+
+    assert_eq!(
+        type_ref.kind,
+        TypeKind::Union(vec![
+            TypeReference::composite(TypeKind::Literal("`user-${string}`".to_string())),
+            TypeReference::composite(TypeKind::Literal("`${number}px`".to_string())),
+        ])
+    );
+}
+
+#[test]
+fn test_type_operators_validate() {
+    let modules = vec![create_patient_module()];
+
+    for type_str in [
+        "readonly string[]",
+        "readonly patient.Patient[]",
+        "keyof patient.Patient",
+        "typeof defaultConfig",
+        "patient.Patient['id']",
+        "unique symbol",
+        "`patient-${string}`",
+    ] {
+        let result = validate_type_string(type_str, None, &modules);
+        assert!(result.is_ok(), "{type_str} should validate: {result:?}");
+    }
+
+    let result = validate_type_string("keyof Missing", None, &modules);
+    assert!(result.unwrap_err().contains("'Missing'"));
+    let result = validate_type_string("Missing['id']", None, &modules);
+    assert!(result.unwrap_err().contains("'Missing'"));
+}
+
 // =============================================================================
 // Integration Tests
 // =============================================================================
@@ -332,6 +685,12 @@ fn create_patient_module() -> Module {
             values: None,
             dependencies: None,
             payload: None,
+            suppress: Vec::new(),
+            public_api: false,
+            entry_point: false,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            alias: None,
         },
     );
 
@@ -342,5 +701,9 @@ fn create_patient_module() -> Module {
         description: Some("Patient domain entity".to_string()),
         exports,
         dependencies: HashMap::new(),
+        suppress: Vec::new(),
+        role: None,
+        service: None,
+        contracts: false,
     }
 }